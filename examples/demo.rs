@@ -166,7 +166,8 @@ fn main() {
             .on_select(|_, selected| {
                 println!("{:?}", selected);
             })
-            .build();
+            .build()
+            .unwrap();
 
         let _text_entry = background
             .create_widget()
@@ -1,25 +1,42 @@
 //! Builder types
 
+use basalt::interface::Position;
+use basalt::interface::UnitValue::{self, Pixels};
+
 pub use crate::button::ButtonBuilder;
 pub use crate::check_box::CheckBoxBuilder;
 pub use crate::code_editor::CodeEditorBuilder;
+pub use crate::color_swatch::ColorSwatchBuilder;
+pub use crate::combo_box::ComboBoxBuilder;
+pub use crate::grid::GridBuilder;
+pub use crate::label::LabelBuilder;
+pub use crate::number_field::NumberFieldBuilder;
 pub use crate::progress_bar::ProgressBarBuilder;
 pub use crate::radio_button::RadioButtonBuilder;
 pub use crate::scaler::ScalerBuilder;
 pub use crate::scroll_bar::ScrollBarBuilder;
+pub use crate::section::SectionBuilder;
 pub use crate::select::SelectBuilder;
+pub use crate::separator::SeparatorBuilder;
 pub use crate::spin_button::SpinButtonBuilder;
+pub use crate::spinner::SpinnerBuilder;
 pub use crate::switch_button::SwitchButtonBuilder;
 pub use crate::text_editor::TextEditorBuilder;
 pub use crate::text_entry::TextEntryBuilder;
 pub use crate::toggle_button::ToggleButtonBuilder;
-use crate::{Theme, WidgetContainer, WidgetPlacement};
+use crate::{Theme, WidgetContainer, WidgetPlacement, WidgetRole};
 
 /// General builder for widgets.
 pub struct WidgetBuilder<'a, C> {
     pub(crate) container: &'a C,
     pub(crate) theme: Theme,
     pub(crate) placement: Option<WidgetPlacement>,
+    pub(crate) name: Option<String>,
+    pub(crate) aria_label: Option<String>,
+    pub(crate) role: Option<WidgetRole>,
+    size: Option<[UnitValue; 2]>,
+    margin: Option<UnitValue>,
+    position: Option<Position>,
 }
 
 impl<'a, C> From<&'a C> for WidgetBuilder<'a, C>
@@ -31,6 +48,12 @@ where
             theme: container.default_theme(),
             container,
             placement: None,
+            name: None,
+            aria_label: None,
+            role: None,
+            size: None,
+            margin: None,
+            position: None,
         }
     }
 }
@@ -47,12 +70,125 @@ where
         self
     }
 
+    /// Modify the theme used by this widget only.
+    ///
+    /// The closure is given the theme currently set on this builder, so it can be combined with
+    /// [`WidgetBuilder::with_theme`] as long as `with_theme` is called first. For example,
+    /// `.theme_tweak(|theme| theme.border = None)` removes the border from a single widget.
+    pub fn theme_tweak<F>(mut self, tweak: F) -> Self
+    where
+        F: FnOnce(&mut Theme),
+    {
+        tweak(&mut self.theme);
+        self
+    }
+
+    /// Specify a name for the widget.
+    ///
+    /// This can be used to identify the widget when debugging or when handling events from many
+    /// widgets generically. The name can be obtained with the widget's `name` method.
+    pub fn named<N>(mut self, name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Specify a label describing the widget.
+    ///
+    /// This is intended for assistive technologies and can be used to locate widgets, such as
+    /// within tests. The label can be obtained with the widget's `aria_label` method.
+    pub fn aria_label<L>(mut self, label: L) -> Self
+    where
+        L: Into<String>,
+    {
+        self.aria_label = Some(label.into());
+        self
+    }
+
+    /// Specify the [`WidgetRole`] of the widget.
+    ///
+    /// **Note**: When not used the widget's default role is used. See [`WidgetRole`] for the
+    /// defaults of each widget.
+    pub fn with_role(mut self, role: WidgetRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
     /// Specify how the widget should be placed.
+    ///
+    /// **Note**: Values set with [`with_size`](Self::with_size),
+    /// [`with_margin`](Self::with_margin) or [`with_position`](Self::with_position) take
+    /// precedence over the values of this placement regardless of the order they are called in.
     pub fn with_placement(mut self, placement: WidgetPlacement) -> Self {
         self.placement = Some(placement);
         self
     }
 
+    /// Specify the size of the widget in pixels.
+    ///
+    /// **Note**: When [`with_placement`](Self::with_placement) isn't used, this modifies the
+    /// widget's default placement.
+    pub fn with_size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some([Pixels(width), Pixels(height)]);
+        self
+    }
+
+    /// Specify the margin in pixels on all sides of the widget.
+    ///
+    /// **Note**: When [`with_placement`](Self::with_placement) isn't used, this modifies the
+    /// widget's default placement.
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = Some(Pixels(margin));
+        self
+    }
+
+    /// Specify the [`Position`] of the widget.
+    ///
+    /// **Note**: When [`with_placement`](Self::with_placement) isn't used, this modifies the
+    /// widget's default placement.
+    pub fn with_position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub(crate) fn take_placement<F>(&mut self, default: F) -> WidgetPlacement
+    where
+        F: FnOnce(&Theme) -> WidgetPlacement,
+    {
+        let placement = self
+            .placement
+            .take()
+            .unwrap_or_else(|| default(&self.theme));
+
+        self.apply_placement(placement)
+    }
+
+    pub(crate) fn apply_placement(&self, mut placement: WidgetPlacement) -> WidgetPlacement {
+        if let Some([width, height]) = self.size {
+            placement.width = width;
+            placement.height = height;
+        }
+
+        if let Some(margin) = self.margin {
+            placement.margin_t = margin;
+            placement.margin_b = margin;
+            placement.margin_l = margin;
+            placement.margin_r = margin;
+        }
+
+        if let Some(position) = self.position {
+            placement.position = position;
+        }
+
+        placement
+    }
+
+    pub(crate) fn has_placement_mods(&self) -> bool {
+        self.size.is_some() || self.margin.is_some() || self.position.is_some()
+    }
+
     /// Transition into building a [`Button`](crate::Button)
     pub fn button(self) -> ButtonBuilder<'a, C> {
         ButtonBuilder::with_builder(self)
@@ -63,6 +199,16 @@ where
         SpinButtonBuilder::with_builder(self)
     }
 
+    /// Transition into building a [`Spinner`](crate::Spinner)
+    pub fn spinner(self) -> SpinnerBuilder<'a, C> {
+        SpinnerBuilder::with_builder(self)
+    }
+
+    /// Transition into building a [`NumberField`](crate::NumberField)
+    pub fn number_field(self) -> NumberFieldBuilder<'a, C> {
+        NumberFieldBuilder::with_builder(self)
+    }
+
     /// Transition into building a [`ToggleButton`](crate::ToggleButton)
     pub fn toggle_button(self) -> ToggleButtonBuilder<'a, C> {
         ToggleButtonBuilder::with_builder(self)
@@ -110,11 +256,44 @@ where
     /// Transition into building a [`Select`](crate::Select)
     pub fn select<I>(self) -> SelectBuilder<'a, C, I>
     where
-        I: Ord + Copy + Send + 'static,
+        I: Ord + Clone + Send + 'static,
     {
         SelectBuilder::with_builder(self)
     }
 
+    /// Transition into building a [`ComboBox`](crate::ComboBox)
+    pub fn combo_box<I>(self) -> ComboBoxBuilder<'a, C, I>
+    where
+        I: Ord + Clone + Send + 'static,
+    {
+        ComboBoxBuilder::with_builder(self)
+    }
+
+    /// Transition into building a [`ColorSwatch`](crate::ColorSwatch)
+    pub fn color_swatch(self) -> ColorSwatchBuilder<'a, C> {
+        ColorSwatchBuilder::with_builder(self)
+    }
+
+    /// Transition into building a [`Grid`](crate::Grid)
+    pub fn grid(self) -> GridBuilder<'a, C> {
+        GridBuilder::with_builder(self)
+    }
+
+    /// Transition into building a [`Section`](crate::Section)
+    pub fn section(self) -> SectionBuilder<'a, C> {
+        SectionBuilder::with_builder(self)
+    }
+
+    /// Transition into building a [`Label`](crate::Label)
+    pub fn label(self) -> LabelBuilder<'a, C> {
+        LabelBuilder::with_builder(self)
+    }
+
+    /// Transition into building a [`Separator`](crate::Separator)
+    pub fn separator(self) -> SeparatorBuilder<'a, C> {
+        SeparatorBuilder::with_builder(self)
+    }
+
     /// Transition into building a [`TextEditor`](crate::TextEditor)
     pub fn text_editor(self) -> TextEditorBuilder<'a, C> {
         TextEditorBuilder::with_builder(self)
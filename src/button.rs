@@ -1,35 +1,53 @@
 use std::cell::RefCell;
+use std::f32::consts::PI;
 use std::sync::Arc;
 use std::sync::atomic::{self, AtomicBool};
 
+use basalt::image::ImageKey;
 use basalt::input::{MouseButton, WindowState};
 use basalt::interface::UnitValue::Pixels;
 use basalt::interface::{
-    Bin, BinStyle, Color, Position, TextAttrs, TextBody, TextHoriAlign, TextVertAlign, TextWrap,
+    Bin, BinStyle, BinVertex, Color, FontFamily, FontWeight, Position, TextAttrs, TextBody,
+    TextHoriAlign, TextVertAlign, TextWrap, Visibility,
 };
 use parking_lot::ReentrantMutex;
 
 use crate::builder::WidgetBuilder;
-use crate::{Theme, WidgetContainer, WidgetPlacement};
+use crate::spinner::arc_verts;
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
 
 /// Builder for [`Button`]
 pub struct ButtonBuilder<'a, C> {
     widget: WidgetBuilder<'a, C>,
     props: Properties,
-    on_press: Vec<Box<dyn FnMut(&Arc<Button>) + Send + 'static>>,
+    on_press: Vec<Box<dyn FnMut(&Arc<Button>, &WindowState) + Send + 'static>>,
+    on_hover: Vec<Box<dyn FnMut(&Arc<Button>) + Send + 'static>>,
+    on_unhover: Vec<Box<dyn FnMut(&Arc<Button>) + Send + 'static>>,
 }
 
-#[derive(Default)]
 struct Properties {
     text: String,
+    text_align: TextHoriAlign,
+    wrap: bool,
+    font: Option<(FontFamily, FontWeight)>,
+    back_colors: [Option<Color>; 3],
+    text_colors: [Option<Color>; 3],
     placement: WidgetPlacement,
+    plmt_is_default: bool,
 }
 
 impl Properties {
-    fn new(placement: WidgetPlacement) -> Self {
+    fn new(placement: WidgetPlacement, plmt_is_default: bool) -> Self {
         Self {
             text: String::new(),
+            text_align: TextHoriAlign::Center,
+            wrap: false,
+            font: None,
+            back_colors: [None; 3],
+            text_colors: [None; 3],
             placement,
+            plmt_is_default,
         }
     }
 }
@@ -39,15 +57,17 @@ where
     C: WidgetContainer,
 {
     pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
+        let plmt_is_default = builder.placement.is_none() && !builder.has_placement_mods();
+
         Self {
             props: Properties::new(
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| Button::default_placement(&builder.theme)),
+                builder.take_placement(Button::default_placement),
+                plmt_is_default,
             ),
             widget: builder,
             on_press: Vec::new(),
+            on_hover: Vec::new(),
+            on_unhover: Vec::new(),
         }
     }
 
@@ -60,17 +80,96 @@ where
         self
     }
 
+    /// Set the horizontal alignment of the text.
+    ///
+    /// **Note**: When this isn't used the text will be centered.
+    pub fn text_align(mut self, text_align: TextHoriAlign) -> Self {
+        self.props.text_align = text_align;
+        self
+    }
+
+    /// Set if the text should wrap onto multiple lines.
+    ///
+    /// When enabled, the height of the [`Button`] grows to fit the wrapped text. The height of
+    /// the placement is used as the minimum.
+    ///
+    /// **Note**: When this isn't used the text is kept to a single line.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.props.wrap = wrap;
+        self
+    }
+
+    /// Set the font used, overriding the font of the theme.
+    ///
+    /// **Note**: When this isn't used the theme's font is used.
+    pub fn font(mut self, family: FontFamily, weight: FontWeight) -> Self {
+        self.props.font = Some((family, weight));
+        self
+    }
+
+    /// Set the background colors used when idle, hovered and pressed.
+    ///
+    /// **Note**: Any color that is `None` uses the theme's color, which are `back3`, `accent1` and
+    /// `accent2` respectively.
+    pub fn colors(
+        mut self,
+        idle: Option<Color>,
+        hover: Option<Color>,
+        pressed: Option<Color>,
+    ) -> Self {
+        self.props.back_colors = [idle, hover, pressed];
+        self
+    }
+
+    /// Set the text colors used when idle, hovered and pressed.
+    ///
+    /// **Note**: Any color that is `None` uses the theme's color, which are `text1a`, `text1b` and
+    /// `text1b` respectively.
+    pub fn text_colors(
+        mut self,
+        idle: Option<Color>,
+        hover: Option<Color>,
+        pressed: Option<Color>,
+    ) -> Self {
+        self.props.text_colors = [idle, hover, pressed];
+        self
+    }
+
     /// Add a callback to be called when the [`Button`] is pressed.
     ///
+    /// The [`WindowState`] of the press is provided, allowing modifier keys to be checked.
+    ///
     /// **Panics**: When adding a callback within the callback.
     pub fn on_press<F>(mut self, on_press: F) -> Self
     where
-        F: FnMut(&Arc<Button>) + Send + 'static,
+        F: FnMut(&Arc<Button>, &WindowState) + Send + 'static,
     {
         self.on_press.push(Box::new(on_press));
         self
     }
 
+    /// Add a callback to be called when the cursor enters the [`Button`].
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_hover<F>(mut self, on_hover: F) -> Self
+    where
+        F: FnMut(&Arc<Button>) + Send + 'static,
+    {
+        self.on_hover.push(Box::new(on_hover));
+        self
+    }
+
+    /// Add a callback to be called when the cursor leaves the [`Button`].
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_unhover<F>(mut self, on_unhover: F) -> Self
+    where
+        F: FnMut(&Arc<Button>) + Send + 'static,
+    {
+        self.on_unhover.push(Box::new(on_unhover));
+        self
+    }
+
     /// Finish building the [`Button`].
     pub fn build(self) -> Arc<Button> {
         let window = self
@@ -88,60 +187,112 @@ where
             .add_child(container.clone());
 
         let button = Arc::new(Button {
-            theme: self.widget.theme,
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::Button),
             props: self.props,
             container,
             state: ReentrantMutex::new(State {
+                scale: RefCell::new(1.0),
+                busy: RefCell::new(BusyState {
+                    busy: false,
+                    run: false,
+                    angle: 0.0,
+                }),
                 on_press: RefCell::new(self.on_press),
+                on_hover: RefCell::new(self.on_hover),
+                on_unhover: RefCell::new(self.on_unhover),
             }),
         });
 
         let cb_button = button.clone();
+        let button_wk = Arc::downgrade(&button);
+        let leave_button_wk = button_wk.clone();
+        let colors_button_wk = button_wk.clone();
 
-        button_hooks(
+        button_hooks_with(
             &button.container,
-            BtnHookColors {
-                text_clr: Some(button.theme.colors.text1a),
-                back_clr: Some(button.theme.colors.back3),
-                h_text_clr: Some(button.theme.colors.text1b),
-                h_back_clr: Some(button.theme.colors.accent1),
-                p_text_clr: Some(button.theme.colors.text1b),
-                p_back_clr: Some(button.theme.colors.accent2),
-                ..Default::default()
+            move || {
+                colors_button_wk
+                    .upgrade()
+                    .map(|button| button.hook_colors())
+                    .unwrap_or_default()
             },
-            move |_| {
-                let state = cb_button.state.lock();
-
-                for on_press in state.on_press.borrow_mut().iter_mut() {
-                    on_press(&cb_button);
-                }
+            BtnHookCallbacks {
+                on_enter: Some(Box::new(move |_| {
+                    if let Some(button) = button_wk.upgrade() {
+                        let state = button.state.lock();
+
+                        for on_hover in state.on_hover.borrow_mut().iter_mut() {
+                            on_hover(&button);
+                        }
+                    }
+                })),
+                on_leave: Some(Box::new(move |_| {
+                    if let Some(button) = leave_button_wk.upgrade() {
+                        let state = button.state.lock();
+
+                        for on_unhover in state.on_unhover.borrow_mut().iter_mut() {
+                            on_unhover(&button);
+                        }
+                    }
+                })),
+                on_press: Some(Box::new(move |w_state| {
+                    let state = cb_button.state.lock();
+
+                    if state.busy.borrow().busy {
+                        return;
+                    }
+
+                    for on_press in state.on_press.borrow_mut().iter_mut() {
+                        on_press(&cb_button, w_state);
+                    }
+                })),
+                ..Default::default()
             },
         );
 
         button.style_update();
+        self.widget.container.widget_built(button.clone());
         button
     }
 }
 
 /// Button widget.
 pub struct Button {
-    theme: Theme,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     container: Arc<Bin>,
     state: ReentrantMutex<State>,
 }
 
 struct State {
-    on_press: RefCell<Vec<Box<dyn FnMut(&Arc<Button>) + Send + 'static>>>,
+    scale: RefCell<f32>,
+    busy: RefCell<BusyState>,
+    on_press: RefCell<Vec<Box<dyn FnMut(&Arc<Button>, &WindowState) + Send + 'static>>>,
+    on_hover: RefCell<Vec<Box<dyn FnMut(&Arc<Button>) + Send + 'static>>>,
+    on_unhover: RefCell<Vec<Box<dyn FnMut(&Arc<Button>) + Send + 'static>>>,
+}
+
+struct BusyState {
+    busy: bool,
+    run: bool,
+    angle: f32,
 }
 
 impl Button {
     /// Add a callback to be called when the [`Button`] is pressed.
     ///
+    /// The [`WindowState`] of the press is provided, allowing modifier keys to be checked.
+    ///
     /// **Panics**: When adding a callback within the callback.
     pub fn on_press<F>(&self, on_press: F)
     where
-        F: FnMut(&Arc<Button>) + Send + 'static,
+        F: FnMut(&Arc<Button>, &WindowState) + Send + 'static,
     {
         self.state
             .lock()
@@ -150,6 +301,161 @@ impl Button {
             .push(Box::new(on_press));
     }
 
+    /// Add a callback to be called when the cursor enters the [`Button`].
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_hover<F>(&self, on_hover: F)
+    where
+        F: FnMut(&Arc<Button>) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_hover
+            .borrow_mut()
+            .push(Box::new(on_hover));
+    }
+
+    /// Add a callback to be called when the cursor leaves the [`Button`].
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_unhover<F>(&self, on_unhover: F)
+    where
+        F: FnMut(&Arc<Button>) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_unhover
+            .borrow_mut()
+            .push(Box::new(on_unhover));
+    }
+
+    /// Set if the [`Button`] is busy.
+    ///
+    /// While busy, the text is replaced with a spinner and presses are ignored.
+    ///
+    /// **Note**: Hover and press colors are still displayed while busy.
+    pub fn set_busy(self: &Arc<Self>, busy: bool) {
+        let state = self.state.lock();
+
+        {
+            let mut busy_state = state.busy.borrow_mut();
+
+            if busy_state.busy == busy {
+                return;
+            }
+
+            busy_state.busy = busy;
+
+            if busy && !busy_state.run {
+                busy_state.run = true;
+                self.run_spinner();
+            }
+        }
+
+        self.container.style_modify(|style| {
+            style.text_body.spans = TextBody::from(self.current_text()).spans;
+        });
+    }
+
+    /// Check if the [`Button`] is busy.
+    pub fn is_busy(&self) -> bool {
+        self.state.lock().busy.borrow().busy
+    }
+
+    /// Re-apply the layout and styling of this [`Button`].
+    ///
+    /// **Note**: The size is defined by the placement and not the text, unless
+    /// [`ButtonBuilder::wrap`] is enabled.
+    pub fn refresh_layout(&self) {
+        self.style_update();
+    }
+
+    /// Set the scale of this [`Button`] and re-apply its styling.
+    ///
+    /// The sizes of the [`Theme`] are multiplied by `scale` as with [`Theme::scaled`]. This is
+    /// intended to be called when the application changes its scaling, such as when the window
+    /// moves to a monitor with a different scale and the application wants to adjust beyond what
+    /// Basalt applies itself.
+    ///
+    /// **Notes**:
+    /// - The default placement is rescaled. A placement provided to the builder, including one
+    ///   modified with [`WidgetBuilder::with_size`] and similar, is used as-is.
+    /// - A `scale` of `1.0` restores the original sizes.
+    pub fn set_scale(&self, scale: f32) {
+        *self.state.lock().scale.borrow_mut() = scale;
+        self.style_update();
+    }
+
+    /// Obtain the current scale of this [`Button`].
+    pub fn scale(&self) -> f32 {
+        *self.state.lock().scale.borrow()
+    }
+
+    /// Give keyboard focus to this [`Button`].
+    pub fn focus(&self) {
+        crate::focus_bin(&self.container);
+    }
+
+    /// Remove keyboard focus from this [`Button`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`Button`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.container);
+    }
+
+    /// Set if this [`Button`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`Button`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`Button`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`Button`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::Button`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
     pub fn default_placement(theme: &Theme) -> WidgetPlacement {
         let height = theme.spacing + theme.base_size;
@@ -167,37 +473,152 @@ impl Button {
         }
     }
 
+    fn back_colors(&self) -> [Color; 3] {
+        let [idle, hover, pressed] = self.props.back_colors;
+
+        [
+            idle.unwrap_or(self.theme.get().colors.back3),
+            hover.unwrap_or(self.theme.get().colors.accent1),
+            pressed.unwrap_or(self.theme.get().colors.accent2),
+        ]
+    }
+
+    fn hook_colors(&self) -> BtnHookColors {
+        let [back_clr, h_back_clr, p_back_clr] = self.back_colors();
+        let [text_clr, h_text_clr, p_text_clr] = self.text_colors();
+
+        BtnHookColors {
+            text_clr: Some(text_clr),
+            back_clr: Some(back_clr),
+            h_text_clr: Some(h_text_clr),
+            h_back_clr: Some(h_back_clr),
+            p_text_clr: Some(p_text_clr),
+            p_back_clr: Some(p_back_clr),
+            ..Default::default()
+        }
+    }
+
+    fn text_colors(&self) -> [Color; 3] {
+        let [idle, hover, pressed] = self.props.text_colors;
+
+        [
+            idle.unwrap_or(self.theme.get().colors.text1a),
+            hover.unwrap_or(self.theme.get().colors.text1b),
+            pressed.unwrap_or(self.theme.get().colors.text1b),
+        ]
+    }
+
+    fn current_text(&self) -> String {
+        if self.is_busy() {
+            String::new()
+        } else {
+            self.props.text.clone()
+        }
+    }
+
+    fn run_spinner(self: &Arc<Self>) {
+        if let Some(window) = self.container.window() {
+            let button_wk = Arc::downgrade(self);
+
+            window.renderer_on_frame(move |elapsed_op| {
+                let button = match button_wk.upgrade() {
+                    Some(some) => some,
+                    None => return false,
+                };
+
+                let state = button.state.lock();
+                let mut busy_state = state.busy.borrow_mut();
+
+                if !busy_state.busy {
+                    busy_state.run = false;
+
+                    button.container.style_modify(|style| {
+                        style.user_vertexes.clear();
+                    });
+
+                    return false;
+                }
+
+                if let Some(elapsed) = elapsed_op {
+                    busy_state.angle = (busy_state.angle
+                        + (elapsed.as_micros() as f32 / 1000.0 * SPINNER_SPEED))
+                        % (PI * 2.0);
+                }
+
+                let vertexes = button.spinner_verts(busy_state.angle);
+
+                button.container.style_modify(|style| {
+                    style.user_vertexes = vec![(ImageKey::INVALID, vertexes)];
+                });
+
+                true
+            });
+        }
+    }
+
+    fn spinner_verts(&self, angle: f32) -> Vec<BinVertex> {
+        let bpu = self.container.post_update();
+        let width = bpu.tri[0] - bpu.tli[0];
+        let height = bpu.bli[1] - bpu.tli[1];
+
+        arc_verts(
+            [width / 2.0, height / 2.0],
+            width.min(height) * 0.3,
+            angle,
+            self.theme.get().colors.text1a,
+        )
+    }
+
     fn style_update(&self) {
+        let theme = self.theme.get().scaled(self.scale());
+
+        let placement = if self.props.plmt_is_default {
+            Self::default_placement(&theme)
+        } else {
+            self.props.placement.clone()
+        };
+
+        let (font_family, font_weight) = self
+            .props
+            .font
+            .clone()
+            .unwrap_or_else(|| (theme.font_family.clone(), theme.label_font_weight()));
+
         let mut container_style = BinStyle {
-            back_color: self.theme.colors.back3,
+            back_color: self.back_colors()[0],
             text_body: TextBody {
-                hori_align: TextHoriAlign::Center,
+                hori_align: self.props.text_align,
                 vert_align: TextVertAlign::Center,
-                text_wrap: TextWrap::None,
+                text_wrap: if self.props.wrap {
+                    TextWrap::Normal
+                } else {
+                    TextWrap::None
+                },
                 base_attrs: TextAttrs {
-                    height: Pixels(self.theme.text_height),
-                    color: self.theme.colors.text1a,
-                    font_family: self.theme.font_family.clone(),
-                    font_weight: self.theme.font_weight,
+                    height: Pixels(theme.text_height),
+                    color: self.text_colors()[0],
+                    font_family,
+                    font_weight,
                     ..Default::default()
                 },
-                ..TextBody::from(self.props.text.clone())
+                ..TextBody::from(self.current_text())
             },
-            ..self.props.placement.clone().into_style()
+            visibility: self.container.style_inspect(|style| style.visibility),
+            ..placement.into_style()
         };
 
-        if let Some(border_size) = self.theme.border {
+        if let Some(border_size) = theme.border {
             container_style.border_size_t = Pixels(border_size);
             container_style.border_size_b = Pixels(border_size);
             container_style.border_size_l = Pixels(border_size);
             container_style.border_size_r = Pixels(border_size);
-            container_style.border_color_t = self.theme.colors.border1;
-            container_style.border_color_b = self.theme.colors.border1;
-            container_style.border_color_l = self.theme.colors.border1;
-            container_style.border_color_r = self.theme.colors.border1;
+            container_style.border_color_t = theme.colors.border1;
+            container_style.border_color_b = theme.colors.border1;
+            container_style.border_color_l = theme.colors.border1;
+            container_style.border_color_r = theme.colors.border1;
         }
 
-        if let Some(border_radius) = self.theme.roundness {
+        if let Some(border_radius) = theme.roundness {
             container_style.border_radius_tl = Pixels(border_radius);
             container_style.border_radius_tr = Pixels(border_radius);
             container_style.border_radius_bl = Pixels(border_radius);
@@ -205,9 +626,26 @@ impl Button {
         }
 
         self.container.style_update(container_style).expect_valid();
+
+        if self.props.wrap {
+            // The overflow is only known once the text has been laid out.
+            self.container.on_update_once(|container, bpu| {
+                let overflow = container.calc_vert_overflow();
+
+                if overflow > 0.0 {
+                    let height = (bpu.bli[1] - bpu.tli[1]) + overflow;
+
+                    container.style_modify(|style| {
+                        style.height = Pixels(height);
+                    });
+                }
+            });
+        }
     }
 }
 
+const SPINNER_SPEED: f32 = 0.008;
+
 #[derive(Clone, Copy, Default)]
 pub(crate) struct BtnHookColors {
     pub text_clr: Option<Color>,
@@ -221,17 +659,53 @@ pub(crate) struct BtnHookColors {
     pub p_vert_clr: Option<Color>,
 }
 
-pub(crate) fn button_hooks<F>(button: &Arc<Bin>, colors: BtnHookColors, mut on_press: F)
+#[derive(Default)]
+pub(crate) struct BtnHookCallbacks {
+    pub on_enter: Option<Box<dyn FnMut(&WindowState) + Send + 'static>>,
+    pub on_leave: Option<Box<dyn FnMut(&WindowState) + Send + 'static>>,
+    pub on_press: Option<Box<dyn FnMut(&WindowState) + Send + 'static>>,
+    pub on_release: Option<Box<dyn FnMut(&WindowState) + Send + 'static>>,
+}
+
+/// Add the hover and press hooks of a button.
+///
+/// `colors` is called on each event, so that the colors follow changes to the theme.
+pub(crate) fn button_hooks<C, F>(button: &Arc<Bin>, colors: C, on_press: F)
 where
+    C: Fn() -> BtnHookColors + Send + Sync + 'static,
     F: FnMut(&WindowState) + Send + 'static,
 {
+    button_hooks_with(
+        button,
+        colors,
+        BtnHookCallbacks {
+            on_press: Some(Box::new(on_press)),
+            ..Default::default()
+        },
+    );
+}
+
+pub(crate) fn button_hooks_with<C>(button: &Arc<Bin>, colors: C, callbacks: BtnHookCallbacks)
+where
+    C: Fn() -> BtnHookColors + Send + Sync + 'static,
+{
+    let BtnHookCallbacks {
+        mut on_enter,
+        mut on_leave,
+        mut on_press,
+        mut on_release,
+    } = callbacks;
+
+    let colors = Arc::new(colors);
     let inside = Arc::new(AtomicBool::new(false));
     let pressed = Arc::new(AtomicBool::new(false));
+    let cb_colors = colors.clone();
     let cb_inside = inside.clone();
     let cb_pressed = pressed.clone();
 
-    button.on_enter(move |target, _| {
+    button.on_enter(move |target, w_state| {
         let button = target.into_bin().unwrap();
+        let colors = cb_colors();
         cb_inside.store(true, atomic::Ordering::SeqCst);
 
         if !cb_pressed.load(atomic::Ordering::SeqCst)
@@ -260,14 +734,20 @@ where
             button.style_update(style).expect_valid();
         }
 
+        if let Some(on_enter) = on_enter.as_mut() {
+            on_enter(w_state);
+        }
+
         Default::default()
     });
 
+    let cb_colors = colors.clone();
     let cb_inside = inside.clone();
     let cb_pressed = pressed.clone();
 
-    button.on_leave(move |target, _| {
+    button.on_leave(move |target, w_state| {
         let button = target.into_bin().unwrap();
+        let colors = cb_colors();
         cb_inside.store(false, atomic::Ordering::SeqCst);
 
         if !cb_pressed.load(atomic::Ordering::SeqCst)
@@ -296,13 +776,19 @@ where
             button.style_update(style).expect_valid();
         }
 
+        if let Some(on_leave) = on_leave.as_mut() {
+            on_leave(w_state);
+        }
+
         Default::default()
     });
 
+    let cb_colors = colors.clone();
     let cb_pressed = pressed.clone();
 
     button.on_press(MouseButton::Left, move |target, w_state, _| {
         let button = target.into_bin().unwrap();
+        let colors = cb_colors();
         cb_pressed.store(true, atomic::Ordering::SeqCst);
 
         if colors.p_text_clr.is_some() || colors.p_back_clr.is_some() || colors.p_vert_clr.is_some()
@@ -328,15 +814,20 @@ where
             button.style_update(style).expect_valid();
         }
 
-        on_press(w_state);
+        if let Some(on_press) = on_press.as_mut() {
+            on_press(w_state);
+        }
+
         Default::default()
     });
 
+    let cb_colors = colors;
     let cb_inside = inside;
     let cb_pressed = pressed;
 
-    button.on_release(MouseButton::Left, move |target, _, _| {
+    button.on_release(MouseButton::Left, move |target, w_state, _| {
         let button = target.into_bin().unwrap();
+        let colors = cb_colors();
         cb_pressed.store(false, atomic::Ordering::SeqCst);
 
         if cb_inside.load(atomic::Ordering::SeqCst)
@@ -385,6 +876,36 @@ where
             button.style_update(style).expect_valid();
         }
 
+        if let Some(on_release) = on_release.as_mut() {
+            on_release(w_state);
+        }
+
         Default::default()
     });
 }
+
+impl Widget for Button {
+    fn focus(&self) {
+        Button::focus(self)
+    }
+
+    fn blur(&self) {
+        Button::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        Button::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        Button::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        Button::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        Button::set_theme(self, theme)
+    }
+}
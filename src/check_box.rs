@@ -1,14 +1,24 @@
 use std::cell::RefCell;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 use basalt::image::ImageKey;
 use basalt::input::MouseButton;
 use basalt::interface::UnitValue::{Percent, Pixels};
-use basalt::interface::{Bin, BinStyle, BinVertex, Color, Position, Visibility};
+use basalt::interface::{Bin, BinID, BinStyle, BinVertex, Color, Position, Visibility};
 use parking_lot::ReentrantMutex;
 
 use crate::builder::WidgetBuilder;
-use crate::{Theme, WidgetContainer, WidgetPlacement};
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
+
+/// An error that can occur from methods on [`CheckBoxGroup`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckBoxError {
+    /// Requested an operation on a check box that isn't in the group.
+    NotInGroup,
+    /// Attempted to add a check box that was already in the group.
+    AlreadyInGroup,
+}
 
 /// Builder for [`CheckBox`]
 pub struct CheckBoxBuilder<'a, C, T> {
@@ -41,10 +51,7 @@ where
         Self {
             props: Properties::new(
                 value,
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| CheckBox::<()>::default_placement(&builder.theme)),
+                builder.take_placement(CheckBox::<()>::default_placement),
             ),
             widget: builder,
             selected: false,
@@ -62,8 +69,11 @@ where
 
     /// Add a callback to be called when the [`CheckBox`]'s state changed.
     ///
-    /// **Note**: When changing the state within the callback, no callbacks will be called with
-    /// the updated state.
+    /// **Notes**:
+    /// - This is only called when the state actually changes. Selecting an already selected
+    /// [`CheckBox`] will not call this.
+    /// - When changing the state within the callback, no callbacks will be called with the
+    /// updated state.
     ///
     /// **Panics**: When adding a callback within the callback.
     pub fn on_change<F>(mut self, on_change: F) -> Self
@@ -95,7 +105,10 @@ where
         container.add_child(fill.clone());
 
         let check_box = Arc::new(CheckBox {
-            theme: self.widget.theme,
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::CheckBox),
             props: self.props,
             container,
             fill,
@@ -122,13 +135,17 @@ where
         });
 
         check_box.style_update();
+        self.widget.container.widget_built(check_box.clone());
         check_box
     }
 }
 
 /// Check box widget
 pub struct CheckBox<T> {
-    theme: Theme,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties<T>,
     container: Arc<Bin>,
     fill: Arc<Bin>,
@@ -173,8 +190,11 @@ impl<T> CheckBox<T> {
 
     /// Add a callback to be called when the [`CheckBox`]'s selection changed.
     ///
-    /// **Note**: When changing the state within the callback, no callbacks add to this
-    /// [`CheckBox`] will be called with the updated state.
+    /// **Notes**:
+    /// - This is only called on actual transitions, e.g. [`select`](CheckBox::select) on an
+    /// already selected [`CheckBox`] will not call this.
+    /// - When changing the state within the callback, no callbacks add to this [`CheckBox`] will
+    /// be called with the updated state.
     ///
     /// **Panics**: When adding a callback within the callback to this [`CheckBox`].
     pub fn on_change<F>(&self, on_change: F)
@@ -213,6 +233,71 @@ impl<T> CheckBox<T> {
         }
     }
 
+    /// Give keyboard focus to this [`CheckBox`].
+    pub fn focus(&self) {
+        crate::focus_bin(&self.container);
+    }
+
+    /// Remove keyboard focus from this [`CheckBox`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`CheckBox`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.container);
+    }
+
+    /// Set if this [`CheckBox`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`CheckBox`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`CheckBox`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`CheckBox`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::CheckBox`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
     pub fn default_placement(theme: &Theme) -> WidgetPlacement {
         WidgetPlacement {
@@ -229,7 +314,8 @@ impl<T> CheckBox<T> {
 
     fn style_update(&self) {
         let mut container_style = BinStyle {
-            back_color: self.theme.colors.back2,
+            back_color: self.theme.get().colors.back2,
+            visibility: self.container.style_inspect(|style| style.visibility),
             ..self.props.placement.clone().into_style()
         };
 
@@ -241,23 +327,23 @@ impl<T> CheckBox<T> {
             pos_from_r: Pixels(0.0),
             user_vertexes: vec![(
                 ImageKey::INVALID,
-                check_symbol_verts(self.theme.colors.accent1),
+                check_symbol_verts(self.theme.get().colors.accent1),
             )],
             ..Default::default()
         };
 
-        if let Some(border_size) = self.theme.border {
+        if let Some(border_size) = self.theme.get().border {
             container_style.border_size_t = Pixels(border_size);
             container_style.border_size_b = Pixels(border_size);
             container_style.border_size_l = Pixels(border_size);
             container_style.border_size_r = Pixels(border_size);
-            container_style.border_color_t = self.theme.colors.border1;
-            container_style.border_color_b = self.theme.colors.border1;
-            container_style.border_color_l = self.theme.colors.border1;
-            container_style.border_color_r = self.theme.colors.border1;
+            container_style.border_color_t = self.theme.get().colors.border1;
+            container_style.border_color_b = self.theme.get().colors.border1;
+            container_style.border_color_l = self.theme.get().colors.border1;
+            container_style.border_color_r = self.theme.get().colors.border1;
         }
 
-        if let Some(radius) = self.theme.roundness {
+        if let Some(radius) = self.theme.get().roundness {
             container_style.border_radius_tl = Pixels(radius);
             container_style.border_radius_tr = Pixels(radius);
             container_style.border_radius_bl = Pixels(radius);
@@ -282,6 +368,268 @@ where
     }
 }
 
+struct GroupState<T> {
+    check_boxes: RefCell<Vec<Arc<CheckBox<T>>>>,
+    hooked: RefCell<Vec<Weak<CheckBox<T>>>>,
+    parent: RefCell<Option<(BinID, Box<dyn Fn(bool) + Send + 'static>)>>,
+    syncing: RefCell<bool>,
+    on_change: RefCell<Vec<Box<dyn FnMut(&[Arc<CheckBox<T>>]) + Send + 'static>>>,
+}
+
+/// Group of [`CheckBox`]'s
+///
+/// **Note**: This does not provide any styling, but exists purely for logic.
+pub struct CheckBoxGroup<T> {
+    state: ReentrantMutex<GroupState<T>>,
+}
+
+impl<T> CheckBoxGroup<T>
+where
+    T: Send + Sync + 'static,
+{
+    /// Create a new [`CheckBoxGroup`].
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            state: ReentrantMutex::new(GroupState {
+                check_boxes: RefCell::new(Vec::new()),
+                hooked: RefCell::new(Vec::new()),
+                parent: RefCell::new(None),
+                syncing: RefCell::new(false),
+                on_change: RefCell::new(Vec::new()),
+            }),
+        })
+    }
+
+    /// Obtain a list of [`CheckBox`]'s in this group.
+    pub fn check_boxes(&self) -> Vec<Arc<CheckBox<T>>> {
+        self.state.lock().check_boxes.borrow().clone()
+    }
+
+    /// Obtain a list of the selected [`CheckBox`]'s in this group.
+    pub fn selected(&self) -> Vec<Arc<CheckBox<T>>> {
+        self.state
+            .lock()
+            .check_boxes
+            .borrow()
+            .iter()
+            .filter(|check_box| check_box.is_selected())
+            .cloned()
+            .collect()
+    }
+
+    /// Add a [`CheckBox`] to this [`CheckBoxGroup`].
+    ///
+    /// **Panics**: When called within a callback of the [`CheckBox`] being added.
+    pub fn add(self: &Arc<Self>, check_box: &Arc<CheckBox<T>>) -> Result<(), CheckBoxError> {
+        let state = self.state.lock();
+
+        if state
+            .check_boxes
+            .borrow()
+            .iter()
+            .any(|existing| Arc::ptr_eq(existing, check_box))
+        {
+            return Err(CheckBoxError::AlreadyInGroup);
+        }
+
+        state.check_boxes.borrow_mut().push(check_box.clone());
+
+        // Callbacks can't be removed, so a check box that is re-added reuses its callback.
+        if !state
+            .hooked
+            .borrow()
+            .iter()
+            .any(|hooked| hooked.as_ptr() == Arc::as_ptr(check_box))
+        {
+            state.hooked.borrow_mut().push(Arc::downgrade(check_box));
+            let group_wk = Arc::downgrade(self);
+
+            check_box.on_change(move |check_box, _| {
+                if let Some(group) = group_wk.upgrade() {
+                    group.child_changed(check_box);
+                }
+            });
+        }
+
+        self.sync_parent();
+        Ok(())
+    }
+
+    /// Remove a [`CheckBox`] from this group.
+    pub fn remove(&self, check_box: &Arc<CheckBox<T>>) -> Result<(), CheckBoxError> {
+        let state = self.state.lock();
+        let mut check_boxes = state.check_boxes.borrow_mut();
+
+        match check_boxes
+            .iter()
+            .position(|existing| Arc::ptr_eq(existing, check_box))
+        {
+            Some(index) => {
+                check_boxes.remove(index);
+            },
+            None => return Err(CheckBoxError::NotInGroup),
+        }
+
+        drop(check_boxes);
+        self.sync_parent();
+        Ok(())
+    }
+
+    /// Select every [`CheckBox`] in this group.
+    pub fn check_all(&self) {
+        self.set_all(true);
+    }
+
+    /// Unselect every [`CheckBox`] in this group.
+    pub fn uncheck_all(&self) {
+        self.set_all(false);
+    }
+
+    /// Link a parent [`CheckBox`] to this group.
+    ///
+    /// The parent will be selected when every [`CheckBox`] in the group is selected. Selecting
+    /// the parent will select every [`CheckBox`] and unselecting it will unselect them.
+    ///
+    /// **Notes**:
+    /// - The parent should not be a member of the group.
+    /// - [`CheckBox`] has no indeterminate state, so a partial selection unselects the parent.
+    /// - Calling this multiple times will result in the previous parent being unlinked.
+    ///
+    /// **Panics**: When called within a callback of the parent [`CheckBox`].
+    pub fn link_parent<P>(self: &Arc<Self>, parent: &Arc<CheckBox<P>>)
+    where
+        P: Send + Sync + 'static,
+    {
+        let state = self.state.lock();
+        let parent_id = parent.container.id();
+        let cb_parent = parent.clone();
+
+        *state.parent.borrow_mut() = Some((
+            parent_id,
+            Box::new(move |selected| cb_parent.set_selected(selected)),
+        ));
+
+        let group_wk = Arc::downgrade(self);
+
+        parent.on_change(move |_, selected| {
+            if let Some(group) = group_wk.upgrade() {
+                group.parent_changed(parent_id, selected);
+            }
+        });
+
+        self.sync_parent();
+    }
+
+    /// Add a callback to be called when the selection of the group changed.
+    ///
+    /// The callback is called with the selected [`CheckBox`]'s.
+    ///
+    /// **Note**: When changing the state within the callback, no callbacks add to this
+    /// [`CheckBoxGroup`] will be called with the updated state. Callbacks added specify to
+    /// [`CheckBox`] will still be called.
+    ///
+    /// **Panics**: When adding a callback within the callback to this [`CheckBoxGroup`].
+    pub fn on_change<F>(&self, on_change: F)
+    where
+        F: FnMut(&[Arc<CheckBox<T>>]) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_change
+            .borrow_mut()
+            .push(Box::new(on_change));
+    }
+
+    fn set_all(&self, selected: bool) {
+        let state = self.state.lock();
+        let check_boxes = state.check_boxes.borrow().clone();
+
+        if check_boxes
+            .iter()
+            .all(|check_box| check_box.is_selected() == selected)
+        {
+            return;
+        }
+
+        *state.syncing.borrow_mut() = true;
+
+        for check_box in check_boxes.iter() {
+            check_box.set_selected(selected);
+        }
+
+        *state.syncing.borrow_mut() = false;
+        self.changed();
+    }
+
+    fn child_changed(&self, check_box: &Arc<CheckBox<T>>) {
+        let state = self.state.lock();
+
+        if *state.syncing.borrow()
+            || !state
+                .check_boxes
+                .borrow()
+                .iter()
+                .any(|existing| Arc::ptr_eq(existing, check_box))
+        {
+            return;
+        }
+
+        self.changed();
+    }
+
+    fn parent_changed(&self, parent_id: BinID, selected: bool) {
+        let state = self.state.lock();
+
+        if *state.syncing.borrow()
+            || state.parent.borrow().as_ref().map(|(id, _)| *id) != Some(parent_id)
+        {
+            return;
+        }
+
+        self.set_all(selected);
+    }
+
+    fn changed(&self) {
+        let state = self.state.lock();
+        self.sync_parent();
+        let selected = self.selected();
+
+        if let Ok(mut on_change_cbs) = state.on_change.try_borrow_mut() {
+            for on_change in on_change_cbs.iter_mut() {
+                on_change(&selected);
+            }
+        }
+    }
+
+    fn sync_parent(&self) {
+        let state = self.state.lock();
+
+        if let Some((_, set_parent)) = state.parent.borrow().as_ref() {
+            let check_boxes = state.check_boxes.borrow().clone();
+
+            let all_selected = !check_boxes.is_empty()
+                && check_boxes.iter().all(|check_box| check_box.is_selected());
+
+            *state.syncing.borrow_mut() = true;
+            set_parent(all_selected);
+            *state.syncing.borrow_mut() = false;
+        }
+    }
+}
+
+impl<T> CheckBoxGroup<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Obtain a copy of the values of the selected [`CheckBox`]'s.
+    pub fn selected_values(&self) -> Vec<T> {
+        self.selected()
+            .iter()
+            .map(|check_box| check_box.value())
+            .collect()
+    }
+}
+
 fn check_symbol_verts(color: Color) -> Vec<BinVertex> {
     const UNIT_POS: [[f32; 2]; 6] = [
         [0.912, 0.131],
@@ -305,3 +653,32 @@ fn check_symbol_verts(color: Color) -> Vec<BinVertex> {
 
     verts
 }
+
+impl<T> Widget for CheckBox<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn focus(&self) {
+        CheckBox::focus(self)
+    }
+
+    fn blur(&self) {
+        CheckBox::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        CheckBox::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        CheckBox::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        CheckBox::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        CheckBox::set_theme(self, theme)
+    }
+}
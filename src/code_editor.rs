@@ -3,12 +3,16 @@ use std::sync::Arc;
 use basalt::input::InputHookCtrl;
 use basalt::interface::UnitValue::Pixels;
 use basalt::interface::{
-    Bin, BinPostUpdate, BinStyle, FontFamily, Position, TextAttrs, TextBody, TextCursor,
-    TextHoriAlign, TextSpan, TextWrap,
+    Bin, BinPostUpdate, BinStyle, Color, FontFamily, FontWeight, Position, TextAttrs, TextBody,
+    TextCursor, TextHoriAlign, TextSpan, TextWrap, Visibility,
 };
 
 use crate::builder::WidgetBuilder;
-use crate::{ScrollAxis, ScrollBar, Theme, WidgetContainer, WidgetPlacement, text_hooks, ulps_eq};
+use crate::theme::ThemeCell;
+use crate::{
+    ScrollAxis, ScrollBar, ScrollSource, Theme, Widget, WidgetContainer, WidgetPlacement,
+    WidgetRole, text_hooks, ulps_eq,
+};
 
 /// Builder for [`CodeEditor`]
 pub struct CodeEditorBuilder<'a, C> {
@@ -19,12 +23,14 @@ pub struct CodeEditorBuilder<'a, C> {
 
 #[derive(Default)]
 struct Properties {
+    font: Option<(FontFamily, FontWeight)>,
     placement: WidgetPlacement,
 }
 
 impl Properties {
     fn new(placement: WidgetPlacement) -> Self {
         Self {
+            font: None,
             placement,
         }
     }
@@ -36,21 +42,21 @@ where
 {
     pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
         Self {
-            props: Properties::new(
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| CodeEditor::default_placement(&builder.theme)),
-            ),
+            props: Properties::new(builder.take_placement(CodeEditor::default_placement)),
             text_body: TextBody {
                 base_attrs: TextAttrs {
                     color: builder.theme.colors.text1a,
                     height: Pixels(builder.theme.text_height),
                     font_family: FontFamily::Monospace,
+                    font_weight: builder.theme.value_font_weight(),
                     ..Default::default()
                 },
                 text_wrap: TextWrap::None,
                 spans: vec![TextSpan::default()],
+                selection_color: Color {
+                    a: 0.5,
+                    ..builder.theme.colors.accent1
+                },
                 ..Default::default()
             },
             widget: builder,
@@ -66,6 +72,46 @@ where
         self
     }
 
+    /// Set the initial [`TextBody`].
+    ///
+    /// Unlike [`with_text`](Self::with_text), this keeps multiple spans along with their
+    /// attributes, allowing the initial text to be pre-colored.
+    ///
+    /// **Note**: The base attributes of the provided body are replaced with those from the theme
+    /// or those provided by [`with_attrs`](Self::with_attrs).
+    pub fn with_body(mut self, body: TextBody) -> Self {
+        let base_attrs = self.text_body.base_attrs.clone();
+        let selection_color = self.text_body.selection_color;
+        self.text_body = body;
+        self.text_body.base_attrs = base_attrs;
+        self.text_body.selection_color = selection_color;
+
+        if self.text_body.spans.is_empty() {
+            self.text_body.spans.push(TextSpan::default());
+        }
+
+        self
+    }
+
+    /// Set the font used, overriding the default monospace font.
+    ///
+    /// The font is also used by the line numbers and status bar.
+    ///
+    /// **Note**: This takes precedence over the font of the [`TextAttrs`] provided by
+    /// [`with_attrs`](Self::with_attrs).
+    pub fn font(mut self, family: FontFamily, weight: FontWeight) -> Self {
+        self.props.font = Some((family, weight));
+        self
+    }
+
+    /// Set the color used to highlight selected text.
+    ///
+    /// **Note**: When this isn't used the theme's `accent1` at half opacity is used.
+    pub fn selection_color(mut self, color: Color) -> Self {
+        self.text_body.selection_color = color;
+        self
+    }
+
     /// Set the [`TextAttrs`] used.
     pub fn with_attrs(mut self, attrs: TextAttrs) -> Self {
         self.text_body.base_attrs = attrs;
@@ -129,7 +175,10 @@ where
             .add_child(container.clone());
 
         let code_editor = Arc::new(CodeEditor {
-            theme: self.widget.theme,
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::TextBox),
             props: self.props,
             container,
             editor,
@@ -145,7 +194,7 @@ where
         text_hooks::create(
             text_hooks::Properties::CODE_EDITOR,
             code_editor.editor.clone(),
-            code_editor.theme.clone(),
+            code_editor.theme.get().clone(),
             Some(Arc::new(move |updated| {
                 let text_hooks::Updated {
                     cursor: _,
@@ -189,7 +238,9 @@ where
             })),
             Some(Arc::new(move |amt| {
                 if let Some(code_editor) = code_editor_wk2.upgrade() {
-                    code_editor.v_scroll_b.scroll(amt);
+                    code_editor
+                        .v_scroll_b
+                        .scroll_from(amt, ScrollSource::Keyboard);
                 }
             })),
         );
@@ -224,7 +275,7 @@ where
                 None => return InputHookCtrl::Remove,
             };
 
-            let theme = &code_editor.theme;
+            let theme = &code_editor.theme.get();
 
             if theme.border.is_some() {
                 code_editor.container.style_modify(|style| {
@@ -246,7 +297,7 @@ where
                 None => return InputHookCtrl::Remove,
             };
 
-            let theme = &code_editor.theme;
+            let theme = &code_editor.theme.get();
 
             if theme.border.is_some() {
                 code_editor.container.style_modify(|style| {
@@ -265,13 +316,17 @@ where
         });
 
         code_editor.style_update(Some(self.text_body));
+        self.widget.container.widget_built(code_editor.clone());
         code_editor
     }
 }
 
 /// Text editor widget.
 pub struct CodeEditor {
-    theme: Theme,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     container: Arc<Bin>,
     editor: Arc<Bin>,
@@ -304,6 +359,106 @@ impl CodeEditor {
         });
     }
 
+    /// Set the color used to highlight selected text.
+    pub fn set_selection_color(&self, color: Color) {
+        self.editor.style_modify(|style| {
+            style.text_body.selection_color = color;
+        });
+    }
+
+    /// Obtain the color used to highlight selected text.
+    pub fn selection_color(&self) -> Color {
+        self.editor
+            .style_inspect(|style| style.text_body.selection_color)
+    }
+
+    /// Obtain the [`ScrollBar`] that scrolls the text vertically.
+    ///
+    /// **Note**: The [`CodeEditor`] scrolls this to keep the cursor in view, so scrolling it
+    /// elsewhere may be undone when the cursor moves.
+    pub fn vertical_scroll_bar(&self) -> &Arc<ScrollBar> {
+        &self.v_scroll_b
+    }
+
+    /// Obtain the [`ScrollBar`] that scrolls the text horizontally.
+    ///
+    /// **Note**: Like [`vertical_scroll_bar`](Self::vertical_scroll_bar), this is scrolled to
+    /// keep the cursor in view.
+    pub fn horizontal_scroll_bar(&self) -> &Arc<ScrollBar> {
+        &self.h_scroll_b
+    }
+
+    /// Give keyboard focus to this [`CodeEditor`].
+    ///
+    /// This allows text to be entered without clicking on it first.
+    pub fn focus(&self) {
+        crate::focus_bin(&self.editor);
+    }
+
+    /// Remove keyboard focus from this [`CodeEditor`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`CodeEditor`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.editor);
+    }
+
+    /// Set if this [`CodeEditor`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`CodeEditor`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`CodeEditor`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`CodeEditor`] and re-apply its styling.
+    ///
+    /// **Note**: The [`TextAttrs`] and selection color of the text are kept.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.v_scroll_b.set_theme(theme.clone());
+        self.h_scroll_b.set_theme(theme.clone());
+        self.theme.set(theme);
+        self.style_update(None);
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::TextBox`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
     pub fn default_placement(theme: &Theme) -> WidgetPlacement {
         let height = theme.spacing + (theme.base_size * 9.0);
@@ -362,45 +517,65 @@ impl CodeEditor {
 
     fn style_update(&self, text_body_op: Option<TextBody>) {
         let mut container_style = self.props.placement.clone().into_style();
-        container_style.back_color = self.theme.colors.back3;
+        container_style.visibility = self.container.style_inspect(|style| style.visibility);
+        container_style.back_color = self.theme.get().colors.back3;
         let mut editor_style = BinStyle::default();
 
-        if let Some(text_body) = text_body_op {
-            editor_style.text_body = text_body;
+        let (font_family, font_weight) = self
+            .props
+            .font
+            .clone()
+            .unwrap_or_else(|| (FontFamily::Monospace, self.theme.get().value_font_weight()));
+
+        // Without a new text body the current one is kept.
+        let mut text_body = match text_body_op {
+            Some(text_body) => text_body,
+            None => self.editor.style_inspect(|style| style.text_body.clone()),
+        };
+
+        if self.props.font.is_some() {
+            text_body.base_attrs.font_family = font_family.clone();
+            text_body.base_attrs.font_weight = font_weight;
         }
 
-        let status_bar_h = self.theme.base_size + self.theme.spacing;
-        let line_numbers_w = self.theme.base_size * 2.0;
-        let border_size = self.theme.border.unwrap_or(0.0);
+        editor_style.text_body = text_body;
+
+        let status_bar_h = self.theme.get().base_size + self.theme.get().spacing;
+        let line_numbers_w = self.theme.get().base_size * 2.0;
+        let border_size = self.theme.get().border.unwrap_or(0.0);
 
         editor_style.position = Position::Relative;
         editor_style.pos_from_t = Pixels(0.0);
-        editor_style.pos_from_b = ScrollBar::default_placement(&self.theme, ScrollAxis::X)
+        editor_style.pos_from_b = ScrollBar::default_placement(&self.theme.get(), ScrollAxis::X)
             .height
-            .offset_pixels(self.theme.base_size + self.theme.spacing + (border_size * 2.0));
+            .offset_pixels(
+                self.theme.get().base_size + self.theme.get().spacing + (border_size * 2.0),
+            );
         editor_style.pos_from_l = Pixels(line_numbers_w + border_size);
-        editor_style.pos_from_r = ScrollBar::default_placement(&self.theme, ScrollAxis::Y).width;
-        editor_style.back_color = self.theme.colors.back2;
-        editor_style.padding_t = Pixels(self.theme.spacing);
-        editor_style.padding_b = Pixels(self.theme.spacing);
-        editor_style.padding_l = Pixels(self.theme.spacing);
-        editor_style.padding_r = Pixels(self.theme.spacing);
+        editor_style.pos_from_r =
+            ScrollBar::default_placement(&self.theme.get(), ScrollAxis::Y).width;
+        editor_style.back_color = self.theme.get().colors.back2;
+        editor_style.padding_t = Pixels(self.theme.get().spacing);
+        editor_style.padding_b = Pixels(self.theme.get().spacing);
+        editor_style.padding_l = Pixels(self.theme.get().spacing);
+        editor_style.padding_r = Pixels(self.theme.get().spacing);
 
         let mut status_bar_style = BinStyle {
             pos_from_b: Pixels(0.0),
             pos_from_l: Pixels(0.0),
             pos_from_r: Pixels(0.0),
             height: Pixels(status_bar_h),
-            padding_t: Pixels(self.theme.spacing / 2.0),
-            padding_b: Pixels(self.theme.spacing / 2.0),
-            padding_l: Pixels(self.theme.spacing),
-            padding_r: Pixels(self.theme.spacing),
-            back_color: self.theme.colors.back2,
+            padding_t: Pixels(self.theme.get().spacing / 2.0),
+            padding_b: Pixels(self.theme.get().spacing / 2.0),
+            padding_l: Pixels(self.theme.get().spacing),
+            padding_r: Pixels(self.theme.get().spacing),
+            back_color: self.theme.get().colors.back2,
             text_body: TextBody {
                 base_attrs: TextAttrs {
-                    color: self.theme.colors.text1a,
-                    height: Pixels(self.theme.text_height),
-                    font_family: FontFamily::Monospace,
+                    color: self.theme.get().colors.text1a,
+                    height: Pixels(self.theme.get().text_height),
+                    font_family: font_family.clone(),
+                    font_weight,
                     ..Default::default()
                 },
                 hori_align: TextHoriAlign::Right,
@@ -430,21 +605,24 @@ impl CodeEditor {
 
         let mut line_numbers_style = BinStyle {
             pos_from_t: Pixels(0.0),
-            pos_from_b: ScrollBar::default_placement(&self.theme, ScrollAxis::X)
+            pos_from_b: ScrollBar::default_placement(&self.theme.get(), ScrollAxis::X)
                 .height
-                .offset_pixels(self.theme.base_size + self.theme.spacing + (border_size * 2.0)),
+                .offset_pixels(
+                    self.theme.get().base_size + self.theme.get().spacing + (border_size * 2.0),
+                ),
             pos_from_l: Pixels(0.0),
             width: Pixels(line_numbers_w),
-            back_color: self.theme.colors.back2,
-            padding_t: Pixels(self.theme.spacing),
-            padding_b: Pixels(self.theme.spacing),
-            padding_l: Pixels(self.theme.spacing / 2.0),
-            padding_r: Pixels(self.theme.spacing / 2.0),
+            back_color: self.theme.get().colors.back2,
+            padding_t: Pixels(self.theme.get().spacing),
+            padding_b: Pixels(self.theme.get().spacing),
+            padding_l: Pixels(self.theme.get().spacing / 2.0),
+            padding_r: Pixels(self.theme.get().spacing / 2.0),
             text_body: TextBody {
                 base_attrs: TextAttrs {
-                    color: self.theme.colors.text1a,
-                    height: Pixels(self.theme.text_height),
-                    font_family: FontFamily::Monospace,
+                    color: self.theme.get().colors.text1a,
+                    height: Pixels(self.theme.get().text_height),
+                    font_family,
+                    font_weight,
                     ..Default::default()
                 },
                 hori_align: TextHoriAlign::Right,
@@ -454,29 +632,29 @@ impl CodeEditor {
             ..Default::default()
         };
 
-        if let Some(border_size) = self.theme.border {
+        if let Some(border_size) = self.theme.get().border {
             container_style.border_size_t = Pixels(border_size);
             container_style.border_size_b = Pixels(border_size);
             container_style.border_size_l = Pixels(border_size);
             container_style.border_size_r = Pixels(border_size);
-            container_style.border_color_t = self.theme.colors.border1;
-            container_style.border_color_b = self.theme.colors.border1;
-            container_style.border_color_l = self.theme.colors.border1;
-            container_style.border_color_r = self.theme.colors.border1;
+            container_style.border_color_t = self.theme.get().colors.border1;
+            container_style.border_color_b = self.theme.get().colors.border1;
+            container_style.border_color_l = self.theme.get().colors.border1;
+            container_style.border_color_r = self.theme.get().colors.border1;
 
             editor_style.border_size_l = Pixels(border_size);
-            editor_style.border_color_l = self.theme.colors.border2;
+            editor_style.border_color_l = self.theme.get().colors.border2;
             editor_style.border_size_b = Pixels(border_size);
-            editor_style.border_color_b = self.theme.colors.border2;
+            editor_style.border_color_b = self.theme.get().colors.border2;
 
             line_numbers_style.border_size_b = Pixels(border_size);
-            line_numbers_style.border_color_b = self.theme.colors.border2;
+            line_numbers_style.border_color_b = self.theme.get().colors.border2;
 
             status_bar_style.border_size_t = Pixels(border_size);
-            status_bar_style.border_color_t = self.theme.colors.border2;
+            status_bar_style.border_color_t = self.theme.get().colors.border2;
         }
 
-        if let Some(border_radius) = self.theme.roundness {
+        if let Some(border_radius) = self.theme.get().roundness {
             container_style.border_radius_tl = Pixels(border_radius);
             container_style.border_radius_tr = Pixels(border_radius);
             container_style.border_radius_bl = Pixels(border_radius);
@@ -496,3 +674,29 @@ impl CodeEditor {
         ]);
     }
 }
+
+impl Widget for CodeEditor {
+    fn focus(&self) {
+        CodeEditor::focus(self)
+    }
+
+    fn blur(&self) {
+        CodeEditor::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        CodeEditor::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        CodeEditor::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        CodeEditor::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        CodeEditor::set_theme(self, theme)
+    }
+}
@@ -0,0 +1,313 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use basalt::input::WindowState;
+use basalt::interface::UnitValue::Pixels;
+use basalt::interface::{Bin, BinStyle, Color, Position, Visibility};
+use parking_lot::ReentrantMutex;
+
+use crate::builder::WidgetBuilder;
+use crate::button::{BtnHookColors, button_hooks};
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
+
+/// Builder for [`ColorSwatch`]
+pub struct ColorSwatchBuilder<'a, C> {
+    widget: WidgetBuilder<'a, C>,
+    props: Properties,
+    on_press: Vec<Box<dyn FnMut(&Arc<ColorSwatch>, &WindowState) + Send + 'static>>,
+}
+
+struct Properties {
+    color: Color,
+    placement: WidgetPlacement,
+}
+
+impl<'a, C> ColorSwatchBuilder<'a, C>
+where
+    C: WidgetContainer,
+{
+    pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
+        Self {
+            props: Properties {
+                color: builder.theme.colors.accent1,
+                placement: builder.take_placement(ColorSwatch::default_placement),
+            },
+            widget: builder,
+            on_press: Vec::new(),
+        }
+    }
+
+    /// Set the initial color.
+    ///
+    /// **Note**: When this isn't used the color will be the theme's `accent1`.
+    pub fn color(mut self, color: Color) -> Self {
+        self.props.color = color;
+        self
+    }
+
+    /// Add a callback to be called when the [`ColorSwatch`] is pressed.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_press<F>(mut self, on_press: F) -> Self
+    where
+        F: FnMut(&Arc<ColorSwatch>, &WindowState) + Send + 'static,
+    {
+        self.on_press.push(Box::new(on_press));
+        self
+    }
+
+    /// Finish building the [`ColorSwatch`].
+    pub fn build(self) -> Arc<ColorSwatch> {
+        let window = self
+            .widget
+            .container
+            .container_bin()
+            .window()
+            .expect("The widget container must have an associated window.");
+
+        let mut new_bins = window.new_bins(2).into_iter();
+        let container = new_bins.next().unwrap();
+        let fill = new_bins.next().unwrap();
+
+        self.widget
+            .container
+            .container_bin()
+            .add_child(container.clone());
+
+        container.add_child(fill.clone());
+        let initial_color = self.props.color;
+
+        let color_swatch = Arc::new(ColorSwatch {
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::Button),
+            props: self.props,
+            container,
+            fill,
+            state: ReentrantMutex::new(State {
+                color: RefCell::new(initial_color),
+                on_press: RefCell::new(self.on_press),
+            }),
+        });
+
+        let color_swatch_wk = Arc::downgrade(&color_swatch);
+
+        button_hooks(&color_swatch.fill, BtnHookColors::default, move |w_state| {
+            if let Some(color_swatch) = color_swatch_wk.upgrade() {
+                let state = color_swatch.state.lock();
+
+                if let Ok(mut on_press_cbs) = state.on_press.try_borrow_mut() {
+                    for on_press in on_press_cbs.iter_mut() {
+                        on_press(&color_swatch, w_state);
+                    }
+                }
+            }
+        });
+
+        color_swatch.style_update();
+        self.widget.container.widget_built(color_swatch.clone());
+        color_swatch
+    }
+}
+
+/// Color swatch widget
+///
+/// Displays a color that can be pressed, such as to open a color picker.
+///
+/// **Note**: Translucent colors are displayed over the theme's `back3`.
+pub struct ColorSwatch {
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
+    props: Properties,
+    container: Arc<Bin>,
+    fill: Arc<Bin>,
+    state: ReentrantMutex<State>,
+}
+
+struct State {
+    color: RefCell<Color>,
+    on_press: RefCell<Vec<Box<dyn FnMut(&Arc<ColorSwatch>, &WindowState) + Send + 'static>>>,
+}
+
+impl ColorSwatch {
+    /// Set the color displayed.
+    pub fn set_color(&self, color: Color) {
+        let state = self.state.lock();
+        *state.color.borrow_mut() = color;
+
+        self.fill.style_modify(|style| {
+            style.back_color = color;
+        });
+    }
+
+    /// Obtain the color displayed.
+    pub fn color(&self) -> Color {
+        *self.state.lock().color.borrow()
+    }
+
+    /// Add a callback to be called when the [`ColorSwatch`] is pressed.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_press<F>(&self, on_press: F)
+    where
+        F: FnMut(&Arc<ColorSwatch>, &WindowState) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_press
+            .borrow_mut()
+            .push(Box::new(on_press));
+    }
+
+    /// Give keyboard focus to this [`ColorSwatch`].
+    pub fn focus(&self) {
+        crate::focus_bin(&self.fill);
+    }
+
+    /// Remove keyboard focus from this [`ColorSwatch`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`ColorSwatch`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.fill);
+    }
+
+    /// Set if this [`ColorSwatch`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`ColorSwatch`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`ColorSwatch`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`ColorSwatch`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::Button`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
+    /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
+    pub fn default_placement(theme: &Theme) -> WidgetPlacement {
+        let size = theme.spacing + theme.base_size;
+
+        WidgetPlacement {
+            position: Position::Floating,
+            margin_t: Pixels(theme.spacing),
+            margin_b: Pixels(theme.spacing),
+            margin_l: Pixels(theme.spacing),
+            margin_r: Pixels(theme.spacing),
+            width: Pixels(size),
+            height: Pixels(size),
+            ..Default::default()
+        }
+    }
+
+    fn style_update(&self) {
+        let mut container_style = BinStyle {
+            back_color: self.theme.get().colors.back3,
+            visibility: self.container.style_inspect(|style| style.visibility),
+            ..self.props.placement.clone().into_style()
+        };
+
+        let mut fill_style = BinStyle {
+            pos_from_t: Pixels(0.0),
+            pos_from_b: Pixels(0.0),
+            pos_from_l: Pixels(0.0),
+            pos_from_r: Pixels(0.0),
+            back_color: self.color(),
+            ..Default::default()
+        };
+
+        if let Some(border_size) = self.theme.get().border {
+            container_style.border_size_t = Pixels(border_size);
+            container_style.border_size_b = Pixels(border_size);
+            container_style.border_size_l = Pixels(border_size);
+            container_style.border_size_r = Pixels(border_size);
+            container_style.border_color_t = self.theme.get().colors.border1;
+            container_style.border_color_b = self.theme.get().colors.border1;
+            container_style.border_color_l = self.theme.get().colors.border1;
+            container_style.border_color_r = self.theme.get().colors.border1;
+        }
+
+        if let Some(border_radius) = self.theme.get().roundness {
+            container_style.border_radius_tl = Pixels(border_radius);
+            container_style.border_radius_tr = Pixels(border_radius);
+            container_style.border_radius_bl = Pixels(border_radius);
+            container_style.border_radius_br = Pixels(border_radius);
+            fill_style.border_radius_tl = Pixels(border_radius);
+            fill_style.border_radius_tr = Pixels(border_radius);
+            fill_style.border_radius_bl = Pixels(border_radius);
+            fill_style.border_radius_br = Pixels(border_radius);
+        }
+
+        Bin::style_update_batch([(&self.container, container_style), (&self.fill, fill_style)]);
+    }
+}
+
+impl Widget for ColorSwatch {
+    fn focus(&self) {
+        ColorSwatch::focus(self)
+    }
+
+    fn blur(&self) {
+        ColorSwatch::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        ColorSwatch::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        ColorSwatch::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        ColorSwatch::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        ColorSwatch::set_theme(self, theme)
+    }
+}
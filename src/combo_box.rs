@@ -0,0 +1,1172 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use basalt::input::{InputHookCtrl, MouseButton, Qwerty};
+use basalt::interface::UnitValue::{PctOffset, Pixels};
+use basalt::interface::{
+    Bin, BinID, BinPostUpdate, BinStyle, Position, TextAttrs, TextBody, TextCursor, TextHoriAlign,
+    TextVertAlign, TextWrap, Visibility, ZIndex,
+};
+use parking_lot::ReentrantMutex;
+
+use crate::builder::WidgetBuilder;
+use crate::theme::ThemeCell;
+use crate::{
+    ScrollBar, ScrollSource, Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole,
+    text_hooks,
+};
+
+/// The value of a [`ComboBox`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComboBoxValue<I> {
+    /// One of the options was committed.
+    Option(I),
+    /// Text not matching any of the options was committed.
+    Custom(String),
+}
+
+/// Builder for [`ComboBox`]
+pub struct ComboBoxBuilder<'a, C, I> {
+    widget: WidgetBuilder<'a, C>,
+    props: Properties,
+    select: Option<I>,
+    options: BTreeMap<I, String>,
+    on_select: Vec<Box<dyn FnMut(&Arc<ComboBox<I>>, I) + Send + 'static>>,
+    on_custom: Vec<Box<dyn FnMut(&Arc<ComboBox<I>>, String) + Send + 'static>>,
+}
+
+struct Properties {
+    drop_down_items: usize,
+    option_height: f32,
+    placement: WidgetPlacement,
+}
+
+impl Properties {
+    fn new(placement: WidgetPlacement, theme: &Theme) -> Self {
+        Self {
+            drop_down_items: 3,
+            option_height: theme.spacing + theme.base_size,
+            placement,
+        }
+    }
+}
+
+impl<'a, C, I> ComboBoxBuilder<'a, C, I>
+where
+    C: WidgetContainer,
+    I: Ord + Clone + Send + 'static,
+{
+    pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
+        Self {
+            props: Properties::new(
+                builder.take_placement(ComboBox::<()>::default_placement),
+                &builder.theme,
+            ),
+            widget: builder,
+            select: None,
+            options: BTreeMap::new(),
+            on_select: Vec::new(),
+            on_custom: Vec::new(),
+        }
+    }
+
+    /// Add an option with the provided id and label.
+    ///
+    /// **Note**: Ids must be unique. Adding an option of the same id as a previously added id will
+    ///           overwrite the existing option.
+    pub fn add_option<L>(mut self, option_id: I, label: L) -> Self
+    where
+        L: Into<String>,
+    {
+        self.options.insert(option_id, label.into());
+        self
+    }
+
+    /// Set the option to be selected at creation.
+    ///
+    /// **Note**: If the id is not present the value will be empty custom text.
+    pub fn select(mut self, option_id: I) -> Self {
+        self.select = Some(option_id);
+        self
+    }
+
+    /// Set the maximum number of options to be displayed within the drop down.
+    ///
+    /// **Note**: This defaults to `3`.
+    pub fn drop_down_items(mut self, count: usize) -> Self {
+        self.props.drop_down_items = count;
+        self
+    }
+
+    /// Set the height of each option within the drop down.
+    ///
+    /// **Note**: This defaults to the theme's `spacing` + `base_size`.
+    pub fn option_height(mut self, height: f32) -> Self {
+        self.props.option_height = height;
+        self
+    }
+
+    /// Add a callback to be called when an option is committed.
+    ///
+    /// **Panics**: When adding a callback within the callback to this [`ComboBox`].
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: FnMut(&Arc<ComboBox<I>>, I) + Send + 'static,
+    {
+        self.on_select.push(Box::new(on_select));
+        self
+    }
+
+    /// Add a callback to be called when text not matching any option is committed.
+    ///
+    /// **Panics**: When adding a callback within the callback to this [`ComboBox`].
+    pub fn on_custom<F>(mut self, on_custom: F) -> Self
+    where
+        F: FnMut(&Arc<ComboBox<I>>, String) + Send + 'static,
+    {
+        self.on_custom.push(Box::new(on_custom));
+        self
+    }
+
+    /// Finish building the [`ComboBox`].
+    pub fn build(self) -> Arc<ComboBox<I>> {
+        let window = self
+            .widget
+            .container
+            .container_bin()
+            .window()
+            .expect("The widget container must have an associated window.");
+
+        let mut new_bins = window.new_bins(4 + self.options.len()).into_iter();
+        let container = new_bins.next().unwrap();
+        let entry = new_bins.next().unwrap();
+        let popup = new_bins.next().unwrap();
+        let option_list = new_bins.next().unwrap();
+
+        self.widget
+            .container
+            .container_bin()
+            .add_child(container.clone());
+
+        container.add_child(entry.clone());
+        entry.add_child(popup.clone());
+        popup.add_child(option_list.clone());
+
+        let scroll_bar = popup
+            .create_widget()
+            .with_theme(self.widget.theme.clone())
+            .scroll_bar(option_list.clone())
+            .step(self.props.option_height + self.widget.theme.border.unwrap_or(0.0))
+            .build();
+
+        let value = match self.select {
+            Some(select_id) if self.options.contains_key(&select_id) => {
+                ComboBoxValue::Option(select_id)
+            },
+            _ => ComboBoxValue::Custom(String::new()),
+        };
+
+        let options = BTreeMap::from_iter(self.options.into_iter().map(|(id, label)| {
+            let bin = new_bins.next().unwrap();
+            option_list.add_child(bin.clone());
+
+            (
+                id,
+                OptionState {
+                    label,
+                    bin,
+                },
+            )
+        }));
+
+        let combo_box = Arc::new(ComboBox {
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::ComboBox),
+            props: self.props,
+            container,
+            entry,
+            popup,
+            option_list,
+            scroll_bar,
+            state: ReentrantMutex::new(State {
+                value: RefCell::new(value),
+                options: RefCell::new(options),
+                popup: RefCell::new(PopupState {
+                    visible: false,
+                    text: String::new(),
+                    matches: Vec::new(),
+                    select_i: None,
+                }),
+                on_select: RefCell::new(self.on_select),
+                on_custom: RefCell::new(self.on_custom),
+            }),
+        });
+
+        let combo_box_wk = Arc::downgrade(&combo_box);
+
+        text_hooks::create(
+            text_hooks::Properties::ENTRY,
+            combo_box.entry.clone(),
+            combo_box.theme.get().clone(),
+            Some(Arc::new(move |updated| {
+                let text_hooks::Updated {
+                    cursor: _,
+                    cursor_bounds,
+                    body_line_count: _,
+                    cursor_line_col: _,
+                    editor_bpu,
+                } = updated;
+
+                if let Some(combo_box) = combo_box_wk.upgrade() {
+                    if let Some(cursor_bounds) = cursor_bounds {
+                        combo_box.check_cursor_in_view(editor_bpu, cursor_bounds);
+                    }
+
+                    combo_box.text_changed();
+                }
+            })),
+            None,
+        );
+
+        let combo_box_wk = Arc::downgrade(&combo_box);
+
+        window
+            .basalt_ref()
+            .input_ref()
+            .hook()
+            .bin(&combo_box.entry)
+            .on_character()
+            .weight(1)
+            .call(move |_, _, c| {
+                let combo_box = match combo_box_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                match c.0 {
+                    '\r' | '\n' | '\u{1b}' => {
+                        if c.0 == '\u{1b}' {
+                            combo_box.revert();
+                        } else {
+                            combo_box.commit();
+                        }
+
+                        // Move focus to the container rather than clearing the window's focus, so
+                        // that keyboard input stays within the widget.
+                        combo_box
+                            .entry
+                            .basalt_ref()
+                            .input_ref()
+                            .set_bin_focused(&combo_box.container);
+
+                        InputHookCtrl::RetainNoPass
+                    },
+                    _ => Default::default(),
+                }
+            })
+            .finish()
+            .unwrap();
+
+        let combo_box_wk = Arc::downgrade(&combo_box);
+
+        combo_box.entry.on_press(Qwerty::ArrowDown, move |_, _, _| {
+            let combo_box = match combo_box_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            combo_box.popup_move(true);
+            Default::default()
+        });
+
+        let combo_box_wk = Arc::downgrade(&combo_box);
+
+        combo_box.entry.on_press(Qwerty::ArrowUp, move |_, _, _| {
+            let combo_box = match combo_box_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            combo_box.popup_move(false);
+            Default::default()
+        });
+
+        let combo_box_wk = Arc::downgrade(&combo_box);
+
+        combo_box.entry.on_focus(move |_, _| {
+            let combo_box = match combo_box_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            let theme = &combo_box.theme.get();
+
+            if theme.border.is_some() {
+                combo_box.entry.style_modify(|style| {
+                    style.border_color_t = theme.colors.accent2;
+                    style.border_color_b = theme.colors.accent2;
+                    style.border_color_l = theme.colors.accent2;
+                    style.border_color_r = theme.colors.accent2;
+                });
+            }
+
+            Default::default()
+        });
+
+        let combo_box_wk = Arc::downgrade(&combo_box);
+        let mut currently_focused = false;
+
+        combo_box
+            .entry
+            .attach_input_hook(window.on_bin_focus_change(move |_, w_state, _| {
+                let combo_box = match combo_box_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                let now_focused = match w_state.focused_bin_id() {
+                    Some(bin_id) => combo_box.has_bin_id(bin_id),
+                    None => false,
+                };
+
+                if currently_focused {
+                    if !now_focused {
+                        currently_focused = false;
+                        combo_box.revert();
+                    }
+                } else {
+                    currently_focused = now_focused;
+                }
+
+                Default::default()
+            }));
+
+        combo_box
+            .state
+            .lock()
+            .options
+            .borrow()
+            .iter()
+            .for_each(|(id, option_state)| {
+                combo_box.add_option_select_hook(id.clone(), &option_state.bin);
+            });
+
+        combo_box.style_update();
+        combo_box.rebuild_list();
+        self.widget.container.widget_built(combo_box.clone());
+        combo_box
+    }
+}
+
+/// Combo box widget
+///
+/// A text entry with a drop down of options matching the entered text.
+///
+/// **Notes**:
+/// - Pressing enter commits the highlighted option, an option whose label matches the entered
+/// text or otherwise the entered text itself.
+/// - Pressing escape or removing focus discards the entered text.
+pub struct ComboBox<I> {
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
+    props: Properties,
+    container: Arc<Bin>,
+    entry: Arc<Bin>,
+    popup: Arc<Bin>,
+    option_list: Arc<Bin>,
+    scroll_bar: Arc<ScrollBar>,
+    state: ReentrantMutex<State<I>>,
+}
+
+struct State<I> {
+    value: RefCell<ComboBoxValue<I>>,
+    options: RefCell<BTreeMap<I, OptionState>>,
+    popup: RefCell<PopupState<I>>,
+    on_select: RefCell<Vec<Box<dyn FnMut(&Arc<ComboBox<I>>, I) + Send + 'static>>>,
+    on_custom: RefCell<Vec<Box<dyn FnMut(&Arc<ComboBox<I>>, String) + Send + 'static>>>,
+}
+
+struct OptionState {
+    label: String,
+    bin: Arc<Bin>,
+}
+
+struct PopupState<I> {
+    visible: bool,
+    text: String,
+    matches: Vec<I>,
+    select_i: Option<usize>,
+}
+
+impl<I> ComboBox<I>
+where
+    I: Ord + Clone + Send + 'static,
+{
+    /// Obtain the current value.
+    pub fn value(&self) -> ComboBoxValue<I> {
+        self.state.lock().value.borrow().clone()
+    }
+
+    /// Select the option with the provided id.
+    ///
+    /// **Note**: This is a no-op if the id is not present.
+    pub fn select(self: &Arc<Self>, option_id: I) {
+        let state = self.state.lock();
+
+        if state.options.borrow().contains_key(&option_id) {
+            self.commit_option(option_id);
+        }
+    }
+
+    /// Add an option with the provided id and label.
+    ///
+    /// **Note**: Ids must be unique. Adding an option of the same id as a previously added id will
+    ///           overwrite the existing option.
+    pub fn add_option<L>(self: &Arc<Self>, option_id: I, label: L)
+    where
+        L: Into<String>,
+    {
+        let bin = self.entry.window().unwrap().new_bin();
+        let state = self.state.lock();
+        self.option_list.add_child(bin.clone());
+        self.add_option_select_hook(option_id.clone(), &bin);
+
+        state.options.borrow_mut().insert(
+            option_id,
+            OptionState {
+                label: label.into(),
+                bin,
+            },
+        );
+
+        self.rebuild_list();
+    }
+
+    /// Remove an option with the provided id.
+    ///
+    /// **Notes**:
+    /// - If the id is not present nothing will happen and `false` will be returned.
+    /// - If the id is the current value, the value becomes its label as custom text.
+    pub fn remove_option(self: &Arc<Self>, option_id: I) -> bool {
+        let state = self.state.lock();
+
+        let option_state = match state.options.borrow_mut().remove(&option_id) {
+            Some(some) => some,
+            None => return false,
+        };
+
+        {
+            let mut value = state.value.borrow_mut();
+
+            if *value == ComboBoxValue::Option(option_id) {
+                *value = ComboBoxValue::Custom(option_state.label);
+            }
+        }
+
+        self.rebuild_list();
+        true
+    }
+
+    /// Add a callback to be called when an option is committed.
+    ///
+    /// **Panics**: When adding a callback within the callback to this [`ComboBox`].
+    pub fn on_select<F>(&self, on_select: F)
+    where
+        F: FnMut(&Arc<ComboBox<I>>, I) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_select
+            .borrow_mut()
+            .push(Box::new(on_select));
+    }
+
+    /// Add a callback to be called when text not matching any option is committed.
+    ///
+    /// **Panics**: When adding a callback within the callback to this [`ComboBox`].
+    pub fn on_custom<F>(&self, on_custom: F)
+    where
+        F: FnMut(&Arc<ComboBox<I>>, String) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_custom
+            .borrow_mut()
+            .push(Box::new(on_custom));
+    }
+
+    fn add_option_select_hook(self: &Arc<Self>, id: I, bin: &Arc<Bin>) {
+        let combo_box_wk = Arc::downgrade(self);
+
+        bin.on_press(MouseButton::Left, move |_, _, _| {
+            let combo_box = match combo_box_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            combo_box.commit_option(id.clone());
+            Default::default()
+        });
+    }
+
+    fn has_bin_id(&self, bin_id: BinID) -> bool {
+        bin_id == self.entry.id()
+            || bin_id == self.popup.id()
+            || bin_id == self.option_list.id()
+            || self.scroll_bar.has_bin_id(bin_id)
+            || self
+                .state
+                .lock()
+                .options
+                .borrow()
+                .values()
+                .any(|option_state| option_state.bin.id() == bin_id)
+    }
+
+    fn entry_text(&self) -> String {
+        self.entry.style_inspect(|style| {
+            style
+                .text_body
+                .spans
+                .iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>()
+        })
+    }
+
+    fn value_text(&self) -> String {
+        let state = self.state.lock();
+
+        match &*state.value.borrow() {
+            ComboBoxValue::Option(option_id) => {
+                state
+                    .options
+                    .borrow()
+                    .get(option_id)
+                    .map(|option_state| option_state.label.clone())
+                    .unwrap_or_default()
+            },
+            ComboBoxValue::Custom(text) => text.clone(),
+        }
+    }
+
+    fn set_entry_text(&self, text: String) {
+        // Prevent the change from being treated as user input.
+        self.state.lock().popup.borrow_mut().text = text.clone();
+
+        self.entry.style_modify(|style| {
+            style.text_body.spans = vec![text.into()];
+            style.text_body.cursor = TextCursor::None;
+            style.text_body.selection = None;
+            style.scroll_x = 0.0;
+        });
+    }
+
+    fn text_changed(&self) {
+        let text = self.entry_text();
+        let state = self.state.lock();
+
+        {
+            let mut popup_state = state.popup.borrow_mut();
+
+            if popup_state.text == text {
+                return;
+            }
+
+            popup_state.text = text;
+            popup_state.visible = true;
+            popup_state.select_i = None;
+        }
+
+        self.rebuild_list();
+        self.popup_jump_to_selected();
+    }
+
+    fn popup_move(&self, down: bool) {
+        let state = self.state.lock();
+
+        {
+            let mut popup_state = state.popup.borrow_mut();
+
+            if !popup_state.visible {
+                popup_state.visible = true;
+                popup_state.select_i = None;
+            } else if !popup_state.matches.is_empty() {
+                let last_i = popup_state.matches.len() - 1;
+
+                popup_state.select_i = Some(match popup_state.select_i {
+                    Some(select_i) => {
+                        if down {
+                            (select_i + 1).min(last_i)
+                        } else {
+                            select_i.saturating_sub(1)
+                        }
+                    },
+                    None => 0,
+                });
+            }
+        }
+
+        self.rebuild_list();
+        self.popup_jump_to_selected();
+    }
+
+    fn popup_jump_to_selected(&self) {
+        let jump_index = self
+            .state
+            .lock()
+            .popup
+            .borrow()
+            .select_i
+            .map(|select_i| select_i.saturating_sub(self.props.drop_down_items / 3))
+            .unwrap_or(0);
+
+        let jump_to =
+            jump_index as f32 * (self.props.option_height + self.theme.get().border.unwrap_or(0.0));
+
+        self.scroll_bar
+            .jump_to_from(jump_to, ScrollSource::Keyboard);
+    }
+
+    fn commit(self: &Arc<Self>) {
+        let text = self.entry_text();
+        let state = self.state.lock();
+
+        let value = {
+            let popup_state = state.popup.borrow();
+
+            let highlighted = match popup_state.select_i {
+                Some(select_i) if popup_state.visible => popup_state.matches.get(select_i).cloned(),
+                _ => None,
+            };
+
+            commit_value(
+                state
+                    .options
+                    .borrow()
+                    .iter()
+                    .map(|(id, option_state)| (id, option_state.label.as_str())),
+                highlighted,
+                text,
+            )
+        };
+
+        match value {
+            ComboBoxValue::Option(option_id) => self.commit_option(option_id),
+            ComboBoxValue::Custom(text) => self.commit_custom(text),
+        }
+    }
+
+    fn commit_option(self: &Arc<Self>, option_id: I) {
+        let state = self.state.lock();
+        *state.value.borrow_mut() = ComboBoxValue::Option(option_id.clone());
+        self.hide_popup();
+        self.set_entry_text(self.value_text());
+
+        if let Ok(mut on_select_cbs) = state.on_select.try_borrow_mut() {
+            for on_select in on_select_cbs.iter_mut() {
+                on_select(self, option_id.clone());
+            }
+        }
+    }
+
+    fn commit_custom(self: &Arc<Self>, text: String) {
+        let state = self.state.lock();
+        *state.value.borrow_mut() = ComboBoxValue::Custom(text.clone());
+        self.hide_popup();
+        self.set_entry_text(text.clone());
+
+        if let Ok(mut on_custom_cbs) = state.on_custom.try_borrow_mut() {
+            for on_custom in on_custom_cbs.iter_mut() {
+                on_custom(self, text.clone());
+            }
+        }
+    }
+
+    fn revert(&self) {
+        let theme = &self.theme.get();
+        self.hide_popup();
+        self.set_entry_text(self.value_text());
+
+        if theme.border.is_some() {
+            self.entry.style_modify(|style| {
+                style.border_color_t = theme.colors.border1;
+                style.border_color_b = theme.colors.border1;
+                style.border_color_l = theme.colors.border1;
+                style.border_color_r = theme.colors.border1;
+            });
+        }
+    }
+
+    fn hide_popup(&self) {
+        let state = self.state.lock();
+        let mut popup_state = state.popup.borrow_mut();
+
+        if !popup_state.visible {
+            return;
+        }
+
+        popup_state.visible = false;
+        popup_state.select_i = None;
+
+        self.popup.style_modify(|style| {
+            style.visibility = Visibility::Hide;
+        });
+    }
+
+    fn popup_rows(&self, num_options: usize) -> usize {
+        crate::select::popup_rows(self.props.drop_down_items, num_options)
+    }
+
+    fn popup_height(&self, num_options: usize) -> f32 {
+        crate::select::popup_height(
+            self.popup_rows(num_options),
+            self.props.option_height,
+            self.theme.get().border.unwrap_or(0.0),
+        )
+    }
+
+    fn rebuild_list(&self) {
+        let value_text = self.value_text();
+        let state = self.state.lock();
+        let options = state.options.borrow();
+        let mut popup_state = state.popup.borrow_mut();
+
+        popup_state.matches = filter_options(
+            options
+                .iter()
+                .map(|(id, option_state)| (id, option_state.label.as_str())),
+            &popup_state.text,
+            &value_text,
+        );
+
+        let num_matches = popup_state.matches.len();
+
+        if popup_state
+            .select_i
+            .is_some_and(|select_i| select_i >= num_matches)
+        {
+            popup_state.select_i = None;
+        }
+
+        let scrollable = num_matches > self.popup_rows(num_matches);
+        let border_size = self.theme.get().border.unwrap_or(0.0);
+        let mut style_update_batch = Vec::with_capacity(options.len() + 2);
+
+        let mut popup_style = self.popup.style_copy();
+        popup_style.height = Pixels(self.popup_height(num_matches));
+
+        if popup_state.visible && num_matches > 0 {
+            popup_style.visibility = Visibility::Inheirt;
+        } else {
+            popup_style.visibility = Visibility::Hide;
+        }
+
+        style_update_batch.push((&self.popup, popup_style));
+
+        let mut option_list_style = self.option_list.style_copy();
+
+        option_list_style.pos_from_r = if scrollable {
+            Pixels(ScrollBar::size(&self.theme.get()))
+        } else {
+            Pixels(0.0)
+        };
+
+        style_update_batch.push((&self.option_list, option_list_style));
+        self.scroll_bar.set_visible(scrollable);
+
+        for (id, option_state) in options.iter() {
+            let match_i = match popup_state
+                .matches
+                .iter()
+                .position(|match_id| match_id == id)
+            {
+                Some(some) => some,
+                None => {
+                    let mut option_style = option_state.bin.style_copy();
+                    option_style.visibility = Visibility::Hide;
+                    option_style.pos_from_t = Pixels(0.0);
+                    style_update_batch.push((&option_state.bin, option_style));
+                    continue;
+                },
+            };
+
+            let [back_color, text_color] = if popup_state.select_i == Some(match_i) {
+                [
+                    self.theme.get().colors.accent1,
+                    self.theme.get().colors.text1b,
+                ]
+            } else {
+                [Default::default(), self.theme.get().colors.text1a]
+            };
+
+            let mut option_style = BinStyle {
+                pos_from_t: Pixels(match_i as f32 * (self.props.option_height + border_size)),
+                pos_from_l: Pixels(0.0),
+                pos_from_r: Pixels(0.0),
+                height: Pixels(self.props.option_height),
+                padding_l: Pixels(self.theme.get().spacing),
+                padding_r: Pixels(self.theme.get().spacing),
+                back_color,
+                text_body: TextBody {
+                    hori_align: TextHoriAlign::Left,
+                    vert_align: TextVertAlign::Center,
+                    text_wrap: TextWrap::None,
+                    base_attrs: TextAttrs {
+                        height: Pixels(self.theme.get().text_height),
+                        color: text_color,
+                        font_family: self.theme.get().font_family.clone(),
+                        font_weight: self.theme.get().label_font_weight(),
+                        ..Default::default()
+                    },
+                    ..TextBody::from(option_state.label.clone())
+                },
+                ..Default::default()
+            };
+
+            if match_i != num_matches - 1 {
+                if let Some(border_size) = self.theme.get().border {
+                    option_style.border_size_b = Pixels(border_size);
+                    option_style.border_color_b = self.theme.get().colors.border2;
+                }
+            }
+
+            style_update_batch.push((&option_state.bin, option_style));
+        }
+
+        Bin::style_update_batch(style_update_batch);
+    }
+
+    fn check_cursor_in_view(&self, entry_bpu: &BinPostUpdate, cursor_bounds: [f32; 4]) {
+        let view_bounds = entry_bpu.optimal_content_bounds;
+
+        let scroll_x_op = if cursor_bounds[0] < view_bounds[0] {
+            Some(cursor_bounds[0] - entry_bpu.content_offset[0] - view_bounds[0])
+        } else if cursor_bounds[1] > view_bounds[1] {
+            Some(cursor_bounds[1] - entry_bpu.content_offset[0] - view_bounds[1])
+        } else {
+            None
+        };
+
+        if let Some(scroll_x) = scroll_x_op {
+            self.entry.style_modify(|style| {
+                style.scroll_x = scroll_x;
+            });
+        }
+    }
+
+    fn style_update(&self) {
+        let border_size = self.theme.get().border.unwrap_or(0.0);
+        let text = self.value_text();
+        self.state.lock().popup.borrow_mut().text = text.clone();
+
+        let container_style = BinStyle {
+            visibility: self.container.style_inspect(|style| style.visibility),
+            ..self.props.placement.clone().into_style()
+        };
+
+        let mut entry_style = BinStyle {
+            pos_from_t: Pixels(0.0),
+            pos_from_b: Pixels(0.0),
+            pos_from_l: Pixels(0.0),
+            pos_from_r: Pixels(0.0),
+            back_color: self.theme.get().colors.back2,
+            padding_l: Pixels(self.theme.get().spacing),
+            padding_r: Pixels(self.theme.get().spacing),
+            text_body: TextBody {
+                hori_align: TextHoriAlign::Left,
+                vert_align: TextVertAlign::Center,
+                text_wrap: TextWrap::None,
+                base_attrs: TextAttrs {
+                    height: Pixels(self.theme.get().text_height),
+                    color: self.theme.get().colors.text1a,
+                    font_family: self.theme.get().font_family.clone(),
+                    font_weight: self.theme.get().value_font_weight(),
+                    ..Default::default()
+                },
+                ..TextBody::from(text)
+            },
+            ..Default::default()
+        };
+
+        let mut popup_style = BinStyle {
+            position: Position::Anchor,
+            z_index: ZIndex::Offset(100),
+            visibility: Visibility::Hide,
+            pos_from_t: PctOffset(100.0, border_size),
+            pos_from_l: Pixels(0.0),
+            pos_from_r: Pixels(0.0),
+            height: Pixels(self.popup_height(self.state.lock().options.borrow().len())),
+            back_color: self.theme.get().colors.back2,
+            ..Default::default()
+        };
+
+        let option_list_style = BinStyle {
+            pos_from_t: Pixels(0.0),
+            pos_from_l: Pixels(0.0),
+            pos_from_r: Pixels(ScrollBar::size(&self.theme.get())),
+            pos_from_b: Pixels(0.0),
+            ..Default::default()
+        };
+
+        if let Some(border_size) = self.theme.get().border {
+            entry_style.border_size_t = Pixels(border_size);
+            entry_style.border_size_b = Pixels(border_size);
+            entry_style.border_size_l = Pixels(border_size);
+            entry_style.border_size_r = Pixels(border_size);
+            entry_style.border_color_t = self.theme.get().colors.border1;
+            entry_style.border_color_b = self.theme.get().colors.border1;
+            entry_style.border_color_l = self.theme.get().colors.border1;
+            entry_style.border_color_r = self.theme.get().colors.border1;
+
+            popup_style.border_size_b = Pixels(border_size);
+            popup_style.border_size_l = Pixels(border_size);
+            popup_style.border_size_r = Pixels(border_size);
+            popup_style.border_color_b = self.theme.get().colors.border1;
+            popup_style.border_color_l = self.theme.get().colors.border1;
+            popup_style.border_color_r = self.theme.get().colors.border1;
+        }
+
+        if let Some(border_radius) = self.theme.get().roundness {
+            entry_style.border_radius_tl = Pixels(border_radius);
+            entry_style.border_radius_tr = Pixels(border_radius);
+            entry_style.border_radius_bl = Pixels(border_radius);
+            entry_style.border_radius_br = Pixels(border_radius);
+
+            popup_style.border_radius_bl = Pixels(border_radius);
+            popup_style.border_radius_br = Pixels(border_radius);
+        }
+
+        Bin::style_update_batch([
+            (&self.container, container_style),
+            (&self.entry, entry_style),
+            (&self.popup, popup_style),
+            (&self.option_list, option_list_style),
+        ]);
+    }
+}
+
+impl<I> ComboBox<I> {
+    /// Give keyboard focus to this [`ComboBox`].
+    ///
+    /// This allows text to be entered without clicking on it first.
+    pub fn focus(&self) {
+        crate::focus_bin(&self.entry);
+    }
+
+    /// Remove keyboard focus from this [`ComboBox`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`ComboBox`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.entry);
+    }
+
+    /// Set if this [`ComboBox`] is visible.
+    ///
+    /// **Note**: Hiding the [`ComboBox`] also closes its popup.
+    pub fn set_visible(&self, visible: bool) {
+        if !visible {
+            self.hide_popup();
+        }
+
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`ComboBox`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`ComboBox`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`ComboBox`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.scroll_bar.set_theme(theme.clone());
+        self.theme.set(theme);
+        self.style_update();
+        self.rebuild_list();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::ComboBox`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
+    /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
+    pub fn default_placement(theme: &Theme) -> WidgetPlacement {
+        let height = theme.spacing + theme.base_size;
+        let width = height * 5.0;
+
+        WidgetPlacement {
+            position: Position::Floating,
+            margin_t: Pixels(theme.spacing),
+            margin_b: Pixels(theme.spacing),
+            margin_l: Pixels(theme.spacing),
+            margin_r: Pixels(theme.spacing),
+            width: Pixels(width),
+            height: Pixels(height),
+            ..Default::default()
+        }
+    }
+}
+
+impl<I> Widget for ComboBox<I>
+where
+    I: Ord + Clone + Send + 'static,
+{
+    fn focus(&self) {
+        ComboBox::focus(self)
+    }
+
+    fn blur(&self) {
+        ComboBox::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        ComboBox::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        ComboBox::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        ComboBox::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        ComboBox::set_theme(self, theme)
+    }
+}
+
+/// The ids of the options whose label contains `text`, ignoring case.
+///
+/// When `text` is the text of the committed value every option matches, so that the drop down
+/// shows the full list until the text is edited.
+fn filter_options<'a, I, O>(options: O, text: &str, committed_text: &str) -> Vec<I>
+where
+    I: Clone + 'a,
+    O: IntoIterator<Item = (&'a I, &'a str)>,
+{
+    let filter = if text == committed_text {
+        String::new()
+    } else {
+        text.to_lowercase()
+    };
+
+    options
+        .into_iter()
+        .filter(|(_, label)| label.to_lowercase().contains(&filter))
+        .map(|(id, _)| id.clone())
+        .collect()
+}
+
+/// The value committed for `text`.
+///
+/// This is the highlighted option, otherwise an option whose label matches `text` ignoring case
+/// or otherwise `text` itself.
+fn commit_value<'a, I, O>(options: O, highlighted: Option<I>, text: String) -> ComboBoxValue<I>
+where
+    I: Clone + 'a,
+    O: IntoIterator<Item = (&'a I, &'a str)>,
+{
+    if let Some(option_id) = highlighted {
+        return ComboBoxValue::Option(option_id);
+    }
+
+    let text_lower = text.to_lowercase();
+
+    match options
+        .into_iter()
+        .find(|(_, label)| label.to_lowercase() == text_lower)
+    {
+        Some((id, _)) => ComboBoxValue::Option(id.clone()),
+        None => ComboBoxValue::Custom(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPTIONS: [(u32, &str); 3] = [(0, "Apple"), (1, "Banana"), (2, "Pineapple")];
+
+    fn options() -> impl Iterator<Item = (&'static u32, &'static str)> {
+        OPTIONS.iter().map(|(id, label)| (id, *label))
+    }
+
+    #[test]
+    fn filter_ignores_case() {
+        assert_eq!(filter_options(options(), "APP", ""), vec![0, 2]);
+    }
+
+    #[test]
+    fn filter_without_matches() {
+        assert!(filter_options(options(), "cherry", "").is_empty());
+    }
+
+    #[test]
+    fn filter_shows_all_for_committed_text() {
+        assert_eq!(filter_options(options(), "Banana", "Banana"), vec![0, 1, 2]);
+        assert_eq!(filter_options(options(), "Banan", "Banana"), vec![1]);
+    }
+
+    #[test]
+    fn commit_highlighted_option() {
+        assert_eq!(
+            commit_value(options(), Some(2), String::from("Apple")),
+            ComboBoxValue::Option(2)
+        );
+    }
+
+    #[test]
+    fn commit_matching_label() {
+        assert_eq!(
+            commit_value(options(), None, String::from("banana")),
+            ComboBoxValue::Option(1)
+        );
+    }
+
+    #[test]
+    fn commit_custom_text() {
+        assert_eq!(
+            commit_value(options(), None, String::from("Cherry")),
+            ComboBoxValue::Custom(String::from("Cherry"))
+        );
+    }
+}
@@ -1,5 +1,17 @@
 //! Error types
 
+pub use crate::check_box::CheckBoxError;
+pub use crate::number_field::NumberFieldError;
 pub use crate::radio_button::RadioButtonError;
 pub use crate::scaler::ScalerError;
 pub use crate::spin_button::SpinButtonError;
+pub use crate::theme::ColorHexError;
+
+/// An error that can occur when building a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    /// The widget container doesn't have an associated window.
+    NoWindow,
+    /// The window failed to allocate the bins of the widget.
+    BinAllocationFailed,
+}
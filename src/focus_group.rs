@@ -0,0 +1,125 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use basalt::input::{InputHookCtrl, Qwerty};
+use basalt::window::Window;
+use parking_lot::ReentrantMutex;
+
+use crate::Widget;
+
+/// Cycles keyboard focus between widgets with Tab and Shift+Tab.
+///
+/// Widgets are focused in the order they were added. Focus wraps around at either end and
+/// widgets that are hidden or disabled are skipped.
+///
+/// **Note**: The focused widget is tracked by the group, focusing a widget by other means, such as
+/// pressing it, isn't taken into account.
+pub struct FocusGroup {
+    state: ReentrantMutex<State>,
+}
+
+struct State {
+    widgets: RefCell<Vec<Arc<dyn Widget>>>,
+    current: RefCell<Option<usize>>,
+}
+
+impl FocusGroup {
+    /// Create a new [`FocusGroup`] that handles Tab presses within the provided window.
+    pub fn new(window: &Arc<Window>) -> Arc<Self> {
+        let focus_group = Arc::new(Self {
+            state: ReentrantMutex::new(State {
+                widgets: RefCell::new(Vec::new()),
+                current: RefCell::new(None),
+            }),
+        });
+
+        let focus_group_wk = Arc::downgrade(&focus_group);
+
+        window
+            .basalt_ref()
+            .input_ref()
+            .hook()
+            .window(window)
+            .on_press()
+            .keys(Qwerty::Tab)
+            .call(move |_, w_state, _| {
+                let focus_group = match focus_group_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                if w_state.is_key_pressed(Qwerty::LShift) || w_state.is_key_pressed(Qwerty::RShift)
+                {
+                    focus_group.focus_prev();
+                } else {
+                    focus_group.focus_next();
+                }
+
+                Default::default()
+            })
+            .finish()
+            .unwrap();
+
+        focus_group
+    }
+
+    /// Add a widget to the end of this [`FocusGroup`].
+    pub fn add<W>(&self, widget: &Arc<W>)
+    where
+        W: Widget + 'static,
+    {
+        self.state.lock().widgets.borrow_mut().push(widget.clone());
+    }
+
+    /// Focus the first widget that isn't hidden or disabled.
+    ///
+    /// Returns `false` if there was no widget to focus.
+    pub fn focus_first(&self) -> bool {
+        let state = self.state.lock();
+        *state.current.borrow_mut() = None;
+        self.step(true)
+    }
+
+    /// Focus the next widget, wrapping to the first after the last.
+    ///
+    /// Returns `false` if there was no widget to focus.
+    pub fn focus_next(&self) -> bool {
+        self.step(true)
+    }
+
+    /// Focus the previous widget, wrapping to the last before the first.
+    ///
+    /// Returns `false` if there was no widget to focus.
+    pub fn focus_prev(&self) -> bool {
+        self.step(false)
+    }
+
+    fn step(&self, forward: bool) -> bool {
+        let state = self.state.lock();
+        let widgets = state.widgets.borrow().clone();
+        let len = widgets.len();
+
+        if len == 0 {
+            return false;
+        }
+
+        let current = *state.current.borrow();
+
+        for offset in 1..=len {
+            let i = match (current, forward) {
+                (Some(current), true) => (current + offset) % len,
+                (Some(current), false) => (current + (len * 2) - offset) % len,
+                (None, true) => offset - 1,
+                (None, false) => len - offset,
+            };
+
+            if widgets[i].is_visible() && !widgets[i].is_disabled() {
+                *state.current.borrow_mut() = Some(i);
+                widgets[i].focus();
+                return true;
+            }
+        }
+
+        false
+    }
+}
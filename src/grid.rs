@@ -0,0 +1,330 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use basalt::interface::UnitValue::Pixels;
+use basalt::interface::{Bin, BinID, BinStyle, Position, Visibility};
+use parking_lot::ReentrantMutex;
+
+use crate::builder::WidgetBuilder;
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
+
+/// Builder for [`Grid`]
+pub struct GridBuilder<'a, C> {
+    widget: WidgetBuilder<'a, C>,
+    props: Properties,
+}
+
+struct Properties {
+    columns: usize,
+    placement: WidgetPlacement,
+    plmt_is_default: bool,
+}
+
+impl<'a, C> GridBuilder<'a, C>
+where
+    C: WidgetContainer,
+{
+    pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
+        let plmt_is_default = builder.placement.is_none() && !builder.has_placement_mods();
+
+        Self {
+            props: Properties {
+                columns: 2,
+                placement: builder.take_placement(Grid::default_placement),
+                plmt_is_default,
+            },
+            widget: builder,
+        }
+    }
+
+    /// Set the number of columns.
+    ///
+    /// **Note**: If not set this defaults to `2`.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.props.columns = columns.max(1);
+        self
+    }
+
+    /// Finish building the [`Grid`].
+    pub fn build(self) -> Arc<Grid> {
+        let window = self
+            .widget
+            .container
+            .container_bin()
+            .window()
+            .expect("The widget container must have an associated window.");
+
+        let container = window.new_bin();
+
+        self.widget
+            .container
+            .container_bin()
+            .add_child(container.clone());
+
+        let grid = Arc::new(Grid {
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::Grid),
+            props: self.props,
+            container,
+            state: ReentrantMutex::new(State {
+                hooked: RefCell::new(Vec::new()),
+            }),
+        });
+
+        let grid_wk = Arc::downgrade(&grid);
+
+        grid.container.on_children_added(move |_, _| {
+            if let Some(grid) = grid_wk.upgrade() {
+                grid.hook_children();
+                grid.layout();
+            }
+        });
+
+        let grid_wk = Arc::downgrade(&grid);
+
+        grid.container.on_children_removed(move |_, _| {
+            if let Some(grid) = grid_wk.upgrade() {
+                grid.layout();
+            }
+        });
+
+        grid.style_update();
+        self.widget.container.widget_built(grid.clone());
+        grid
+    }
+}
+
+/// Grid layout widget
+///
+/// Widgets created with the [`Grid`] as their container are placed into rows and columns in the
+/// order they are created. Each column is as wide as its widest widget and each row is as tall as
+/// its tallest widget, with the theme's spacing between them.
+///
+/// **Notes**:
+/// - The position of each widget is controlled by the [`Grid`], so the position and margins of
+/// their placement are ignored.
+/// - When a placement isn't provided, the [`Grid`] sizes itself to fit its widgets.
+pub struct Grid {
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
+    props: Properties,
+    container: Arc<Bin>,
+    state: ReentrantMutex<State>,
+}
+
+struct State {
+    hooked: RefCell<Vec<BinID>>,
+}
+
+impl Grid {
+    /// Obtain the number of columns.
+    pub fn columns(&self) -> usize {
+        self.props.columns
+    }
+
+    /// Set if this [`Grid`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`Grid`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`Grid`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`Grid`] and re-apply its styling.
+    ///
+    /// **Note**: Widgets within this [`Grid`] keep their own theme. The theme is only used for
+    /// widgets built within this [`Grid`] afterwards.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+        self.layout();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::Grid`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
+    /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
+    pub fn default_placement(theme: &Theme) -> WidgetPlacement {
+        WidgetPlacement {
+            position: Position::Floating,
+            margin_t: Pixels(theme.spacing),
+            margin_b: Pixels(theme.spacing),
+            margin_l: Pixels(theme.spacing),
+            margin_r: Pixels(theme.spacing),
+            width: Pixels(0.0),
+            height: Pixels(0.0),
+            ..Default::default()
+        }
+    }
+
+    fn hook_children(self: &Arc<Self>) {
+        let state = self.state.lock();
+        let mut hooked = state.hooked.borrow_mut();
+        hooked.retain(|bin_id| self.container.children().iter().any(|c| c.id() == *bin_id));
+
+        for child in self.container.children() {
+            if hooked.contains(&child.id()) {
+                continue;
+            }
+
+            hooked.push(child.id());
+            let grid_wk = Arc::downgrade(self);
+
+            // The size of a widget is only known after it has been updated.
+            child.on_update(move |_, _| {
+                if let Some(grid) = grid_wk.upgrade() {
+                    grid.layout();
+                }
+            });
+        }
+    }
+
+    fn layout(&self) {
+        let _state = self.state.lock();
+        let children = self.container.children();
+        let columns = self.props.columns;
+        let rows = children.len().div_ceil(columns);
+        let spacing = self.theme.get().spacing;
+        let mut col_widths = vec![0.0_f32; columns];
+        let mut row_heights = vec![0.0_f32; rows];
+
+        for (i, child) in children.iter().enumerate() {
+            let bpu = child.post_update();
+            col_widths[i % columns] = col_widths[i % columns].max(bpu.tri[0] - bpu.tli[0]);
+            row_heights[i / columns] = row_heights[i / columns].max(bpu.bli[1] - bpu.tli[1]);
+        }
+
+        let mut style_update_batch = Vec::new();
+
+        for (i, child) in children.iter().enumerate() {
+            let [col, row] = [i % columns, i / columns];
+            let pos_from_l = col_widths[..col].iter().map(|w| w + spacing).sum::<f32>();
+            let pos_from_t = row_heights[..row].iter().map(|h| h + spacing).sum::<f32>();
+
+            if let Some(mut child_style) = child.style_inspect(|style| {
+                if style.position == Position::Relative
+                    && style.pos_from_l == Pixels(pos_from_l)
+                    && style.pos_from_t == Pixels(pos_from_t)
+                {
+                    None
+                } else {
+                    Some(style.clone())
+                }
+            }) {
+                child_style.position = Position::Relative;
+                child_style.pos_from_l = Pixels(pos_from_l);
+                child_style.pos_from_t = Pixels(pos_from_t);
+                child_style.pos_from_r = Default::default();
+                child_style.pos_from_b = Default::default();
+                child_style.margin_t = Default::default();
+                child_style.margin_b = Default::default();
+                child_style.margin_l = Default::default();
+                child_style.margin_r = Default::default();
+                style_update_batch.push((child, child_style));
+            }
+        }
+
+        if self.props.plmt_is_default {
+            let width =
+                col_widths.iter().sum::<f32>() + (spacing * columns.saturating_sub(1) as f32);
+
+            let height =
+                row_heights.iter().sum::<f32>() + (spacing * rows.saturating_sub(1) as f32);
+
+            if let Some(mut container_style) = self.container.style_inspect(|style| {
+                if style.width == Pixels(width) && style.height == Pixels(height) {
+                    None
+                } else {
+                    Some(style.clone())
+                }
+            }) {
+                container_style.width = Pixels(width);
+                container_style.height = Pixels(height);
+                style_update_batch.push((&self.container, container_style));
+            }
+        }
+
+        Bin::style_update_batch(style_update_batch);
+    }
+
+    fn style_update(&self) {
+        let container_style = BinStyle {
+            visibility: self.container.style_inspect(|style| style.visibility),
+            ..self.props.placement.clone().into_style()
+        };
+
+        self.container.style_update(container_style).expect_valid();
+    }
+}
+
+impl WidgetContainer for Arc<Grid> {
+    fn container_bin(&self) -> &Arc<Bin> {
+        &self.container
+    }
+
+    fn default_theme(&self) -> Theme {
+        self.theme.get().clone()
+    }
+}
+
+impl Widget for Grid {
+    fn focus(&self) {}
+
+    fn blur(&self) {}
+
+    fn is_visible(&self) -> bool {
+        Grid::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        Grid::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        Grid::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        Grid::set_theme(self, theme)
+    }
+}
@@ -0,0 +1,300 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use basalt::interface::UnitValue::{PctOffset, Pixels};
+use basalt::interface::{
+    Bin, BinStyle, Color, Position, TextAttrs, TextBody, TextHoriAlign, TextVertAlign, TextWrap,
+    Visibility,
+};
+use parking_lot::ReentrantMutex;
+
+use crate::builder::WidgetBuilder;
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
+
+/// Builder for [`Label`]
+pub struct LabelBuilder<'a, C> {
+    widget: WidgetBuilder<'a, C>,
+    props: Properties,
+    text: String,
+}
+
+struct Properties {
+    color: Option<Color>,
+    align: TextHoriAlign,
+    wrap: bool,
+    placement: WidgetPlacement,
+    plmt_is_default: bool,
+}
+
+impl<'a, C> LabelBuilder<'a, C>
+where
+    C: WidgetContainer,
+{
+    pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
+        let plmt_is_default = builder.placement.is_none() && !builder.has_placement_mods();
+
+        Self {
+            props: Properties {
+                color: None,
+                align: TextHoriAlign::Left,
+                wrap: false,
+                placement: builder.take_placement(Label::default_placement),
+                plmt_is_default,
+            },
+            widget: builder,
+            text: String::new(),
+        }
+    }
+
+    /// Set the text.
+    pub fn text<T>(mut self, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.text = text.into();
+        self
+    }
+
+    /// Set the color of the text.
+    ///
+    /// **Note**: When this isn't used the theme's `text1a` color is used.
+    pub fn color(mut self, color: Color) -> Self {
+        self.props.color = Some(color);
+        self
+    }
+
+    /// Set the horizontal alignment of the text.
+    ///
+    /// **Note**: When this isn't used the text will be aligned to the left.
+    pub fn align(mut self, align: TextHoriAlign) -> Self {
+        self.props.align = align;
+        self
+    }
+
+    /// Set if the text should wrap onto multiple lines.
+    ///
+    /// **Notes**:
+    /// - When this isn't used the text will not wrap.
+    /// - When wrapping with the default placement, the [`Label`] fills the width of its
+    /// container but its height remains a single line. A placement with a suitable height should
+    /// be provided for multiple lines.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.props.wrap = wrap;
+        self
+    }
+
+    /// Finish building the [`Label`].
+    pub fn build(self) -> Arc<Label> {
+        let window = self
+            .widget
+            .container
+            .container_bin()
+            .window()
+            .expect("The widget container must have an associated window.");
+
+        let container = window.new_bin();
+
+        self.widget
+            .container
+            .container_bin()
+            .add_child(container.clone());
+
+        let label = Arc::new(Label {
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::Label),
+            props: self.props,
+            container,
+            state: ReentrantMutex::new(State {
+                text: RefCell::new(self.text),
+            }),
+        });
+
+        label.style_update();
+        self.widget.container.widget_built(label.clone());
+        label
+    }
+}
+
+/// Label widget
+///
+/// Displays text using the theme's font.
+///
+/// **Note**: When the default placement is used, the width is fit to the text once it has been
+/// laid out.
+pub struct Label {
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
+    props: Properties,
+    container: Arc<Bin>,
+    state: ReentrantMutex<State>,
+}
+
+struct State {
+    text: RefCell<String>,
+}
+
+impl Label {
+    /// Set the text.
+    pub fn set_text<T>(&self, text: T)
+    where
+        T: Into<String>,
+    {
+        let state = self.state.lock();
+        *state.text.borrow_mut() = text.into();
+        self.style_update();
+    }
+
+    /// Obtain the text.
+    pub fn text(&self) -> String {
+        self.state.lock().text.borrow().clone()
+    }
+
+    /// Set if this [`Label`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`Label`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`Label`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`Label`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::Label`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
+    /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
+    pub fn default_placement(theme: &Theme) -> WidgetPlacement {
+        WidgetPlacement {
+            position: Position::Floating,
+            margin_t: Pixels(theme.spacing),
+            margin_b: Pixels(theme.spacing),
+            margin_l: Pixels(theme.spacing),
+            margin_r: Pixels(theme.spacing),
+            width: Pixels(0.0),
+            height: Pixels(theme.base_size),
+            ..Default::default()
+        }
+    }
+
+    fn style_update(&self) {
+        let text = self.state.lock().text.borrow().clone();
+        let mut placement = self.props.placement.clone();
+        let fit_to_text = self.props.plmt_is_default && !self.props.wrap;
+
+        if self.props.plmt_is_default {
+            if self.props.wrap {
+                placement.width = PctOffset(100.0, -2.0 * self.theme.get().spacing);
+            } else {
+                // Starting from zero, all of the text is overflow and can be measured.
+                placement.width = Pixels(0.0);
+            }
+        }
+
+        // The alignment has no effect when the width fits the text.
+        let hori_align = if fit_to_text {
+            TextHoriAlign::Left
+        } else {
+            self.props.align
+        };
+
+        let container_style = BinStyle {
+            text_body: TextBody {
+                hori_align,
+                vert_align: TextVertAlign::Center,
+                text_wrap: if self.props.wrap {
+                    TextWrap::Normal
+                } else {
+                    TextWrap::None
+                },
+                base_attrs: TextAttrs {
+                    height: Pixels(self.theme.get().text_height),
+                    color: self.props.color.unwrap_or(self.theme.get().colors.text1a),
+                    font_family: self.theme.get().font_family.clone(),
+                    font_weight: self.theme.get().label_font_weight(),
+                    ..Default::default()
+                },
+                ..TextBody::from(text)
+            },
+            visibility: self.container.style_inspect(|style| style.visibility),
+            ..placement.into_style()
+        };
+
+        self.container.style_update(container_style).expect_valid();
+
+        if fit_to_text {
+            let container = self.container.clone();
+
+            crate::measure_text_width(&self.container, move |width| {
+                container.style_modify(|style| {
+                    style.width = Pixels(width);
+                });
+            });
+        }
+    }
+}
+
+impl Widget for Label {
+    fn focus(&self) {}
+
+    fn blur(&self) {}
+
+    fn is_visible(&self) -> bool {
+        Label::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        Label::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        Label::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        Label::set_theme(self, theme)
+    }
+}
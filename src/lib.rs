@@ -11,39 +11,68 @@ pub mod placement;
 mod button;
 mod check_box;
 mod code_editor;
+mod color_swatch;
+mod combo_box;
+mod focus_group;
+mod grid;
+mod label;
+mod number_field;
 mod progress_bar;
 mod radio_button;
+mod reorderable;
+mod role;
 mod scaler;
 mod scroll_bar;
+mod section;
 mod select;
+mod separator;
 mod spin_button;
+mod spinner;
 mod switch_button;
 mod text_editor;
 mod text_entry;
 mod text_hooks;
 mod theme;
 mod toggle_button;
+mod tracking_container;
 
 use std::sync::Arc;
 
-use basalt::interface::Bin;
+use basalt::interface::UnitValue::{PctOffset, Pixels};
+use basalt::interface::{
+    Bin, BinStyle, Color, Position, TextAttrs, TextBody, TextHoriAlign, TextVertAlign, TextWrap,
+    Visibility,
+};
 
 use self::builder::WidgetBuilder;
 pub use self::button::Button;
-pub use self::check_box::CheckBox;
+pub use self::check_box::{CheckBox, CheckBoxGroup};
 pub use self::code_editor::CodeEditor;
+pub use self::color_swatch::ColorSwatch;
+pub use self::combo_box::{ComboBox, ComboBoxValue};
+pub use self::focus_group::FocusGroup;
+pub use self::grid::Grid;
+pub use self::label::Label;
+pub use self::number_field::NumberField;
 pub use self::placement::{WidgetPlacement, WidgetPlcmtError, WidgetPlcmtErrorKind};
 pub use self::progress_bar::ProgressBar;
 pub use self::radio_button::{RadioButton, RadioButtonGroup};
-pub use self::scaler::{Scaler, ScalerOrientation, ScalerRound};
-pub use self::scroll_bar::{ScrollAxis, ScrollBar};
+pub use self::reorderable::Reorderable;
+pub use self::role::WidgetRole;
+pub use self::scaler::{Scaler, ScalerKnobShape, ScalerOrientation, ScalerRound};
+pub use self::scroll_bar::{Easing, ScrollAxis, ScrollBar, ScrollSource};
+pub use self::section::Section;
 pub use self::select::Select;
-pub use self::spin_button::SpinButton;
+pub use self::separator::{Separator, SeparatorOrientation};
+pub use self::spin_button::{SpinButton, SpinButtonLayout};
+pub use self::spinner::Spinner;
 pub use self::switch_button::SwitchButton;
-pub use self::text_editor::TextEditor;
+pub use self::text_editor::{LoadOptions, TextEditor};
 pub use self::text_entry::TextEntry;
-pub use self::theme::{Theme, ThemeColors};
+pub use self::text_hooks::WordBoundary;
+pub use self::theme::{ColorHex, Theme, ThemeColors, ThemeColorsBuilder};
 pub use self::toggle_button::ToggleButton;
+pub use self::tracking_container::TrackingContainer;
 
 /// Trait used by containers that support containing widgets.
 pub trait WidgetContainer: Sized {
@@ -56,6 +85,11 @@ pub trait WidgetContainer: Sized {
     fn default_theme(&self) -> Theme {
         Theme::default()
     }
+
+    /// Called with each widget built within this container.
+    ///
+    /// **Note**: Only widgets that implement [`Widget`] are provided.
+    fn widget_built(&self, _widget: Arc<dyn Widget>) {}
 }
 
 impl WidgetContainer for Arc<Bin> {
@@ -71,6 +105,140 @@ impl WidgetContainer for &Arc<Bin> {
     }
 }
 
+/// Trait implemented by widgets to allow operating on them without knowing their type.
+///
+/// This is primarily used by [`FocusGroup`] and [`TrackingContainer`].
+pub trait Widget: Send + Sync {
+    /// Give keyboard focus to this widget.
+    ///
+    /// **Note**: Widgets that can't receive focus ignore this.
+    fn focus(&self);
+
+    /// Remove keyboard focus from this widget.
+    ///
+    /// **Note**: Widgets that can't receive focus ignore this.
+    fn blur(&self);
+
+    /// Check if this widget is visible.
+    fn is_visible(&self) -> bool;
+
+    /// Set if this widget is visible.
+    fn set_visible(&self, visible: bool);
+
+    /// Obtain the bounds of this widget as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`. This is
+    /// useful for positioning other bins, such as popups, relative to the widget.
+    fn bounds(&self) -> [f32; 4];
+
+    /// Check if this widget is disabled.
+    ///
+    /// **Note**: Widgets that can't be disabled always return `false`.
+    fn is_disabled(&self) -> bool {
+        false
+    }
+
+    /// Set if this widget is disabled.
+    ///
+    /// **Note**: Widgets that can't be disabled ignore this.
+    fn set_disabled(&self, _disabled: bool) {}
+
+    /// Replace the theme of this widget and re-apply its styling.
+    ///
+    /// **Note**: Most widgets keep the sizes derived from the theme they were built with, such as
+    /// their default placement. [`Button`] derives its default placement when styled, so it
+    /// follows the new theme.
+    fn set_theme(&self, theme: Theme);
+}
+
+fn focus_bin(bin: &Arc<Bin>) {
+    bin.basalt_ref().input_ref().set_bin_focused(bin);
+}
+
+fn bin_bounds(bin: &Arc<Bin>) -> [f32; 4] {
+    let bpu = bin.post_update();
+    [bpu.tli[0], bpu.tri[0], bpu.tli[1], bpu.bli[1]]
+}
+
+/// Measure the width of the text of `bin` once it has been laid out.
+///
+/// **Note**: `bin` should have a width of zero with its text aligned to the left and not wrapping,
+/// so that all of the text is reported as overflow.
+fn measure_text_width<F>(bin: &Arc<Bin>, method: F)
+where
+    F: FnOnce(f32) + Send + 'static,
+{
+    bin.on_update_once(move |bin, _| {
+        method(bin.calc_hori_overflow());
+    });
+}
+
+fn blur_bin(bin: &Arc<Bin>) {
+    if let Some(window) = bin.window() {
+        bin.basalt_ref().input_ref().clear_bin_focus(window.id());
+    }
+}
+
+/// The border color of a field, an error takes precedence over focus.
+fn field_border_color(theme: &Theme, error: bool, focused: bool) -> Color {
+    if error {
+        theme.colors.error
+    } else if focused {
+        theme.colors.accent2
+    } else {
+        theme.colors.border1
+    }
+}
+
+/// Apply or clear the error appearance of a field.
+///
+/// The border of `field` is colored with the theme's error color and `message` is displayed
+/// below `field` with the error's text.
+fn set_field_error(
+    theme: &Theme,
+    field: &Arc<Bin>,
+    message: &Arc<Bin>,
+    error: Option<&str>,
+    focused: bool,
+) {
+    let border_color = field_border_color(theme, error.is_some(), focused);
+
+    let mut field_style = field.style_copy();
+    field_style.border_color_t = border_color;
+    field_style.border_color_b = border_color;
+    field_style.border_color_l = border_color;
+    field_style.border_color_r = border_color;
+
+    let message_style = BinStyle {
+        visibility: match error {
+            Some(_) => Visibility::Inheirt,
+            None => Visibility::Hide,
+        },
+        position: Position::Anchor,
+        pos_from_t: PctOffset(100.0, theme.spacing / 4.0),
+        pos_from_l: Pixels(0.0),
+        pos_from_r: Pixels(0.0),
+        height: Pixels(theme.text_height),
+        text_body: TextBody {
+            spans: vec![error.unwrap_or_default().to_string().into()],
+            hori_align: TextHoriAlign::Left,
+            vert_align: TextVertAlign::Center,
+            text_wrap: TextWrap::None,
+            base_attrs: TextAttrs {
+                height: Pixels(theme.text_height),
+                color: theme.colors.error,
+                font_family: theme.font_family.clone(),
+                font_weight: theme.label_font_weight(),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    Bin::style_update_batch([(field, field_style), (message, message_style)]);
+}
+
 fn ulps_eq(a: f32, b: f32, tol: u32) -> bool {
     if a.is_nan() || b.is_nan() {
         false
@@ -84,3 +252,18 @@ fn ulps_eq(a: f32, b: f32, tol: u32) -> bool {
         (max - min) <= tol
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_border_color_error_over_focus() {
+        let theme = Theme::default();
+        let hex = |error, focused| field_border_color(&theme, error, focused).to_hex();
+        assert_eq!(hex(false, false), theme.colors.border1.to_hex());
+        assert_eq!(hex(false, true), theme.colors.accent2.to_hex());
+        assert_eq!(hex(true, false), theme.colors.error.to_hex());
+        assert_eq!(hex(true, true), theme.colors.error.to_hex());
+    }
+}
@@ -0,0 +1,637 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use basalt::input::InputHookCtrl;
+use basalt::interface::UnitValue::Pixels;
+use basalt::interface::{
+    Bin, BinPostUpdate, BinStyle, Position, TextAttrs, TextBody, TextHoriAlign, TextVertAlign,
+    TextWrap, Visibility,
+};
+use parking_lot::ReentrantMutex;
+
+use crate::builder::WidgetBuilder;
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole, text_hooks};
+
+/// Builder for [`NumberField`]
+pub struct NumberFieldBuilder<'a, C> {
+    widget: WidgetBuilder<'a, C>,
+    props: Properties,
+    on_change: Vec<Box<dyn FnMut(&Arc<NumberField>, f64) + Send + 'static>>,
+}
+
+/// An error than can occur from [`NumberFieldBuilder::build`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumberFieldError {
+    /// Value provided by [`NumberFieldBuilder::max_value`] is greater than the value provided by
+    /// [`NumberFieldBuilder::min_value`].
+    MaxLessThanMin,
+    /// Value provided by [`NumberFieldBuilder::set_value`] is not in range specified by
+    /// [`NumberFieldBuilder::min_value`] and [`NumberFieldBuilder::max_value`].
+    SetValNotInRange,
+}
+
+struct Properties {
+    min: Option<f64>,
+    max: Option<f64>,
+    val: f64,
+    decimals: Option<usize>,
+    placement: WidgetPlacement,
+}
+
+impl Properties {
+    fn new(placement: WidgetPlacement) -> Self {
+        Self {
+            min: None,
+            max: None,
+            val: 0.0,
+            decimals: None,
+            placement,
+        }
+    }
+}
+
+impl<'a, C> NumberFieldBuilder<'a, C>
+where
+    C: WidgetContainer,
+{
+    pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
+        Self {
+            props: Properties::new(builder.take_placement(NumberField::default_placement)),
+            widget: builder,
+            on_change: Vec::new(),
+        }
+    }
+
+    /// Specify the minimum value.
+    ///
+    /// **Note**: When this isn't used there is no minimum value.
+    pub fn min_value(mut self, min: f64) -> Self {
+        self.props.min = Some(min);
+        self
+    }
+
+    /// Specify the maximum value.
+    ///
+    /// **Note**: When this isn't used there is no maximum value.
+    pub fn max_value(mut self, max: f64) -> Self {
+        self.props.max = Some(max);
+        self
+    }
+
+    /// Set the initial value.
+    ///
+    /// **Note**: When this isn't used the initial value will be `0.0`.
+    pub fn set_value(mut self, val: f64) -> Self {
+        self.props.val = val;
+        self
+    }
+
+    /// Set the number of decimal places.
+    ///
+    /// Values will be rounded to this many decimal places. When zero, a decimal point can not be
+    /// entered.
+    ///
+    /// **Note**: When this isn't used the value is not rounded.
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.props.decimals = Some(decimals);
+        self
+    }
+
+    /// Add a callback to be called when the [`NumberField`]'s value changed.
+    ///
+    /// **Note**: When changing the value within the callback, no callbacks will be called with
+    ///  the updated value.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: FnMut(&Arc<NumberField>, f64) + Send + 'static,
+    {
+        self.on_change.push(Box::new(on_change));
+        self
+    }
+
+    /// Finish building the [`NumberField`].
+    pub fn build(self) -> Result<Arc<NumberField>, NumberFieldError> {
+        if let (Some(min), Some(max)) = (self.props.min, self.props.max)
+            && max < min
+        {
+            return Err(NumberFieldError::MaxLessThanMin);
+        }
+
+        if self.props.min.is_some_and(|min| self.props.val < min)
+            || self.props.max.is_some_and(|max| self.props.val > max)
+        {
+            return Err(NumberFieldError::SetValNotInRange);
+        }
+
+        let window = self
+            .widget
+            .container
+            .container_bin()
+            .window()
+            .expect("The widget container must have an associated window.");
+
+        let mut new_bins = window.new_bins(3).into_iter();
+        let container = new_bins.next().unwrap();
+        let entry = new_bins.next().unwrap();
+        let error_message = new_bins.next().unwrap();
+
+        self.widget
+            .container
+            .container_bin()
+            .add_child(container.clone());
+
+        container.add_child(entry.clone());
+        container.add_child(error_message.clone());
+
+        let initial_val = self.props.val;
+
+        let number_field = Arc::new(NumberField {
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::SpinButton),
+            props: self.props,
+            container,
+            entry,
+            error_message,
+            state: ReentrantMutex::new(State {
+                val: RefCell::new(initial_val),
+                on_change: RefCell::new(self.on_change),
+                error: RefCell::new(None),
+                focused: RefCell::new(false),
+            }),
+        });
+
+        {
+            let state = number_field.state.lock();
+            let rounded_val = number_field.round_val(initial_val);
+            *state.val.borrow_mut() = rounded_val;
+        }
+
+        let number_field_wk = Arc::downgrade(&number_field);
+
+        text_hooks::create(
+            text_hooks::Properties::ENTRY,
+            number_field.entry.clone(),
+            number_field.theme.get().clone(),
+            Some(Arc::new(move |updated| {
+                let text_hooks::Updated {
+                    cursor: _,
+                    cursor_bounds,
+                    body_line_count: _,
+                    cursor_line_col: _,
+                    editor_bpu,
+                } = updated;
+
+                if let Some(cursor_bounds) = cursor_bounds
+                    && let Some(number_field) = number_field_wk.upgrade()
+                {
+                    number_field.check_cursor_in_view(editor_bpu, cursor_bounds);
+                }
+            })),
+            None,
+        );
+
+        let number_field_wk = Arc::downgrade(&number_field);
+
+        window
+            .basalt_ref()
+            .input_ref()
+            .hook()
+            .bin(&number_field.entry)
+            .on_character()
+            .weight(1)
+            .call(move |_, _, c| {
+                let number_field = match number_field_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                match c.0 {
+                    '\r' | '\n' | '\u{1b}' => {
+                        if c.0 != '\u{1b}' {
+                            number_field.commit_entry();
+                        }
+
+                        // Move focus to the container rather than clearing the window's focus, so
+                        // that keyboard input stays within the widget.
+                        number_field
+                            .entry
+                            .basalt_ref()
+                            .input_ref()
+                            .set_bin_focused(&number_field.container);
+
+                        InputHookCtrl::RetainNoPass
+                    },
+                    '0'..='9' => Default::default(),
+                    '-' if number_field.props.min.is_none_or(|min| min < 0.0) => Default::default(),
+                    '.' if number_field.props.decimals != Some(0) => Default::default(),
+                    c if c.is_control() => Default::default(),
+                    _ => InputHookCtrl::RetainNoPass,
+                }
+            })
+            .finish()
+            .unwrap();
+
+        let number_field_wk = Arc::downgrade(&number_field);
+
+        number_field.entry.on_focus(move |_, _| {
+            let number_field = match number_field_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            let theme = &number_field.theme.get();
+            *number_field.state.lock().focused.borrow_mut() = true;
+
+            if theme.border.is_some() {
+                let border_color =
+                    crate::field_border_color(theme, number_field.error().is_some(), true);
+
+                number_field.entry.style_modify(|style| {
+                    style.border_color_t = border_color;
+                    style.border_color_b = border_color;
+                    style.border_color_l = border_color;
+                    style.border_color_r = border_color;
+                });
+            }
+
+            Default::default()
+        });
+
+        let number_field_wk = Arc::downgrade(&number_field);
+
+        number_field.entry.on_focus_lost(move |_, _| {
+            let number_field = match number_field_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
+
+            let theme = &number_field.theme.get();
+            let text = number_field.val_text(number_field.value());
+            *number_field.state.lock().focused.borrow_mut() = false;
+            let border_color =
+                crate::field_border_color(theme, number_field.error().is_some(), false);
+
+            number_field.entry.style_modify(|style| {
+                if theme.border.is_some() {
+                    style.border_color_t = border_color;
+                    style.border_color_b = border_color;
+                    style.border_color_l = border_color;
+                    style.border_color_r = border_color;
+                }
+
+                style.scroll_x = 0.0;
+                style.text_body.spans = vec![text.into()];
+            });
+
+            Default::default()
+        });
+
+        number_field.style_update();
+        number_field.set_error(None);
+        self.widget.container.widget_built(number_field.clone());
+        Ok(number_field)
+    }
+}
+
+/// Number field widget
+///
+/// **Notes**:
+/// - Only numeric characters can be entered.
+/// - The entered value is applied when enter is pressed. Pressing escape or removing focus
+/// discards the entered value.
+pub struct NumberField {
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
+    props: Properties,
+    container: Arc<Bin>,
+    entry: Arc<Bin>,
+    error_message: Arc<Bin>,
+    state: ReentrantMutex<State>,
+}
+
+struct State {
+    val: RefCell<f64>,
+    on_change: RefCell<Vec<Box<dyn FnMut(&Arc<NumberField>, f64) + Send + 'static>>>,
+    error: RefCell<Option<String>>,
+    focused: RefCell<bool>,
+}
+
+impl NumberField {
+    /// Set the value to the provided valued.
+    ///
+    /// **Notes**:
+    /// - This will be rounded to the decimals provided by [`NumberFieldBuilder::decimals`].
+    /// - This value will be clamped to values provided by [`NumberFieldBuilder::min_value`]
+    /// and [`NumberFieldBuilder::max_value`].
+    pub fn set_value(self: &Arc<Self>, val: f64) {
+        let state = self.state.lock();
+        let val = self.round_val(val);
+        *state.val.borrow_mut() = val;
+        let text = self.val_text(val);
+
+        self.entry.style_modify(|style| {
+            style.text_body.spans = vec![text.into()];
+        });
+
+        if let Ok(mut on_change_cbs) = state.on_change.try_borrow_mut() {
+            for on_change in on_change_cbs.iter_mut() {
+                on_change(self, val);
+            }
+        }
+    }
+
+    /// Get the current value.
+    pub fn value(&self) -> f64 {
+        *self.state.lock().val.borrow()
+    }
+
+    /// Add a callback to be called when the [`NumberField`]'s value changed.
+    ///
+    /// **Note**: When changing the value within the callback, no callbacks will be called with
+    ///  the updated value.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_change<F>(&self, on_change: F)
+    where
+        F: FnMut(&Arc<NumberField>, f64) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_change
+            .borrow_mut()
+            .push(Box::new(on_change));
+    }
+
+    /// Set or clear the error of this [`NumberField`].
+    ///
+    /// When `Some`, the border is colored with the theme's `error` color and the message is
+    /// displayed below the [`NumberField`]. When `None`, the normal styling is restored.
+    ///
+    /// **Notes**:
+    /// - The message may overlap neighboring widgets.
+    /// - The border is only displayed when the theme has a border.
+    pub fn set_error(&self, error: Option<String>) {
+        let state = self.state.lock();
+        crate::set_field_error(
+            &self.theme.get(),
+            &self.entry,
+            &self.error_message,
+            error.as_deref(),
+            *state.focused.borrow(),
+        );
+        *state.error.borrow_mut() = error;
+    }
+
+    /// Obtain the current error provided by [`set_error`](Self::set_error).
+    pub fn error(&self) -> Option<String> {
+        self.state.lock().error.borrow().clone()
+    }
+
+    /// Give keyboard focus to this [`NumberField`].
+    ///
+    /// This allows text to be entered without clicking on it first.
+    pub fn focus(&self) {
+        crate::focus_bin(&self.entry);
+    }
+
+    /// Remove keyboard focus from this [`NumberField`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`NumberField`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.entry);
+    }
+
+    /// Set if this [`NumberField`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`NumberField`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`NumberField`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`NumberField`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::SpinButton`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
+    /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
+    pub fn default_placement(theme: &Theme) -> WidgetPlacement {
+        let height = theme.spacing + theme.base_size;
+        let width = height * 3.5;
+
+        WidgetPlacement {
+            position: Position::Floating,
+            margin_t: Pixels(theme.spacing),
+            margin_b: Pixels(theme.spacing),
+            margin_l: Pixels(theme.spacing),
+            margin_r: Pixels(theme.spacing),
+            width: Pixels(width),
+            height: Pixels(height),
+            ..Default::default()
+        }
+    }
+
+    fn round_val(&self, mut val: f64) -> f64 {
+        if let Some(decimals) = self.props.decimals {
+            let mult = 10.0_f64.powi(decimals as i32);
+            val = (val * mult).round() / mult;
+        }
+
+        if let Some(min) = self.props.min {
+            val = val.max(min);
+        }
+
+        if let Some(max) = self.props.max {
+            val = val.min(max);
+        }
+
+        val
+    }
+
+    fn val_text(&self, val: f64) -> String {
+        match self.props.decimals {
+            Some(decimals) => format!("{:.*}", decimals, val),
+            None => format!("{}", val),
+        }
+    }
+
+    fn commit_entry(self: &Arc<Self>) {
+        let val = self
+            .entry
+            .style_inspect(|style| {
+                style
+                    .text_body
+                    .spans
+                    .iter()
+                    .map(|span| span.text.as_str())
+                    .collect::<String>()
+                    .parse::<f64>()
+            })
+            .ok()
+            .filter(|val| val.is_finite());
+
+        match val {
+            Some(val) => self.set_value(val),
+            None => {
+                let text = self.val_text(self.value());
+
+                self.entry.style_modify(|style| {
+                    style.text_body.spans = vec![text.into()];
+                });
+            },
+        }
+    }
+
+    fn check_cursor_in_view(&self, entry_bpu: &BinPostUpdate, cursor_bounds: [f32; 4]) {
+        let view_bounds = entry_bpu.optimal_content_bounds;
+
+        let scroll_x_op = if cursor_bounds[0] < view_bounds[0] {
+            Some(cursor_bounds[0] - entry_bpu.content_offset[0] - view_bounds[0])
+        } else if cursor_bounds[1] > view_bounds[1] {
+            Some(cursor_bounds[1] - entry_bpu.content_offset[0] - view_bounds[1])
+        } else {
+            None
+        };
+
+        if let Some(scroll_x) = scroll_x_op {
+            self.entry.style_modify(|style| {
+                style.scroll_x = scroll_x;
+            });
+        }
+    }
+
+    fn style_update(&self) {
+        let container_style = BinStyle {
+            visibility: self.container.style_inspect(|style| style.visibility),
+            ..self.props.placement.clone().into_style()
+        };
+
+        let mut entry_style = BinStyle {
+            pos_from_t: Pixels(0.0),
+            pos_from_b: Pixels(0.0),
+            pos_from_l: Pixels(0.0),
+            pos_from_r: Pixels(0.0),
+            back_color: self.theme.get().colors.back2,
+            padding_l: Pixels(self.theme.get().spacing),
+            padding_r: Pixels(self.theme.get().spacing),
+            text_body: TextBody {
+                spans: vec![self.val_text(self.value()).into()],
+                hori_align: TextHoriAlign::Left,
+                vert_align: TextVertAlign::Center,
+                text_wrap: TextWrap::None,
+                base_attrs: TextAttrs {
+                    height: Pixels(self.theme.get().text_height),
+                    color: self.theme.get().colors.text1a,
+                    font_family: self.theme.get().font_family.clone(),
+                    font_weight: self.theme.get().value_font_weight(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        if let Some(border_size) = self.theme.get().border {
+            entry_style.border_size_t = Pixels(border_size);
+            entry_style.border_size_b = Pixels(border_size);
+            entry_style.border_size_l = Pixels(border_size);
+            entry_style.border_size_r = Pixels(border_size);
+
+            let border_color = crate::field_border_color(
+                &self.theme.get(),
+                self.error().is_some(),
+                *self.state.lock().focused.borrow(),
+            );
+
+            entry_style.border_color_t = border_color;
+            entry_style.border_color_b = border_color;
+            entry_style.border_color_l = border_color;
+            entry_style.border_color_r = border_color;
+        }
+
+        if let Some(border_radius) = self.theme.get().roundness {
+            entry_style.border_radius_tl = Pixels(border_radius);
+            entry_style.border_radius_tr = Pixels(border_radius);
+            entry_style.border_radius_bl = Pixels(border_radius);
+            entry_style.border_radius_br = Pixels(border_radius);
+        }
+
+        Bin::style_update_batch([
+            (&self.container, container_style),
+            (&self.entry, entry_style),
+        ]);
+    }
+}
+
+impl Widget for NumberField {
+    fn focus(&self) {
+        NumberField::focus(self)
+    }
+
+    fn blur(&self) {
+        NumberField::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        NumberField::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        NumberField::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        NumberField::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        NumberField::set_theme(self, theme)
+    }
+}
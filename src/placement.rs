@@ -1,5 +1,7 @@
+use std::sync::Arc;
+
 use basalt::interface::UnitValue::Undefined;
-use basalt::interface::{BinStyle, FloatWeight, Position, UnitValue, ZIndex};
+use basalt::interface::{Bin, BinStyle, FloatWeight, Position, UnitValue, ZIndex};
 
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct WidgetPlacement {
@@ -29,6 +31,40 @@ pub enum WidgetPlcmtErrorKind {
 }
 
 impl WidgetPlacement {
+    /// Create a [`WidgetPlacement`] from the current style of a [`Bin`].
+    ///
+    /// This is useful for placing a widget the same as an existing one, such as a sibling.
+    ///
+    /// The fields captured are `position`, `z_index`, `float_weight`, `pos_from_t`, `pos_from_b`,
+    /// `pos_from_l`, `pos_from_r`, `width`, `height`, `margin_t`, `margin_b`, `margin_l` and
+    /// `margin_r`.
+    ///
+    /// **Note**: The values are copied as they are defined in the style, not as computed.
+    pub fn from_bin(bin: &Arc<Bin>) -> Self {
+        bin.style_inspect(Self::from_style)
+    }
+
+    /// Create a [`WidgetPlacement`] from the placement related fields of a [`BinStyle`].
+    ///
+    /// **Note**: See [`from_bin`](Self::from_bin) for the fields captured.
+    pub fn from_style(style: &BinStyle) -> Self {
+        Self {
+            position: style.position,
+            z_index: style.z_index,
+            float_weight: style.float_weight,
+            pos_from_t: style.pos_from_t,
+            pos_from_b: style.pos_from_b,
+            pos_from_l: style.pos_from_l,
+            pos_from_r: style.pos_from_r,
+            width: style.width,
+            height: style.height,
+            margin_t: style.margin_t,
+            margin_b: style.margin_b,
+            margin_l: style.margin_l,
+            margin_r: style.margin_r,
+        }
+    }
+
     #[allow(dead_code)]
     pub(crate) fn validate(&self) -> Result<(), WidgetPlcmtError> {
         match self.position {
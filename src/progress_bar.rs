@@ -1,13 +1,20 @@
 use std::cell::RefCell;
-use std::sync::Arc;
+use std::f32::consts::PI;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 
+use basalt::image::ImageKey;
 use basalt::input::MouseButton;
-use basalt::interface::UnitValue::{Percent, Pixels};
-use basalt::interface::{Bin, BinStyle, Position};
+use basalt::interface::UnitValue::{PctOffset, Percent, Pixels};
+use basalt::interface::{
+    Bin, BinStyle, BinVertex, Position, TextAttrs, TextBody, TextHoriAlign, TextVertAlign,
+    TextWrap, Visibility, ZIndex,
+};
 use parking_lot::ReentrantMutex;
 
 use crate::builder::WidgetBuilder;
-use crate::{Theme, WidgetContainer, WidgetPlacement};
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
 
 /// Builder for [`ProgressBar`].
 pub struct ProgressBarBuilder<'a, C> {
@@ -18,6 +25,10 @@ pub struct ProgressBarBuilder<'a, C> {
 
 struct Properties {
     pct: f32,
+    range: [f32; 2],
+    animation_duration: Duration,
+    animated_stripes: bool,
+    ticks: Vec<(f32, String)>,
     placement: WidgetPlacement,
 }
 
@@ -25,6 +36,10 @@ impl Properties {
     fn new(placement: WidgetPlacement) -> Self {
         Self {
             pct: 0.0,
+            range: [0.0, 100.0],
+            animation_duration: Duration::ZERO,
+            animated_stripes: false,
+            ticks: Vec::new(),
             placement,
         }
     }
@@ -36,12 +51,7 @@ where
 {
     pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
         Self {
-            props: Properties::new(
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| ProgressBar::default_placement(&builder.theme)),
-            ),
+            props: Properties::new(builder.take_placement(ProgressBar::default_placement)),
             widget: builder,
             on_press: Vec::new(),
         }
@@ -55,6 +65,52 @@ where
         self
     }
 
+    /// Set the range of values used by [`ProgressBar::set_value`] and [`ProgressBar::value`].
+    ///
+    /// **Notes**:
+    /// - When this isn't used the range is `0.0` to `100.0`, the same as the percent.
+    /// - If `min` is greater than `max` they are swapped.
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.props.range = [min.min(max), min.max(max)];
+        self
+    }
+
+    /// Animate changes to the percent over the provided duration.
+    ///
+    /// **Notes**:
+    /// - When this isn't used or the duration is zero, changes are applied instantly.
+    /// - Has no effect if the theme's `reduce_motion` is enabled.
+    pub fn animate(mut self, duration: Duration) -> Self {
+        self.props.animation_duration = duration;
+        self
+    }
+
+    /// Display moving diagonal stripes over the fill to indicate active work.
+    ///
+    /// **Notes**:
+    /// - The stripes only move while the percent is between `0.0` and `100.0`.
+    /// - Has no effect if the theme's `reduce_motion` is enabled.
+    pub fn animated_stripes(mut self, animated_stripes: bool) -> Self {
+        self.props.animated_stripes = animated_stripes;
+        self
+    }
+
+    /// Draw tick marks with labels along the [`ProgressBar`].
+    ///
+    /// Each tick is a fraction along the bar, from `0.0` to `1.0`, and its label.
+    ///
+    /// **Notes**:
+    /// - An empty label will draw only the tick mark.
+    /// - Labels are displayed below the bar and may overlap neighboring widgets.
+    pub fn ticks(mut self, ticks: Vec<(f32, String)>) -> Self {
+        self.props.ticks = ticks
+            .into_iter()
+            .map(|(fraction, label)| (fraction.clamp(0.0, 1.0), label))
+            .collect();
+
+        self
+    }
+
     /// Add a callback to be called when the [`ProgressBar`] is pressed.
     ///
     /// The callback is called with the cursors percent along the [`ProgressBar`].
@@ -69,7 +125,12 @@ where
     }
 
     /// Finish building the [`ProgressBar`].
-    pub fn build(self) -> Arc<ProgressBar> {
+    pub fn build(mut self) -> Arc<ProgressBar> {
+        if self.widget.theme.reduce_motion {
+            self.props.animation_duration = Duration::ZERO;
+            self.props.animated_stripes = false;
+        }
+
         let window = self
             .widget
             .container
@@ -77,9 +138,18 @@ where
             .window()
             .expect("The widget container must have an associated window.");
 
-        let mut new_bins = window.new_bins(2).into_iter();
+        let num_labels = self
+            .props
+            .ticks
+            .iter()
+            .filter(|(_, label)| !label.is_empty())
+            .count();
+
+        let mut new_bins = window.new_bins(3 + num_labels).into_iter();
         let container = new_bins.next().unwrap();
         let fill = new_bins.next().unwrap();
+        let tick_marks = new_bins.next().unwrap();
+        let tick_labels = new_bins.collect::<Vec<_>>();
 
         self.widget
             .container
@@ -87,17 +157,40 @@ where
             .add_child(container.clone());
 
         container.add_child(fill.clone());
+        container.add_child(tick_marks.clone());
+
+        for tick_label in tick_labels.iter() {
+            container.add_child(tick_label.clone());
+        }
         let initial_pct = self.props.pct;
 
-        let progress_bar = Arc::new(ProgressBar {
-            theme: self.widget.theme,
-            props: self.props,
-            container,
-            fill,
-            state: ReentrantMutex::new(State {
-                pct: RefCell::new(initial_pct),
-                on_press: RefCell::new(self.on_press),
-            }),
+        let progress_bar = Arc::new_cyclic(|this| {
+            ProgressBar {
+                this: this.clone(),
+                theme: ThemeCell::new(self.widget.theme),
+                name: self.widget.name,
+                aria_label: self.widget.aria_label,
+                role: self.widget.role.unwrap_or(WidgetRole::ProgressBar),
+                props: self.props,
+                container,
+                fill,
+                tick_marks,
+                tick_labels,
+                state: ReentrantMutex::new(State {
+                    pct: RefCell::new(initial_pct),
+                    animation: RefCell::new(AnimationState {
+                        run: false,
+                        start: initial_pct,
+                        current: initial_pct,
+                        time: 0.0,
+                    }),
+                    stripes: RefCell::new(StripeState {
+                        run: false,
+                        offset: 0.0,
+                    }),
+                    on_press: RefCell::new(self.on_press),
+                }),
+            }
         });
 
         let cb_progress_bar = progress_bar.clone();
@@ -118,38 +211,80 @@ where
                 Default::default()
             });
 
+        let cb_progress_bar = progress_bar.clone();
+
+        progress_bar
+            .tick_marks
+            .on_press(MouseButton::Left, move |_, w_state, _| {
+                cb_progress_bar.proc_press(w_state.cursor_pos());
+                Default::default()
+            });
+
         progress_bar.style_update();
+        progress_bar.check_stripes(initial_pct);
+        self.widget.container.widget_built(progress_bar.clone());
         progress_bar
     }
 }
 
 /// Progress bar widget
 pub struct ProgressBar {
-    theme: Theme,
+    this: Weak<ProgressBar>,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     container: Arc<Bin>,
     fill: Arc<Bin>,
+    tick_marks: Arc<Bin>,
+    tick_labels: Vec<Arc<Bin>>,
     state: ReentrantMutex<State>,
 }
 
 struct State {
     pct: RefCell<f32>,
+    animation: RefCell<AnimationState>,
+    stripes: RefCell<StripeState>,
     on_press: RefCell<Vec<Box<dyn FnMut(&Arc<ProgressBar>, f32) + Send + 'static>>>,
 }
 
+struct AnimationState {
+    run: bool,
+    start: f32,
+    current: f32,
+    time: f32,
+}
+
+struct StripeState {
+    run: bool,
+    offset: f32,
+}
+
 impl ProgressBar {
     /// Set the percent
+    ///
+    /// **Note**: If [`ProgressBarBuilder::animate`] was used, the fill will animate from its
+    /// current position.
     pub fn set_pct(self: &Arc<Self>, pct: f32) {
         let pct = pct.clamp(0.0, 100.0);
+        let state = self.state.lock();
+        *state.pct.borrow_mut() = pct;
+        let mut animation_state = state.animation.borrow_mut();
 
-        self.fill
-            .style_update(BinStyle {
-                width: Percent(pct),
-                ..self.fill.style_copy()
-            })
-            .expect_valid();
+        if self.props.animation_duration.is_zero() {
+            animation_state.current = pct;
+            self.update_fill(pct);
+            return;
+        }
 
-        *self.state.lock().pct.borrow_mut() = pct;
+        animation_state.start = animation_state.current;
+        animation_state.time = 0.0;
+
+        if !animation_state.run {
+            animation_state.run = true;
+            self.run_animation();
+        }
     }
 
     /// Get the current percent
@@ -157,6 +292,37 @@ impl ProgressBar {
         *self.state.lock().pct.borrow()
     }
 
+    /// Set the value within the range provided by [`ProgressBarBuilder::range`].
+    ///
+    /// **Notes**:
+    /// - The value is clamped to the range.
+    /// - If the range is empty, e.g. `min == max`, the [`ProgressBar`] will be empty unless the
+    /// value is at least `max`.
+    pub fn set_value(self: &Arc<Self>, value: f32) {
+        let [min, max] = self.props.range;
+
+        let pct = if max - min > 0.0 {
+            ((value.clamp(min, max) - min) / (max - min)) * 100.0
+        } else if value >= max {
+            100.0
+        } else {
+            0.0
+        };
+
+        self.set_pct(pct);
+    }
+
+    /// Get the current value within the range provided by [`ProgressBarBuilder::range`].
+    pub fn value(&self) -> f32 {
+        let [min, max] = self.props.range;
+        min + ((max - min) * self.fraction())
+    }
+
+    /// Get the current fraction, from `0.0` to `1.0`.
+    pub fn fraction(&self) -> f32 {
+        self.pct() / 100.0
+    }
+
     /// Add a callback to be called when the [`ProgressBar`] is pressed.
     ///
     /// The callback is called with the cursors percent along the [`ProgressBar`].
@@ -173,6 +339,145 @@ impl ProgressBar {
             .push(Box::new(on_press));
     }
 
+    fn update_fill(self: &Arc<Self>, pct: f32) {
+        self.fill
+            .style_update(BinStyle {
+                width: Percent(pct),
+                ..self.fill.style_copy()
+            })
+            .expect_valid();
+
+        self.check_stripes(pct);
+    }
+
+    fn check_stripes(self: &Arc<Self>, pct: f32) {
+        if !self.props.animated_stripes || pct <= 0.0 || pct >= 100.0 {
+            return;
+        }
+
+        let state = self.state.lock();
+        let mut stripe_state = state.stripes.borrow_mut();
+
+        if !stripe_state.run {
+            stripe_state.run = true;
+            self.run_stripes();
+        }
+    }
+
+    fn run_stripes(self: &Arc<Self>) {
+        if let Some(window) = self.container.window() {
+            let progress_bar_wk = Arc::downgrade(self);
+            let period = self.theme.get().base_size;
+
+            window.renderer_on_frame(move |elapsed_op| {
+                let progress_bar = match progress_bar_wk.upgrade() {
+                    Some(some) => some,
+                    None => return false,
+                };
+
+                let state = progress_bar.state.lock();
+                let current = state.animation.borrow().current;
+                let mut stripe_state = state.stripes.borrow_mut();
+
+                if current <= 0.0 || current >= 100.0 {
+                    stripe_state.run = false;
+
+                    progress_bar.fill.style_modify(|style| {
+                        style.user_vertexes.clear();
+                    });
+
+                    return false;
+                }
+
+                if let Some(elapsed) = elapsed_op {
+                    stripe_state.offset = (stripe_state.offset
+                        + (elapsed.as_micros() as f32 / 1000.0 * STRIPE_SPEED))
+                        % period;
+                }
+
+                let vertexes = progress_bar.stripe_verts(stripe_state.offset);
+
+                progress_bar.fill.style_modify(|style| {
+                    style.user_vertexes = vec![(ImageKey::INVALID, vertexes)];
+                });
+
+                true
+            });
+        }
+    }
+
+    fn stripe_verts(&self, offset: f32) -> Vec<BinVertex> {
+        let bpu = self.fill.post_update();
+        let width = bpu.tri[0] - bpu.tli[0];
+        let height = bpu.bli[1] - bpu.tli[1];
+        let period = self.theme.get().base_size;
+        let stripe_width = period / 2.0;
+        let color = self.theme.get().colors.accent2;
+        let mut vertexes = Vec::new();
+
+        if width <= 0.0 || height <= 0.0 {
+            return vertexes;
+        }
+
+        let mut x = offset - height - period;
+
+        while x < width {
+            let points = clip_x(
+                &[
+                    [x, height],
+                    [x + stripe_width, height],
+                    [x + stripe_width + height, 0.0],
+                    [x + height, 0.0],
+                ],
+                0.0,
+                width,
+            );
+
+            for i in 1..points.len().saturating_sub(1) {
+                for [x, y] in [points[0], points[i], points[i + 1]] {
+                    vertexes.push(BinVertex {
+                        x: Pixels(x),
+                        y: Pixels(y),
+                        color,
+                        ..Default::default()
+                    });
+                }
+            }
+
+            x += period;
+        }
+
+        vertexes
+    }
+
+    fn run_animation(self: &Arc<Self>) {
+        if let Some(window) = self.container.window() {
+            let progress_bar = self.clone();
+            let animation_duration = self.props.animation_duration.as_micros() as f32 / 1000.0;
+
+            window.renderer_on_frame(move |elapsed_op| {
+                let state = progress_bar.state.lock();
+                let mut animation_state = state.animation.borrow_mut();
+
+                if !animation_state.run {
+                    return false;
+                }
+
+                if let Some(elapsed) = elapsed_op {
+                    animation_state.time += elapsed.as_micros() as f32 / 1000.0;
+                }
+
+                let delta = *state.pct.borrow() - animation_state.start;
+                let linear_t = (animation_state.time / animation_duration).clamp(0.0, 1.0);
+                let smooth_t = (((linear_t + 1.5) * PI).sin() + 1.0) / 2.0;
+                animation_state.current = animation_state.start + (delta * smooth_t);
+                progress_bar.update_fill(animation_state.current);
+                animation_state.run = animation_state.time < animation_duration;
+                animation_state.run
+            });
+        }
+    }
+
     fn proc_press(self: &Arc<Self>, cursor: [f32; 2]) {
         let bpu = self.container.post_update();
 
@@ -186,6 +491,58 @@ impl ProgressBar {
         }
     }
 
+    /// Set if this [`ProgressBar`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`ProgressBar`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`ProgressBar`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`ProgressBar`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(self: &Arc<Self>, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::ProgressBar`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
     pub fn default_placement(theme: &Theme) -> WidgetPlacement {
         WidgetPlacement {
@@ -204,7 +561,8 @@ impl ProgressBar {
         let pct = *self.state.lock().pct.borrow();
 
         let mut container_style = BinStyle {
-            back_color: self.theme.colors.back2,
+            back_color: self.theme.get().colors.back2,
+            visibility: self.container.style_inspect(|style| style.visibility),
             ..self.props.placement.clone().into_style()
         };
 
@@ -213,22 +571,22 @@ impl ProgressBar {
             pos_from_b: Pixels(0.0),
             pos_from_l: Pixels(0.0),
             width: Percent(pct),
-            back_color: self.theme.colors.accent1,
+            back_color: self.theme.get().colors.accent1,
             ..Default::default()
         };
 
-        if let Some(border_size) = self.theme.border {
+        if let Some(border_size) = self.theme.get().border {
             container_style.border_size_t = Pixels(border_size);
             container_style.border_size_b = Pixels(border_size);
             container_style.border_size_l = Pixels(border_size);
             container_style.border_size_r = Pixels(border_size);
-            container_style.border_color_t = self.theme.colors.border1;
-            container_style.border_color_b = self.theme.colors.border1;
-            container_style.border_color_l = self.theme.colors.border1;
-            container_style.border_color_r = self.theme.colors.border1;
+            container_style.border_color_t = self.theme.get().colors.border1;
+            container_style.border_color_b = self.theme.get().colors.border1;
+            container_style.border_color_l = self.theme.get().colors.border1;
+            container_style.border_color_r = self.theme.get().colors.border1;
         }
 
-        if let Some(radius) = self.theme.roundness {
+        if let Some(radius) = self.theme.get().roundness {
             container_style.border_radius_tl = Pixels(radius);
             container_style.border_radius_tr = Pixels(radius);
             container_style.border_radius_bl = Pixels(radius);
@@ -239,6 +597,135 @@ impl ProgressBar {
             fill_style.border_radius_br = Pixels(radius);
         }
 
-        Bin::style_update_batch([(&self.container, container_style), (&self.fill, fill_style)]);
+        let tick_width = self.theme.get().border.unwrap_or(1.0);
+        let mut tick_verts = Vec::with_capacity(self.props.ticks.len() * 6);
+
+        for (fraction, _) in self.props.ticks.iter() {
+            let x = fraction * 100.0;
+
+            for (x, y) in [
+                (PctOffset(x, -tick_width / 2.0), Percent(65.0)),
+                (PctOffset(x, -tick_width / 2.0), Percent(100.0)),
+                (PctOffset(x, tick_width / 2.0), Percent(100.0)),
+                (PctOffset(x, -tick_width / 2.0), Percent(65.0)),
+                (PctOffset(x, tick_width / 2.0), Percent(100.0)),
+                (PctOffset(x, tick_width / 2.0), Percent(65.0)),
+            ] {
+                tick_verts.push(BinVertex {
+                    x,
+                    y,
+                    color: self.theme.get().colors.border2,
+                    ..Default::default()
+                });
+            }
+        }
+
+        let tick_marks_style = BinStyle {
+            z_index: ZIndex::Offset(1),
+            pos_from_t: Pixels(0.0),
+            pos_from_b: Pixels(0.0),
+            pos_from_l: Pixels(0.0),
+            pos_from_r: Pixels(0.0),
+            user_vertexes: vec![(ImageKey::INVALID, tick_verts)],
+            ..Default::default()
+        };
+
+        let mut styles = vec![
+            (&self.container, container_style),
+            (&self.fill, fill_style),
+            (&self.tick_marks, tick_marks_style),
+        ];
+
+        let labeled_ticks = self
+            .props
+            .ticks
+            .iter()
+            .filter(|(_, label)| !label.is_empty());
+
+        for (tick_label, (fraction, label)) in self.tick_labels.iter().zip(labeled_ticks) {
+            styles.push((
+                tick_label,
+                BinStyle {
+                    position: Position::Anchor,
+                    pos_from_t: PctOffset(100.0, self.theme.get().spacing / 2.0),
+                    pos_from_l: PctOffset(fraction * 100.0, -self.theme.get().base_size),
+                    width: Pixels(self.theme.get().base_size * 2.0),
+                    height: Pixels(self.theme.get().text_height),
+                    text_body: TextBody {
+                        spans: vec![label.clone().into()],
+                        hori_align: TextHoriAlign::Center,
+                        vert_align: TextVertAlign::Center,
+                        text_wrap: TextWrap::None,
+                        base_attrs: TextAttrs {
+                            height: Pixels(self.theme.get().text_height),
+                            color: self.theme.get().colors.text1a,
+                            font_family: self.theme.get().font_family.clone(),
+                            font_weight: self.theme.get().label_font_weight(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ));
+        }
+
+        Bin::style_update_batch(styles);
+    }
+}
+
+impl Widget for ProgressBar {
+    fn focus(&self) {}
+
+    fn blur(&self) {}
+
+    fn is_visible(&self) -> bool {
+        ProgressBar::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        ProgressBar::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        ProgressBar::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        if let Some(progress_bar) = self.this.upgrade() {
+            ProgressBar::set_theme(&progress_bar, theme);
+        }
+    }
+}
+
+const STRIPE_SPEED: f32 = 0.03;
+
+fn clip_x(points: &[[f32; 2]], min: f32, max: f32) -> Vec<[f32; 2]> {
+    let mut output = points.to_vec();
+
+    for (bound, keep_greater) in [(min, true), (max, false)] {
+        let input = std::mem::take(&mut output);
+
+        for i in 0..input.len() {
+            let a = input[i];
+            let b = input[(i + 1) % input.len()];
+
+            let [a_inside, b_inside] = if keep_greater {
+                [a[0] >= bound, b[0] >= bound]
+            } else {
+                [a[0] <= bound, b[0] <= bound]
+            };
+
+            if a_inside {
+                output.push(a);
+            }
+
+            if a_inside != b_inside {
+                let t = (bound - a[0]) / (b[0] - a[0]);
+                output.push([bound, a[1] + ((b[1] - a[1]) * t)]);
+            }
+        }
     }
+
+    output
 }
@@ -9,7 +9,8 @@ use basalt::interface::{Bin, BinStyle, Position, Visibility};
 use parking_lot::ReentrantMutex;
 
 use crate::builder::WidgetBuilder;
-use crate::{Theme, WidgetContainer, WidgetPlacement};
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
 
 static GROUP_ID: AtomicU64 = AtomicU64::new(0);
 
@@ -54,10 +55,7 @@ where
         Self {
             props: Properties::new(
                 value,
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| RadioButton::<()>::default_placement(&builder.theme)),
+                builder.take_placement(RadioButton::<()>::default_placement),
             ),
             widget: builder,
             selected: false,
@@ -85,8 +83,12 @@ where
 
     /// Add a callback to be called when the [`RadioButton`]'s selection changed.
     ///
-    /// **Note**: When changing the state within the callback, no callbacks add to this
+    /// **Notes**:
+    /// - When changing the state within the callback, no callbacks add to this
     /// [`RadioButton`] will be called with the updated state.
+    /// - When in a [`RadioButtonGroup`] and another [`RadioButton`] is selected, the previously
+    /// selected [`RadioButton`]'s callbacks are called with `false` first, then the newly selected
+    /// [`RadioButton`]'s callbacks with `true` and lastly the group's callbacks.
     ///
     /// **Panics**: When adding a callback within the callback to this [`RadioButton`].
     pub fn on_change<F>(mut self, on_change: F) -> Self
@@ -118,7 +120,10 @@ where
         container.add_child(fill.clone());
 
         let radio_button = Arc::new(RadioButton {
-            theme: self.widget.theme,
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::RadioButton),
             props: self.props,
             container,
             fill,
@@ -155,13 +160,17 @@ where
             });
 
         radio_button.style_update();
+        self.widget.container.widget_built(radio_button.clone());
         radio_button
     }
 }
 
 /// Radio button widget
 pub struct RadioButton<T> {
-    theme: Theme,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties<T>,
     container: Arc<Bin>,
     fill: Arc<Bin>,
@@ -223,8 +232,12 @@ impl<T> RadioButton<T> {
 
     /// Add a callback to be called when the [`RadioButton`]'s selection changed.
     ///
-    /// **Note**: When changing the state within the callback, no callbacks add to this
+    /// **Notes**:
+    /// - When changing the state within the callback, no callbacks add to this
     /// [`RadioButton`] will be called with the updated state.
+    /// - When in a [`RadioButtonGroup`] and another [`RadioButton`] is selected, the previously
+    /// selected [`RadioButton`]'s callbacks are called with `false` first, then the newly selected
+    /// [`RadioButton`]'s callbacks with `true` and lastly the group's callbacks.
     ///
     /// **Panics**: When adding a callback within the callback to this [`RadioButton`].
     pub fn on_change<F>(&self, on_change: F)
@@ -263,6 +276,71 @@ impl<T> RadioButton<T> {
         }
     }
 
+    /// Give keyboard focus to this [`RadioButton`].
+    pub fn focus(&self) {
+        crate::focus_bin(&self.container);
+    }
+
+    /// Remove keyboard focus from this [`RadioButton`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`RadioButton`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.container);
+    }
+
+    /// Set if this [`RadioButton`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`RadioButton`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`RadioButton`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`RadioButton`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::RadioButton`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
     pub fn default_placement(theme: &Theme) -> WidgetPlacement {
         WidgetPlacement {
@@ -279,11 +357,12 @@ impl<T> RadioButton<T> {
 
     fn style_update(&self) {
         let mut container_style = BinStyle {
-            back_color: self.theme.colors.back2,
+            back_color: self.theme.get().colors.back2,
             border_radius_tl: PctOfWidth(50.0),
             border_radius_tr: PctOfWidth(50.0),
             border_radius_bl: PctOfWidth(50.0),
             border_radius_br: PctOfWidth(50.0),
+            visibility: self.container.style_inspect(|style| style.visibility),
             ..self.props.placement.clone().into_style()
         };
 
@@ -297,19 +376,19 @@ impl<T> RadioButton<T> {
             border_radius_tr: PctOfWidth(50.0),
             border_radius_bl: PctOfWidth(50.0),
             border_radius_br: PctOfWidth(50.0),
-            back_color: self.theme.colors.accent1,
+            back_color: self.theme.get().colors.accent1,
             ..Default::default()
         };
 
-        if let Some(border_size) = self.theme.border {
+        if let Some(border_size) = self.theme.get().border {
             container_style.border_size_t = Pixels(border_size);
             container_style.border_size_b = Pixels(border_size);
             container_style.border_size_l = Pixels(border_size);
             container_style.border_size_r = Pixels(border_size);
-            container_style.border_color_t = self.theme.colors.border1;
-            container_style.border_color_b = self.theme.colors.border1;
-            container_style.border_color_l = self.theme.colors.border1;
-            container_style.border_color_r = self.theme.colors.border1;
+            container_style.border_color_t = self.theme.get().colors.border1;
+            container_style.border_color_b = self.theme.get().colors.border1;
+            container_style.border_color_l = self.theme.get().colors.border1;
+            container_style.border_color_r = self.theme.get().colors.border1;
         }
 
         if self.is_selected() {
@@ -422,6 +501,9 @@ impl<T> RadioButtonGroup<T> {
     }
 
     /// Remove a [`RadioButton`] from this group.
+    ///
+    /// **Note**: If the [`RadioButton`] is selected, it will be unselected and the selection of
+    /// this group will be cleared.
     pub fn remove(&self, radio_button: &Arc<RadioButton<T>>) -> Result<(), RadioButtonError> {
         let state = self.state.lock();
         let b_state = radio_button.state.lock();
@@ -450,6 +532,7 @@ impl<T> RadioButtonGroup<T> {
         *b_state.id.borrow_mut() = None;
 
         if selection_changed {
+            radio_button.set_selected(false);
             state.call_on_change(None);
         }
 
@@ -502,9 +585,12 @@ impl<T> RadioButtonGroup<T> {
 
     /// Add a callback to be called when a [`RadioButton`] is selected.
     ///
-    /// **Note**: When changing the state within the callback, no callbacks add to this
+    /// **Notes**:
+    /// - When changing the state within the callback, no callbacks add to this
     /// [`RadioButtonGroup`] will be called with the updated state. Callbacks added specify to
     /// [`RadioButton`] will still be called.
+    /// - This is called after the callbacks of the [`RadioButton`]'s whose selection changed.
+    /// - When the selection is cleared, this is called with `None`.
     ///
     /// **Panics**: When adding a callback within the callback to this [`RadioButtonGroup`].
     pub fn on_change<F>(&self, on_change: F)
@@ -562,3 +648,32 @@ where
         false
     }
 }
+
+impl<T> Widget for RadioButton<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn focus(&self) {
+        RadioButton::focus(self)
+    }
+
+    fn blur(&self) {
+        RadioButton::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        RadioButton::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        RadioButton::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        RadioButton::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        RadioButton::set_theme(self, theme)
+    }
+}
@@ -0,0 +1,263 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use basalt::input::{InputHookCtrl, MouseButton};
+use basalt::interface::UnitValue::Pixels;
+use basalt::interface::{Bin, BinID, BinStyle, Position, ZIndex};
+use parking_lot::ReentrantMutex;
+
+/// Distance in pixels the cursor must move before a press is considered a drag.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// Drag to reorder helper
+///
+/// The direct children of the provided [`Bin`] can be dragged vertically by the user. While being
+/// dragged, a child is raised above its siblings and follows the cursor. Once released, the
+/// callbacks added with [`Reorderable::on_reorder`] are called with the index the child was
+/// dragged from and the index it was dropped at.
+///
+/// **Notes**:
+/// - The children themselves aren't reordered. It is up to the callback to rebuild or reposition
+/// them in their new order.
+/// - Indexes are in the order of [`Bin::children`]. The drop index is determined by comparing the
+/// cursor with the vertical center of the other children.
+/// - Children added after creation are hooked as well.
+pub struct Reorderable {
+    container: Arc<Bin>,
+    state: ReentrantMutex<State>,
+}
+
+struct State {
+    hooked: RefCell<Vec<BinID>>,
+    drag: RefCell<Option<DragState>>,
+    on_reorder: RefCell<Vec<Box<dyn FnMut(&Arc<Reorderable>, usize, usize) + Send + 'static>>>,
+}
+
+struct DragState {
+    bin: Arc<Bin>,
+    index: usize,
+    cursor_start: [f32; 2],
+    origin: [f32; 2],
+    lifted_from: Option<BinStyle>,
+}
+
+impl Reorderable {
+    /// Create a new [`Reorderable`] for the children of the provided [`Bin`].
+    ///
+    /// **Panics**: When the [`Bin`] doesn't have an associated window.
+    pub fn new(container: Arc<Bin>) -> Arc<Self> {
+        let window = container
+            .window()
+            .expect("The container must have an associated window.");
+
+        let reorderable = Arc::new(Self {
+            container,
+            state: ReentrantMutex::new(State {
+                hooked: RefCell::new(Vec::new()),
+                drag: RefCell::new(None),
+                on_reorder: RefCell::new(Vec::new()),
+            }),
+        });
+
+        let reorderable_wk = Arc::downgrade(&reorderable);
+
+        reorderable.container.on_children_added(move |_, _| {
+            if let Some(reorderable) = reorderable_wk.upgrade() {
+                reorderable.hook_children();
+            }
+        });
+
+        let reorderable_wk = Arc::downgrade(&reorderable);
+
+        reorderable
+            .container
+            .attach_input_hook(window.on_cursor(move |_, w_state, _| {
+                let reorderable = match reorderable_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                reorderable.drag_to(w_state.cursor_pos());
+                Default::default()
+            }));
+
+        reorderable.hook_children();
+        reorderable
+    }
+
+    /// Add a callback to be called when a child is dropped at a different index.
+    ///
+    /// The callback is called with the index the child was dragged from and the index it should
+    /// be moved to.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_reorder<F>(&self, on_reorder: F)
+    where
+        F: FnMut(&Arc<Reorderable>, usize, usize) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_reorder
+            .borrow_mut()
+            .push(Box::new(on_reorder));
+    }
+
+    /// Check if a child is currently being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.state
+            .lock()
+            .drag
+            .borrow()
+            .as_ref()
+            .is_some_and(|drag| drag.lifted_from.is_some())
+    }
+
+    /// Obtain the [`Bin`] whose children are reorderable.
+    pub fn container(&self) -> &Arc<Bin> {
+        &self.container
+    }
+
+    fn hook_children(self: &Arc<Self>) {
+        let state = self.state.lock();
+        let mut hooked = state.hooked.borrow_mut();
+        let children = self.container.children();
+        hooked.retain(|bin_id| children.iter().any(|c| c.id() == *bin_id));
+
+        for child in children {
+            if hooked.contains(&child.id()) {
+                continue;
+            }
+
+            hooked.push(child.id());
+            let reorderable_wk = Arc::downgrade(self);
+            let child_wk = Arc::downgrade(&child);
+
+            child.on_press(MouseButton::Left, move |_, w_state, _| {
+                let (reorderable, child) = match (reorderable_wk.upgrade(), child_wk.upgrade()) {
+                    (Some(reorderable), Some(child)) => (reorderable, child),
+                    _ => return InputHookCtrl::Remove,
+                };
+
+                reorderable.start_drag(child, w_state.cursor_pos());
+                Default::default()
+            });
+
+            let reorderable_wk = Arc::downgrade(self);
+
+            child.on_release(MouseButton::Left, move |_, w_state, _| {
+                let reorderable = match reorderable_wk.upgrade() {
+                    Some(some) => some,
+                    None => return InputHookCtrl::Remove,
+                };
+
+                reorderable.end_drag(w_state.cursor_pos());
+                Default::default()
+            });
+        }
+    }
+
+    fn start_drag(&self, bin: Arc<Bin>, cursor: [f32; 2]) {
+        let state = self.state.lock();
+        let mut drag = state.drag.borrow_mut();
+
+        if drag.is_some() {
+            return;
+        }
+
+        let index = match self
+            .container
+            .children()
+            .iter()
+            .position(|child| child.id() == bin.id())
+        {
+            Some(some) => some,
+            None => return,
+        };
+
+        let bin_bpu = bin.post_update();
+        let container_bpu = self.container.post_update();
+
+        *drag = Some(DragState {
+            index,
+            cursor_start: cursor,
+            origin: [
+                bin_bpu.tli[0] - container_bpu.tli[0],
+                bin_bpu.tli[1] - container_bpu.tli[1],
+            ],
+            lifted_from: None,
+            bin,
+        });
+    }
+
+    fn drag_to(&self, [cursor_x, cursor_y]: [f32; 2]) {
+        let state = self.state.lock();
+        let mut drag_op = state.drag.borrow_mut();
+
+        let drag = match drag_op.as_mut() {
+            Some(some) => some,
+            None => return,
+        };
+
+        let delta_x = cursor_x - drag.cursor_start[0];
+        let delta_y = cursor_y - drag.cursor_start[1];
+
+        if drag.lifted_from.is_none() {
+            if delta_x.abs() < DRAG_THRESHOLD && delta_y.abs() < DRAG_THRESHOLD {
+                return;
+            }
+
+            drag.lifted_from = Some(drag.bin.style_copy());
+        }
+
+        let pos_from_l = drag.origin[0] + delta_x;
+        let pos_from_t = drag.origin[1] + delta_y;
+
+        drag.bin.style_modify(|style| {
+            style.position = Position::Relative;
+            style.z_index = ZIndex::Offset(100);
+            style.pos_from_l = Pixels(pos_from_l);
+            style.pos_from_t = Pixels(pos_from_t);
+            style.pos_from_r = Default::default();
+            style.pos_from_b = Default::default();
+            style.margin_t = Default::default();
+            style.margin_b = Default::default();
+            style.margin_l = Default::default();
+            style.margin_r = Default::default();
+        });
+    }
+
+    fn end_drag(self: &Arc<Self>, [_, cursor_y]: [f32; 2]) {
+        let state = self.state.lock();
+
+        let drag = match state.drag.borrow_mut().take() {
+            Some(some) => some,
+            None => return,
+        };
+
+        let original_style = match drag.lifted_from {
+            Some(some) => some,
+            None => return,
+        };
+
+        drag.bin.style_update(original_style).expect_valid();
+
+        let to = self
+            .container
+            .children()
+            .iter()
+            .filter(|child| child.id() != drag.bin.id())
+            .filter(|child| {
+                let bpu = child.post_update();
+                (bpu.tli[1] + bpu.bli[1]) / 2.0 < cursor_y
+            })
+            .count();
+
+        if to != drag.index
+            && let Ok(mut on_reorder_cbs) = state.on_reorder.try_borrow_mut()
+        {
+            for on_reorder in on_reorder_cbs.iter_mut() {
+                on_reorder(self, drag.index, to);
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+/// The role of a widget.
+///
+/// This describes what a widget is to assistive technologies and can also be used to locate
+/// widgets by their purpose, such as within tests.
+///
+/// **Note**: Each widget has a default role which can be changed with
+/// [`WidgetBuilder::with_role`](crate::builder::WidgetBuilder::with_role).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WidgetRole {
+    /// A button that performs an action when pressed.
+    ///
+    /// **Note**: This is the default of [`Button`](crate::Button) and
+    /// [`ColorSwatch`](crate::ColorSwatch).
+    Button,
+    /// A button that is either pressed or not pressed.
+    ///
+    /// **Note**: This is the default of [`ToggleButton`](crate::ToggleButton).
+    ToggleButton,
+    /// A control that is either on or off.
+    ///
+    /// **Note**: This is the default of [`SwitchButton`](crate::SwitchButton).
+    Switch,
+    /// A control that is either checked or unchecked.
+    ///
+    /// **Note**: This is the default of [`CheckBox`](crate::CheckBox).
+    CheckBox,
+    /// A control that is one of a group where only one can be selected.
+    ///
+    /// **Note**: This is the default of [`RadioButton`](crate::RadioButton).
+    RadioButton,
+    /// A control that selects a value within a range.
+    ///
+    /// **Note**: This is the default of [`Scaler`](crate::Scaler).
+    Slider,
+    /// A control that scrolls content.
+    ///
+    /// **Note**: This is the default of [`ScrollBar`](crate::ScrollBar).
+    ScrollBar,
+    /// A control that selects a value within a range by stepping.
+    ///
+    /// **Note**: This is the default of [`SpinButton`](crate::SpinButton) and
+    /// [`NumberField`](crate::NumberField).
+    SpinButton,
+    /// A control where text can be entered.
+    ///
+    /// **Note**: This is the default of [`TextEntry`](crate::TextEntry),
+    /// [`TextEditor`](crate::TextEditor) and [`CodeEditor`](crate::CodeEditor).
+    TextBox,
+    /// A control where a value can either be selected from a list or entered.
+    ///
+    /// **Note**: This is the default of [`ComboBox`](crate::ComboBox).
+    ComboBox,
+    /// A control where a value is selected from a list.
+    ///
+    /// **Note**: This is the default of [`Select`](crate::Select).
+    ListBox,
+    /// An indicator of the progress of a task.
+    ///
+    /// **Note**: This is the default of [`ProgressBar`](crate::ProgressBar) and
+    /// [`Spinner`](crate::Spinner).
+    ProgressBar,
+    /// Static text that isn't interactive.
+    ///
+    /// **Note**: This is the default of [`Label`](crate::Label).
+    Label,
+    /// A divider between content.
+    ///
+    /// **Note**: This is the default of [`Separator`](crate::Separator).
+    Separator,
+    /// A container that arranges content in rows and columns.
+    ///
+    /// **Note**: This is the default of [`Grid`](crate::Grid).
+    Grid,
+    /// A region of content that can be collapsed.
+    ///
+    /// **Note**: This is the default of [`Section`](crate::Section).
+    Section,
+}
@@ -1,16 +1,23 @@
 use std::cell::RefCell;
-use std::sync::Arc;
 use std::sync::atomic::{self, AtomicBool};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
 
+use basalt::image::ImageKey;
 use basalt::input::{MouseButton, Qwerty, WindowState};
 use basalt::interface::UnitValue::{
-    PctOfHeight, PctOfHeightOffset, PctOfWidth, PctOfWidthOffset, Percent, Pixels,
+    PctOfHeight, PctOfHeightOffset, PctOfWidth, PctOfWidthOffset, PctOffset, Percent, Pixels,
 };
-use basalt::interface::{Bin, BinStyle, Position};
-use parking_lot::ReentrantMutex;
+use basalt::interface::{
+    Bin, BinStyle, BinVertex, Position, TextAttrs, TextBody, TextHoriAlign, TextVertAlign,
+    TextWrap, Visibility, ZIndex,
+};
+use parking_lot::{Mutex, ReentrantMutex};
 
 use crate::builder::WidgetBuilder;
-use crate::{Theme, WidgetContainer, WidgetPlacement};
+use crate::error::BuildError;
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole, ulps_eq};
 
 /// Builder for [`Scaler`]
 pub struct ScalerBuilder<'a, C> {
@@ -20,7 +27,7 @@ pub struct ScalerBuilder<'a, C> {
     on_change: Vec<Box<dyn FnMut(&Arc<Scaler>, f32) + Send + 'static>>,
 }
 
-/// An error than can occur from [`ScalerBuilder::build`].
+/// An error than can occur from [`ScalerBuilder::build`] or [`Scaler::set_range`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ScalerError {
     /// Value provided by [`ScalerBuilder::max_value`] is greater than the value provided by
@@ -29,6 +36,14 @@ pub enum ScalerError {
     /// Value provided by [`ScalerBuilder::set_value`] is not in range specified by
     /// [`ScalerBuilder::min_value`] and [`ScalerBuilder::max_value`].
     SetValNotInRange,
+    /// The widget failed to build.
+    Build(BuildError),
+}
+
+impl From<BuildError> for ScalerError {
+    fn from(error: BuildError) -> Self {
+        Self::Build(error)
+    }
 }
 
 /// Determines how the value of [`Scaler`] is rounded when it is modified.
@@ -58,6 +73,20 @@ pub enum ScalerOrientation {
     Vertical,
 }
 
+/// The shape of the [`Scaler`]'s knob.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalerKnobShape {
+    /// A circular knob.
+    ///
+    /// This is the the default.
+    #[default]
+    Circle,
+    /// A rectangular handle, half as long along the track as it is across.
+    ///
+    /// **Note**: This uses the roundness of the [`Theme`].
+    Rect,
+}
+
 struct Properties {
     min: f32,
     max: f32,
@@ -65,8 +94,15 @@ struct Properties {
     small_step: f32,
     medium_step: f32,
     large_step: f32,
+    fine_step: Option<f32>,
+    scroll_step: Option<f32>,
     round: ScalerRound,
     orientation: ScalerOrientation,
+    knob_size: Option<f32>,
+    knob_shape: ScalerKnobShape,
+    change_throttle: Option<Duration>,
+    value_label: Option<fn(f32) -> String>,
+    stops: Vec<(f32, String)>,
     placement: WidgetPlacement,
 }
 
@@ -79,8 +115,15 @@ impl Properties {
             small_step: 1.0,
             medium_step: 1.0,
             large_step: 1.0,
+            fine_step: None,
+            scroll_step: None,
             round: Default::default(),
             orientation: Default::default(),
+            knob_size: None,
+            knob_shape: Default::default(),
+            change_throttle: None,
+            value_label: None,
+            stops: Vec::new(),
             placement,
         }
     }
@@ -95,9 +138,8 @@ where
         Self {
             plmt_is_default: builder.placement.is_none(),
             props: Properties::new(
-                builder.placement.take().unwrap_or_else(|| {
-                    Scaler::default_placement(&builder.theme, Default::default())
-                }),
+                builder
+                    .take_placement(|theme| Scaler::default_placement(theme, Default::default())),
             ),
             widget: builder,
             on_change: Vec::new(),
@@ -141,7 +183,7 @@ where
     /// Set the value of a medium step.
     ///
     /// **Notes**:
-    /// - This when either [`Qwerty::LCtrl`](basalt::input::Qwerty::LCtrl) or
+    /// - This is used when either [`Qwerty::LCtrl`](basalt::input::Qwerty::LCtrl) or
     /// [`Qwerty::RCtrl`](basalt::input::Qwerty::RCtrl) is used.
     /// - Dragging the knob with the mouse will not be effected by this value.
     /// - When this isn't used the medium step will be `1.0`.
@@ -153,7 +195,7 @@ where
     /// Set the value of a large step.
     ///
     /// **Notes**:
-    /// - This when either [`Qwerty::LShift`](basalt::input::Qwerty::LShift) or
+    /// - This is used when either [`Qwerty::LShift`](basalt::input::Qwerty::LShift) or
     /// [`Qwerty::RShift`](basalt::input::Qwerty::RShift) is used.
     /// - Dragging the knob with the mouse will not be effected by this value.
     /// - When this isn't used the large step will be `1.0`.
@@ -162,6 +204,31 @@ where
         self
     }
 
+    /// Set the value of a fine step.
+    ///
+    /// **Notes**:
+    /// - This is used when either [`Qwerty::LAlt`](basalt::input::Qwerty::LAlt) or
+    /// [`Qwerty::RAlt`](basalt::input::Qwerty::RAlt) is used.
+    /// - Dragging the knob with the mouse will not be effected by this value.
+    /// - Rounding still applies, so with [`ScalerRound::Step`] this has no effect if it is less
+    /// than half of the small step.
+    /// - When this isn't used the fine step will be a tenth of the small step, or the small step
+    /// with [`ScalerRound::Step`].
+    pub fn fine_step(mut self, step: f32) -> Self {
+        self.props.fine_step = Some(step);
+        self
+    }
+
+    /// Set the value of a step when scrolling.
+    ///
+    /// **Notes**:
+    /// - This is when no modifier keys are used, otherwise the fine, medium or large step is used.
+    /// - When this isn't used the small step will be used.
+    pub fn scroll_step(mut self, step: f32) -> Self {
+        self.props.scroll_step = Some(step);
+        self
+    }
+
     /// Set how the value is rounded after being modified.
     ///
     /// See documentation of [`ScalerRound`] for more information.
@@ -176,13 +243,81 @@ where
     /// [`Horizontal`](ScalerOrientation::Horizontal).
     pub fn orientation(mut self, orientation: ScalerOrientation) -> Self {
         if self.plmt_is_default {
-            self.props.placement = Scaler::default_placement(&self.widget.theme, orientation);
+            self.props.placement = self
+                .widget
+                .apply_placement(Scaler::default_placement(&self.widget.theme, orientation));
         }
 
         self.props.orientation = orientation;
         self
     }
 
+    /// Set the size of the knob across the track in pixels.
+    ///
+    /// **Note**: When this isn't used the knob fills the [`Scaler`] across the track.
+    pub fn knob_size(mut self, size: f32) -> Self {
+        self.props.knob_size = Some(size.max(0.0));
+        self
+    }
+
+    /// Set the shape of the knob.
+    ///
+    /// **Note**: When this isn't used the [`ScalerKnobShape`] will be
+    /// [`Circle`](ScalerKnobShape::Circle).
+    pub fn knob_shape(mut self, shape: ScalerKnobShape) -> Self {
+        self.props.knob_shape = shape;
+        self
+    }
+
+    /// Limit how often callbacks added with [`on_change`](Self::on_change) are called while the
+    /// knob is being dragged.
+    ///
+    /// The value itself is updated immediately, only the calling of callbacks is limited to at
+    /// most once per `interval`. When the knob is released, callbacks are called with the final
+    /// value if it hasn't been provided yet.
+    ///
+    /// **Notes**:
+    /// - This only applies to changes made by dragging the knob.
+    /// - When this isn't used callbacks are called on every change.
+    pub fn change_throttle(mut self, interval: Duration) -> Self {
+        self.props.change_throttle = Some(interval);
+        self
+    }
+
+    /// Display the value in a label next to the track, formatted with `format`.
+    ///
+    /// The label is placed after the track when [`Horizontal`](ScalerOrientation::Horizontal) and
+    /// below it when [`Vertical`](ScalerOrientation::Vertical). The space for the label is taken
+    /// from the [`Scaler`], so the track is shortened to make room for it.
+    ///
+    /// **Notes**:
+    /// - The label is sized to fit the longer of the formatted minimum and maximum values, so
+    /// the track doesn't resize as the value changes. These are measured once laid out, so the
+    /// label may be resized shortly after the [`Scaler`] is built.
+    /// - When this isn't used there is no label.
+    pub fn with_value_label(mut self, format: fn(f32) -> String) -> Self {
+        self.props.value_label = Some(format);
+        self
+    }
+
+    /// Limit the value to a set of stops, each with a label.
+    ///
+    /// The value snaps to the nearest stop when dragged and steps between adjacent stops when
+    /// scrolled or changed with the keyboard. A tick and its label are displayed at each stop.
+    ///
+    /// **Notes**:
+    /// - An empty label will draw only the tick.
+    /// - Labels are displayed below the track when [`Horizontal`](ScalerOrientation::Horizontal)
+    /// and to the right of it when [`Vertical`](ScalerOrientation::Vertical). They may overlap
+    /// neighboring widgets.
+    /// - Rounding provided by [`round`](Self::round) is not used when there are stops.
+    /// - When this isn't used the value is continuous.
+    pub fn stops(mut self, mut stops: Vec<(f32, String)>) -> Self {
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.props.stops = stops;
+        self
+    }
+
     /// Add a callback to be called when the [`Scaler`]'s value changed.
     ///
     /// **Note**: When changing the value within the callback, no callbacks will be called with
@@ -212,13 +347,34 @@ where
             .container
             .container_bin()
             .window()
-            .expect("The widget container must have an associated window.");
+            .ok_or(BuildError::NoWindow)?;
 
-        let mut new_bins = window.new_bins(4).into_iter();
+        let num_bins = if self.props.value_label.is_some() {
+            6
+        } else {
+            5
+        };
+        let num_bins = num_bins + self.props.stops.len();
+        let new_bins = window.new_bins(num_bins);
+
+        if new_bins.len() != num_bins {
+            return Err(BuildError::BinAllocationFailed.into());
+        }
+
+        let mut new_bins = new_bins.into_iter();
         let container = new_bins.next().unwrap();
         let track = new_bins.next().unwrap();
         let confine = new_bins.next().unwrap();
         let knob = new_bins.next().unwrap();
+        let ghost = new_bins.next().unwrap();
+
+        let value_label = if self.props.value_label.is_some() {
+            new_bins.next()
+        } else {
+            None
+        };
+
+        let stops = new_bins.collect::<Vec<_>>();
 
         self.widget
             .container
@@ -227,27 +383,62 @@ where
 
         container.add_child(track.clone());
         container.add_child(confine.clone());
+        confine.add_child(ghost.clone());
         confine.add_child(knob.clone());
 
-        let initial_val = self.props.val;
+        if let Some(value_label) = value_label.as_ref() {
+            container.add_child(value_label.clone());
+        }
 
-        let scaler = Arc::new(Scaler {
-            theme: self.widget.theme,
-            props: self.props,
-            container,
-            track,
-            confine,
-            knob,
-            state: ReentrantMutex::new(State {
-                val: RefCell::new(initial_val),
-                on_change: RefCell::new(self.on_change),
-            }),
+        for stop in stops.iter() {
+            confine.add_child(stop.clone());
+        }
+
+        let initial_val = self.props.val;
+        let initial_range = [self.props.min, self.props.max];
+
+        let scaler = Arc::new_cyclic(|this| {
+            Scaler {
+                this: this.clone(),
+                theme: ThemeCell::new(self.widget.theme),
+                name: self.widget.name,
+                aria_label: self.widget.aria_label,
+                role: self.widget.role.unwrap_or(WidgetRole::Slider),
+                props: self.props,
+                container,
+                track,
+                confine,
+                knob,
+                ghost,
+                value_label,
+                stops,
+                state: ReentrantMutex::new(State {
+                    val: RefCell::new(initial_val),
+                    ghost: RefCell::new(None),
+                    value_label_width: RefCell::new(None),
+                    stop_label_width: RefCell::new(None),
+                    range: RefCell::new(initial_range),
+                    throttle: RefCell::new(ThrottleState {
+                        last_call: None,
+                        pending: false,
+                    }),
+                    on_change: RefCell::new(self.on_change),
+                }),
+            }
         });
 
+        {
+            // Align the initial value to the rounding so the first interaction doesn't jump.
+            let state = scaler.state.lock();
+            let rounded_val = scaler.round_val(initial_val);
+            *state.val.borrow_mut() = rounded_val;
+        }
+
         let cb_scaler = scaler.clone();
 
         scaler.container.on_scroll(move |_, w_state, amt, _| {
-            let step = cb_scaler.step_size(w_state) * -amt;
+            // High resolution devices may provide large amounts in a single event.
+            let step = cb_scaler.scroll_step_size(w_state) * -amt.clamp(-1.0, 1.0);
             cb_scaler.increment(step);
             Default::default()
         });
@@ -262,40 +453,42 @@ where
             Default::default()
         });
 
+        let cb_scaler = scaler.clone();
         let cb_knob_held = knob_held.clone();
 
         scaler.knob.on_release(MouseButton::Left, move |_, _, _| {
             cb_knob_held.store(false, atomic::Ordering::SeqCst);
+            cb_scaler.end_throttle();
             Default::default()
         });
 
+        // Pressing the track jumps the knob to the cursor and continues as a drag while held.
+        for bin in [&scaler.track, &scaler.confine, &scaler.ghost] {
+            let cb_scaler = scaler.clone();
+            let cb_knob_held = knob_held.clone();
+
+            bin.on_press(MouseButton::Left, move |_, w_state, _| {
+                cb_knob_held.store(true, atomic::Ordering::SeqCst);
+                cb_scaler.set_from_cursor(w_state.cursor_pos());
+                Default::default()
+            });
+
+            let cb_scaler = scaler.clone();
+            let cb_knob_held = knob_held.clone();
+
+            bin.on_release(MouseButton::Left, move |_, _, _| {
+                cb_knob_held.store(false, atomic::Ordering::SeqCst);
+                cb_scaler.end_throttle();
+                Default::default()
+            });
+        }
+
         let cb_scaler = scaler.clone();
         let cb_knob_held = knob_held.clone();
 
         window_hook_ids.push(window.on_cursor(move |_, w_state, _| {
             if cb_knob_held.load(atomic::Ordering::SeqCst) {
-                let [cursor_x, cursor_y] = w_state.cursor_pos();
-                let track_bpu = cb_scaler.track.post_update();
-                let knob_bpu = cb_scaler.knob.post_update();
-
-                match cb_scaler.props.orientation {
-                    ScalerOrientation::Horizontal => {
-                        let knob_width_1_2 = (knob_bpu.tri[0] - knob_bpu.tli[0]) / 2.0;
-                        let cursor_x_min = track_bpu.tli[0] + knob_width_1_2;
-                        let cursor_x_max = track_bpu.tri[0] - knob_width_1_2;
-                        let pct =
-                            ((cursor_x - cursor_x_min) / (cursor_x_max - cursor_x_min)) * 100.0;
-                        cb_scaler.set_pct(pct.clamp(0.0, 100.0));
-                    },
-                    ScalerOrientation::Vertical => {
-                        let knob_height_1_2 = (knob_bpu.bli[0] - knob_bpu.tli[0]) / 2.0;
-                        let cursor_y_min = track_bpu.tli[1] + knob_height_1_2;
-                        let cursor_y_max = track_bpu.bli[1] - knob_height_1_2;
-                        let pct = 100.0
-                            - (((cursor_y - cursor_y_min) / (cursor_y_max - cursor_y_min)) * 100.0);
-                        cb_scaler.set_pct(pct.clamp(0.0, 100.0));
-                    },
-                }
+                cb_scaler.set_from_cursor(w_state.cursor_pos());
             }
 
             Default::default()
@@ -308,6 +501,7 @@ where
             scaler.track.id(),
             scaler.confine.id(),
             scaler.knob.id(),
+            scaler.ghost.id(),
         ];
 
         for bin in [
@@ -315,6 +509,7 @@ where
             &scaler.track,
             &scaler.confine,
             &scaler.knob,
+            &scaler.ghost,
         ] {
             let cb_focused = focused.clone();
 
@@ -389,29 +584,53 @@ where
         }
 
         scaler.style_update();
+        scaler.measure_value_label();
+        scaler.measure_stop_labels();
+        self.widget.container.widget_built(scaler.clone());
         Ok(scaler)
     }
 }
 
 /// Scaler widget
 pub struct Scaler {
-    theme: Theme,
+    this: Weak<Scaler>,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     container: Arc<Bin>,
     track: Arc<Bin>,
     confine: Arc<Bin>,
     knob: Arc<Bin>,
+    ghost: Arc<Bin>,
+    value_label: Option<Arc<Bin>>,
+    stops: Vec<Arc<Bin>>,
     state: ReentrantMutex<State>,
 }
 
 struct State {
     val: RefCell<f32>,
+    ghost: RefCell<Option<f32>>,
+    value_label_width: RefCell<Option<f32>>,
+    stop_label_width: RefCell<Option<f32>>,
+    range: RefCell<[f32; 2]>,
+    throttle: RefCell<ThrottleState>,
     on_change: RefCell<Vec<Box<dyn FnMut(&Arc<Scaler>, f32) + Send + 'static>>>,
 }
 
+struct ThrottleState {
+    last_call: Option<Instant>,
+    pending: bool,
+}
+
 impl Scaler {
     fn step_size(&self, w_state: &WindowState) -> f32 {
-        if w_state.is_key_pressed(Qwerty::LCtrl) || w_state.is_key_pressed(Qwerty::RCtrl) {
+        if w_state.is_key_pressed(Qwerty::LAlt) || w_state.is_key_pressed(Qwerty::RAlt) {
+            self.props
+                .fine_step
+                .unwrap_or_else(|| default_fine_step(self.props.small_step, self.props.round))
+        } else if w_state.is_key_pressed(Qwerty::LCtrl) || w_state.is_key_pressed(Qwerty::RCtrl) {
             self.props.medium_step
         } else if w_state.is_key_pressed(Qwerty::LShift) || w_state.is_key_pressed(Qwerty::RShift) {
             self.props.large_step
@@ -420,8 +639,64 @@ impl Scaler {
         }
     }
 
+    fn scroll_step_size(&self, w_state: &WindowState) -> f32 {
+        match self.props.scroll_step {
+            Some(scroll_step)
+                if !w_state.is_key_pressed(Qwerty::LCtrl)
+                    && !w_state.is_key_pressed(Qwerty::RCtrl)
+                    && !w_state.is_key_pressed(Qwerty::LShift)
+                    && !w_state.is_key_pressed(Qwerty::RShift)
+                    && !w_state.is_key_pressed(Qwerty::LAlt)
+                    && !w_state.is_key_pressed(Qwerty::RAlt) =>
+            {
+                scroll_step
+            },
+            _ => self.step_size(w_state),
+        }
+    }
+
+    fn range(&self) -> [f32; 2] {
+        *self.state.lock().range.borrow()
+    }
+
     fn set_pct(self: &Arc<Self>, pct: f32) {
-        self.set(((self.props.max - self.props.min) * (pct / 100.0)) + self.props.min);
+        let [min, max] = self.range();
+        let val = self.round_val(val_from_pct(pct, [min, max]));
+
+        // The knob is only moved once the value reaches the next increment.
+        if ulps_eq(val, self.val(), 4) {
+            return;
+        }
+
+        self.set_inner(val, true);
+    }
+
+    fn set_from_cursor(self: &Arc<Self>, [cursor_x, cursor_y]: [f32; 2]) {
+        let track_bpu = self.track.post_update();
+        let knob_bpu = self.knob.post_update();
+
+        self.set_pct(cursor_pct(
+            self.props.orientation,
+            [cursor_x, cursor_y],
+            [track_bpu.tli, track_bpu.tri, track_bpu.bli],
+            [knob_bpu.tli, knob_bpu.tri, knob_bpu.bli],
+        ));
+    }
+
+    fn round_val(&self, val: f32) -> f32 {
+        let [min, max] = self.range();
+
+        if !self.props.stops.is_empty() {
+            return self
+                .props
+                .stops
+                .iter()
+                .map(|(stop, _)| stop.clamp(min, max))
+                .min_by(|a, b| (a - val).abs().total_cmp(&(b - val).abs()))
+                .unwrap();
+        }
+
+        apply_round(val, self.props.round, self.props.small_step, [min, max])
     }
 
     /// Set the value to the provided valued.
@@ -430,15 +705,13 @@ impl Scaler {
     /// - This will be effected by rounding provided by [`ScalerBuilder::round`].
     /// - This value will be clamped to values provided by [`ScalerBuilder::min_value`]
     /// and [`ScalerBuilder::max_value`].
-    pub fn set(self: &Arc<Self>, mut val: f32) {
-        val = match self.props.round {
-            ScalerRound::None => val,
-            ScalerRound::Int => val.round(),
-            ScalerRound::Step => (val / self.props.small_step).round() * self.props.small_step,
-        }
-        .clamp(self.props.min, self.props.max);
+    pub fn set(self: &Arc<Self>, val: f32) {
+        self.set_inner(val, false);
+    }
 
-        let pct = ((val - self.props.min) / (self.props.max - self.props.min)) * 100.0;
+    fn set_inner(self: &Arc<Self>, mut val: f32, dragged: bool) {
+        val = self.round_val(val);
+        let pct = pct_from_val(val, self.range());
         let mut knob_style = self.knob.style_copy();
 
         match self.props.orientation {
@@ -450,10 +723,58 @@ impl Scaler {
             },
         }
 
-        self.knob.style_update(knob_style).expect_valid();
+        match (self.value_label.as_ref(), self.props.value_label) {
+            (Some(value_label), Some(format)) => {
+                let mut value_label_style = value_label.style_copy();
+                value_label_style.text_body.spans[0].text = format(val);
+                Bin::style_update_batch([
+                    (&self.knob, knob_style),
+                    (value_label, value_label_style),
+                ]);
+            },
+            _ => {
+                self.knob.style_update(knob_style).expect_valid();
+            },
+        }
+
         let state = self.state.lock();
         *state.val.borrow_mut() = val;
 
+        if dragged && let Some(interval) = self.props.change_throttle {
+            let mut throttle = state.throttle.borrow_mut();
+
+            if throttle
+                .last_call
+                .is_some_and(|last_call| last_call.elapsed() < interval)
+            {
+                throttle.pending = true;
+                return;
+            }
+
+            throttle.last_call = Some(Instant::now());
+            throttle.pending = false;
+        }
+
+        self.call_on_change(val);
+    }
+
+    fn end_throttle(self: &Arc<Self>) {
+        let state = self.state.lock();
+
+        let pending = {
+            let mut throttle = state.throttle.borrow_mut();
+            throttle.last_call = None;
+            std::mem::take(&mut throttle.pending)
+        };
+
+        if pending {
+            self.call_on_change(*state.val.borrow());
+        }
+    }
+
+    fn call_on_change(self: &Arc<Self>, val: f32) {
+        let state = self.state.lock();
+
         if let Ok(mut on_change_cbs) = state.on_change.try_borrow_mut() {
             for on_change in on_change_cbs.iter_mut() {
                 on_change(self, val);
@@ -466,14 +787,136 @@ impl Scaler {
         *self.state.lock().val.borrow()
     }
 
+    /// Get the current value as a fraction of the range, from `0.0` at the minimum to `1.0` at
+    /// the maximum.
+    ///
+    /// **Note**: If the minimum and maximum are equal, this is `0.0`.
+    pub fn normalized(&self) -> f32 {
+        let [min, max] = self.range();
+
+        if max - min <= 0.0 {
+            return 0.0;
+        }
+
+        ((self.val() - min) / (max - min)).clamp(0.0, 1.0)
+    }
+
+    /// Set the value from a fraction of the range, where `0.0` is the minimum and `1.0` is the
+    /// maximum.
+    ///
+    /// **Notes**:
+    /// - The fraction is clamped to `0.0..=1.0`.
+    /// - This will be effected by rounding provided by [`ScalerBuilder::round`].
+    pub fn set_normalized(self: &Arc<Self>, fraction: f32) {
+        let [min, max] = self.range();
+        self.set(min + ((max - min) * fraction.clamp(0.0, 1.0)));
+    }
+
+    /// Set the minimum and maximum values.
+    ///
+    /// The current value will be clamped to the new range. If this changes the value, the
+    /// callbacks added with [`on_change`](`Scaler::on_change`) will be called.
+    ///
+    /// **Note**: If `max` is less than `min`, the range will not be modified.
+    pub fn set_range(self: &Arc<Self>, min: f32, max: f32) -> Result<(), ScalerError> {
+        if max < min {
+            return Err(ScalerError::MaxLessThanMin);
+        }
+
+        let state = self.state.lock();
+        *state.range.borrow_mut() = [min, max];
+        let val = self.val();
+        let rounded_val = self.round_val(val);
+        self.style_update();
+        self.measure_value_label();
+
+        if !ulps_eq(val, rounded_val, 4) {
+            self.set(rounded_val);
+        }
+
+        Ok(())
+    }
+
+    /// Set the value of the ghost knob.
+    ///
+    /// The ghost knob is a semi-transparent knob displayed alongside the actual knob. This is
+    /// useful for previewing a value, such as the target of an automation, without changing the
+    /// value of the [`Scaler`]. Providing `None` hides the ghost knob.
+    ///
+    /// **Notes**:
+    /// - The ghost value isn't effected by rounding, but is clamped to the range.
+    /// - This doesn't call the [`on_change`](Scaler::on_change) callbacks.
+    pub fn set_ghost(&self, ghost: Option<f32>) {
+        let state = self.state.lock();
+        *state.ghost.borrow_mut() = ghost;
+        let ghost_pct = self.ghost_pct();
+
+        self.ghost.style_modify(|style| {
+            match ghost_pct {
+                Some(pct) => {
+                    style.visibility = Visibility::Inheirt;
+
+                    match self.props.orientation {
+                        ScalerOrientation::Horizontal => style.pos_from_l = Percent(pct),
+                        ScalerOrientation::Vertical => style.pos_from_b = Percent(pct),
+                    }
+                },
+                None => {
+                    style.visibility = Visibility::Hide;
+                },
+            }
+        });
+    }
+
+    /// Get the value of the ghost knob.
+    pub fn ghost(&self) -> Option<f32> {
+        *self.state.lock().ghost.borrow()
+    }
+
+    fn ghost_pct(&self) -> Option<f32> {
+        let [min, max] = self.range();
+
+        self.ghost().map(|ghost| {
+            if max - min <= 0.0 {
+                0.0
+            } else {
+                ((ghost.clamp(min, max) - min) / (max - min)) * 100.0
+            }
+        })
+    }
+
+    /// Get the current minimum and maximum values.
+    pub fn range_values(&self) -> (f32, f32) {
+        let [min, max] = self.range();
+        (min, max)
+    }
+
+    /// Increment the value by a percentage of the range.
+    ///
+    /// For example, `5.0` increments the value by five percent of the difference between the
+    /// minimum and maximum. A negative percentage decrements the value.
+    ///
+    /// **Note**: This behaves the same as [`Scaler::increment`] otherwise.
+    pub fn step_by_percent(self: &Arc<Self>, percent: f32) {
+        let [min, max] = self.range();
+        self.increment((max - min) * (percent / 100.0));
+    }
+
     /// Increment the value by the provided amount.
     ///
     /// **Notes**:
+    /// - If [`ScalerBuilder::stops`] was used, this moves to the next stop instead.
     /// - The resulting value will be effected by rounding provided by [`ScalerBuilder::round`].
     /// - The resulting value will be clamped to values provided by [`ScalerBuilder::min_value`]
     /// and [`ScalerBuilder::max_value`].
     pub fn increment(self: &Arc<Self>, amt: f32) {
         let state = self.state.lock();
+
+        if !self.props.stops.is_empty() {
+            self.step_stop(amt);
+            return;
+        }
+
         let val = *state.val.borrow() + amt;
         self.set(val);
     }
@@ -481,15 +924,40 @@ impl Scaler {
     /// Decrement the value by the provided amount.
     ///
     /// **Notes**:
+    /// - If [`ScalerBuilder::stops`] was used, this moves to the previous stop instead.
     /// - The resulting value will be effected by rounding provided by [`ScalerBuilder::round`].
     /// - The resulting value will be clamped to values provided by [`ScalerBuilder::min_value`]
     /// and [`ScalerBuilder::max_value`].
     pub fn decrement(self: &Arc<Self>, amt: f32) {
         let state = self.state.lock();
+
+        if !self.props.stops.is_empty() {
+            self.step_stop(-amt);
+            return;
+        }
+
         let val = *state.val.borrow() - amt;
         self.set(val);
     }
 
+    /// Move to the adjacent stop in the direction of `amt`.
+    fn step_stop(self: &Arc<Self>, amt: f32) {
+        let val = self.val();
+        let stops = self.props.stops.iter().map(|(stop, _)| *stop);
+
+        let next = if amt > 0.0 {
+            stops.filter(|stop| *stop > val).min_by(f32::total_cmp)
+        } else if amt < 0.0 {
+            stops.filter(|stop| *stop < val).max_by(f32::total_cmp)
+        } else {
+            None
+        };
+
+        if let Some(next) = next {
+            self.set(next);
+        }
+    }
+
     /// Add a callback to be called when the [`Scaler`]'s value changed.
     ///
     /// **Note**: When changing the value within the callback, no callbacks will be called with
@@ -507,6 +975,71 @@ impl Scaler {
             .push(Box::new(on_change));
     }
 
+    /// Give keyboard focus to this [`Scaler`].
+    pub fn focus(&self) {
+        crate::focus_bin(&self.container);
+    }
+
+    /// Remove keyboard focus from this [`Scaler`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`Scaler`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.container);
+    }
+
+    /// Set if this [`Scaler`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`Scaler`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`Scaler`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`Scaler`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(self: &Arc<Self>, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::Slider`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`) and
     /// the [`ScalerOrientation`](`ScalerOrientation`).
     pub fn default_placement(theme: &Theme, orientation: ScalerOrientation) -> WidgetPlacement {
@@ -538,17 +1071,126 @@ impl Scaler {
         }
     }
 
+    fn value_label_size(&self) -> Option<f32> {
+        self.props.value_label?;
+
+        match self.props.orientation {
+            ScalerOrientation::Horizontal => {
+                Some(self.state.lock().value_label_width.borrow().unwrap_or(0.0))
+            },
+            ScalerOrientation::Vertical => Some(self.theme.get().text_height),
+        }
+    }
+
+    fn stop_label_size(&self) -> f32 {
+        match self.props.orientation {
+            ScalerOrientation::Horizontal => self.theme.get().text_height,
+            ScalerOrientation::Vertical => {
+                self.state.lock().stop_label_width.borrow().unwrap_or(0.0)
+            },
+        }
+    }
+
+    /// Measure the width the value label needs to fit both the minimum and maximum values.
+    ///
+    /// **Note**: This only applies to the horizontal orientation, the height of the text is used
+    /// for the vertical orientation.
+    fn measure_value_label(self: &Arc<Self>) {
+        let format = match self.props.value_label {
+            Some(format) if self.props.orientation == ScalerOrientation::Horizontal => format,
+            _ => return,
+        };
+
+        let [min, max] = self.range();
+        *self.state.lock().value_label_width.borrow_mut() = None;
+        self.measure_value_label_texts(vec![format(min), format(max)], 0.0);
+    }
+
+    fn measure_value_label_texts(self: &Arc<Self>, mut texts: Vec<String>, width: f32) {
+        let value_label = match self.value_label.as_ref() {
+            Some(some) => some,
+            None => return,
+        };
+
+        let text = match texts.pop() {
+            Some(some) => some,
+            None => {
+                *self.state.lock().value_label_width.borrow_mut() = Some(width);
+                self.style_update();
+                return;
+            },
+        };
+
+        value_label.style_modify(|style| {
+            style.width = Pixels(0.0);
+            style.text_body.hori_align = TextHoriAlign::Left;
+            style.text_body.spans[0].text = text;
+        });
+
+        let scaler_wk = Arc::downgrade(self);
+
+        crate::measure_text_width(value_label, move |text_width| {
+            if let Some(scaler) = scaler_wk.upgrade() {
+                scaler.measure_value_label_texts(texts, width.max(text_width));
+            }
+        });
+    }
+
+    /// Measure the width of the widest stop label.
+    ///
+    /// **Note**: This only applies to the vertical orientation, the height of the text is used
+    /// for the horizontal orientation.
+    fn measure_stop_labels(self: &Arc<Self>) {
+        if self.props.orientation != ScalerOrientation::Vertical || self.stops.is_empty() {
+            return;
+        }
+
+        // The number of stops remaining to be measured and the widest so far.
+        let measured = Arc::new(Mutex::new((self.stops.len(), 0.0_f32)));
+
+        for stop in self.stops.iter() {
+            stop.style_modify(|style| {
+                style.pos_from_r = Default::default();
+                style.width = Pixels(0.0);
+                style.text_body.hori_align = TextHoriAlign::Left;
+            });
+
+            let scaler_wk = Arc::downgrade(self);
+            let measured = measured.clone();
+
+            crate::measure_text_width(stop, move |text_width| {
+                let mut measured = measured.lock();
+                measured.0 -= 1;
+                measured.1 = measured.1.max(text_width);
+
+                if measured.0 == 0
+                    && let Some(scaler) = scaler_wk.upgrade()
+                {
+                    *scaler.state.lock().stop_label_width.borrow_mut() = Some(measured.1);
+                    drop(measured);
+                    scaler.style_update();
+                }
+            });
+        }
+    }
+
     fn style_update(self: &Arc<Self>) {
-        let border_size = self.theme.border.unwrap_or(0.0);
+        let border_size = self.theme.get().border.unwrap_or(0.0);
+
+        let [min, max] = self.range();
+        let pct = pct_from_val(self.val(), [min, max]);
 
-        let pct = ((*self.state.lock().val.borrow() - self.props.min)
-            / (self.props.max - self.props.min))
-            * 100.0;
+        // The space along the track taken by the value label.
+        let label_space = self
+            .value_label_size()
+            .map(|size| size + self.theme.get().spacing)
+            .unwrap_or(0.0);
 
-        let container_style = self.props.placement.clone().into_style();
+        let mut container_style = self.props.placement.clone().into_style();
+        container_style.visibility = self.container.style_inspect(|style| style.visibility);
 
         let mut track_style = BinStyle {
-            back_color: self.theme.colors.back3,
+            back_color: self.theme.get().colors.back3,
             ..Default::default()
         };
 
@@ -556,20 +1198,22 @@ impl Scaler {
 
         let mut knob_style = BinStyle {
             position: Position::Anchor,
-            back_color: self.theme.colors.accent1,
-            border_radius_tl: PctOfWidth(50.0),
-            border_radius_tr: PctOfWidth(50.0),
-            border_radius_bl: PctOfWidth(50.0),
-            border_radius_br: PctOfWidth(50.0),
+            back_color: self.theme.get().colors.accent1,
             ..Default::default()
         };
 
+        // The length of the knob along the track relative to its size across the track.
+        let knob_len_pct = match self.props.knob_shape {
+            ScalerKnobShape::Circle => 100.0,
+            ScalerKnobShape::Rect => 50.0,
+        };
+
         match self.props.orientation {
             ScalerOrientation::Horizontal => {
                 track_style.pos_from_t = Percent(25.0);
                 track_style.pos_from_b = Percent(25.0);
                 track_style.pos_from_l = Pixels(border_size);
-                track_style.pos_from_r = Pixels(border_size);
+                track_style.pos_from_r = Pixels(border_size + label_space);
                 track_style.border_radius_tl = PctOfHeight(50.0);
                 track_style.border_radius_tr = PctOfHeight(50.0);
                 track_style.border_radius_bl = PctOfHeight(50.0);
@@ -578,16 +1222,31 @@ impl Scaler {
                 confine_style.pos_from_t = Pixels(0.0);
                 confine_style.pos_from_b = Pixels(0.0);
                 confine_style.pos_from_l = Pixels(border_size);
-                confine_style.pos_from_r = PctOfHeightOffset(100.0, -border_size);
-
-                knob_style.pos_from_t = Pixels(border_size);
-                knob_style.pos_from_b = Pixels(border_size);
                 knob_style.pos_from_l = Percent(pct);
-                knob_style.width = PctOfHeightOffset(100.0, -2.0 * border_size);
+
+                match self.props.knob_size {
+                    Some(knob_size) => {
+                        let knob_len = knob_size * (knob_len_pct / 100.0);
+                        confine_style.pos_from_r = Pixels(knob_len + border_size + label_space);
+                        knob_style.pos_from_t = PctOffset(50.0, knob_size / -2.0);
+                        knob_style.height = Pixels(knob_size);
+                        knob_style.width = Pixels(knob_len);
+                    },
+                    None => {
+                        confine_style.pos_from_r = PctOfHeightOffset(
+                            knob_len_pct,
+                            border_size - ((knob_len_pct / 50.0) * border_size) + label_space,
+                        );
+                        knob_style.pos_from_t = Pixels(border_size);
+                        knob_style.pos_from_b = Pixels(border_size);
+                        knob_style.width =
+                            PctOfHeightOffset(knob_len_pct, (knob_len_pct / -50.0) * border_size);
+                    },
+                }
             },
             ScalerOrientation::Vertical => {
                 track_style.pos_from_t = Pixels(border_size);
-                track_style.pos_from_b = Pixels(border_size);
+                track_style.pos_from_b = Pixels(border_size + label_space);
                 track_style.pos_from_l = Percent(25.0);
                 track_style.pos_from_r = Percent(25.0);
                 track_style.border_radius_tl = PctOfWidth(50.0);
@@ -595,42 +1254,395 @@ impl Scaler {
                 track_style.border_radius_bl = PctOfWidth(50.0);
                 track_style.border_radius_br = PctOfWidth(50.0);
 
-                confine_style.pos_from_t = PctOfWidthOffset(100.0, -border_size);
-                confine_style.pos_from_b = Pixels(border_size);
+                confine_style.pos_from_b = Pixels(border_size + label_space);
                 confine_style.pos_from_l = Pixels(0.0);
                 confine_style.pos_from_r = Pixels(0.0);
-
-                knob_style.pos_from_l = Pixels(border_size);
-                knob_style.pos_from_r = Pixels(border_size);
                 knob_style.pos_from_b = Percent(pct);
-                knob_style.height = PctOfWidthOffset(100.0, -2.0 * border_size);
+
+                match self.props.knob_size {
+                    Some(knob_size) => {
+                        let knob_len = knob_size * (knob_len_pct / 100.0);
+                        confine_style.pos_from_t = Pixels(knob_len + border_size);
+                        knob_style.pos_from_l = PctOffset(50.0, knob_size / -2.0);
+                        knob_style.width = Pixels(knob_size);
+                        knob_style.height = Pixels(knob_len);
+                    },
+                    None => {
+                        confine_style.pos_from_t = PctOfWidthOffset(
+                            knob_len_pct,
+                            border_size - ((knob_len_pct / 50.0) * border_size),
+                        );
+                        knob_style.pos_from_l = Pixels(border_size);
+                        knob_style.pos_from_r = Pixels(border_size);
+                        knob_style.height =
+                            PctOfWidthOffset(knob_len_pct, (knob_len_pct / -50.0) * border_size);
+                    },
+                }
             },
         }
 
-        if let Some(border_size) = self.theme.border {
+        match self.props.knob_shape {
+            ScalerKnobShape::Circle => {
+                knob_style.border_radius_tl = PctOfWidth(50.0);
+                knob_style.border_radius_tr = PctOfWidth(50.0);
+                knob_style.border_radius_bl = PctOfWidth(50.0);
+                knob_style.border_radius_br = PctOfWidth(50.0);
+            },
+            ScalerKnobShape::Rect => {
+                if let Some(border_radius) = self.theme.get().roundness {
+                    knob_style.border_radius_tl = Pixels(border_radius);
+                    knob_style.border_radius_tr = Pixels(border_radius);
+                    knob_style.border_radius_bl = Pixels(border_radius);
+                    knob_style.border_radius_br = Pixels(border_radius);
+                }
+            },
+        }
+
+        if let Some(border_size) = self.theme.get().border {
             track_style.border_size_t = Pixels(border_size);
             track_style.border_size_b = Pixels(border_size);
             track_style.border_size_l = Pixels(border_size);
             track_style.border_size_r = Pixels(border_size);
-            track_style.border_color_t = self.theme.colors.border3;
-            track_style.border_color_b = self.theme.colors.border3;
-            track_style.border_color_l = self.theme.colors.border3;
-            track_style.border_color_r = self.theme.colors.border3;
+            track_style.border_color_t = self.theme.get().colors.border3;
+            track_style.border_color_b = self.theme.get().colors.border3;
+            track_style.border_color_l = self.theme.get().colors.border3;
+            track_style.border_color_r = self.theme.get().colors.border3;
             knob_style.border_size_t = Pixels(border_size);
             knob_style.border_size_b = Pixels(border_size);
             knob_style.border_size_l = Pixels(border_size);
             knob_style.border_size_r = Pixels(border_size);
-            knob_style.border_color_t = self.theme.colors.border3;
-            knob_style.border_color_b = self.theme.colors.border3;
-            knob_style.border_color_l = self.theme.colors.border3;
-            knob_style.border_color_r = self.theme.colors.border3;
+            knob_style.border_color_t = self.theme.get().colors.border3;
+            knob_style.border_color_b = self.theme.get().colors.border3;
+            knob_style.border_color_l = self.theme.get().colors.border3;
+            knob_style.border_color_r = self.theme.get().colors.border3;
+        }
+
+        // Each stop mirrors the knob along the track, extended past the track for its label.
+        let label_size = self.stop_label_size();
+        let stop_extent = (self.theme.get().spacing / 2.0) + label_size;
+        let tick_width = self.theme.get().border.unwrap_or(1.0);
+        let mut stop_styles = Vec::with_capacity(self.stops.len());
+
+        for (stop, label) in self.props.stops.iter() {
+            let stop_pct = (((stop - min) / (max - min)) * 100.0).clamp(0.0, 100.0);
+
+            let mut stop_style = BinStyle {
+                position: Position::Anchor,
+                text_body: TextBody {
+                    spans: vec![label.clone().into()],
+                    text_wrap: TextWrap::None,
+                    base_attrs: TextAttrs {
+                        height: Pixels(self.theme.get().text_height),
+                        color: self.theme.get().colors.text1a,
+                        font_family: self.theme.get().font_family.clone(),
+                        font_weight: self.theme.get().label_font_weight(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            let [x1, x2, y1, y2] = match self.props.orientation {
+                ScalerOrientation::Horizontal => {
+                    stop_style.pos_from_l = Percent(stop_pct);
+                    stop_style.pos_from_t = Pixels(0.0);
+                    stop_style.pos_from_b = Pixels(-stop_extent);
+                    stop_style.width = knob_style.width;
+                    stop_style.text_body.hori_align = TextHoriAlign::Center;
+                    stop_style.text_body.vert_align = TextVertAlign::Bottom;
+
+                    [
+                        PctOffset(50.0, -tick_width / 2.0),
+                        PctOffset(50.0, tick_width / 2.0),
+                        PctOffset(100.0, -stop_extent),
+                        PctOffset(100.0, -label_size),
+                    ]
+                },
+                ScalerOrientation::Vertical => {
+                    stop_style.pos_from_b = Percent(stop_pct);
+                    stop_style.pos_from_l = Pixels(0.0);
+                    stop_style.pos_from_r = Pixels(-stop_extent);
+                    stop_style.height = knob_style.height;
+                    stop_style.text_body.hori_align = TextHoriAlign::Right;
+                    stop_style.text_body.vert_align = TextVertAlign::Center;
+
+                    [
+                        PctOffset(100.0, -stop_extent),
+                        PctOffset(100.0, -label_size),
+                        PctOffset(50.0, -tick_width / 2.0),
+                        PctOffset(50.0, tick_width / 2.0),
+                    ]
+                },
+            };
+
+            stop_style.user_vertexes = vec![(
+                ImageKey::INVALID,
+                [(x1, y1), (x1, y2), (x2, y2), (x1, y1), (x2, y2), (x2, y1)]
+                    .into_iter()
+                    .map(|(x, y)| {
+                        BinVertex {
+                            x,
+                            y,
+                            color: self.theme.get().colors.border2,
+                            ..Default::default()
+                        }
+                    })
+                    .collect(),
+            )];
+
+            stop_styles.push(stop_style);
         }
 
-        Bin::style_update_batch([
+        // The ghost mirrors the knob at its own value, drawn below the knob at half opacity.
+        let mut ghost_style = knob_style.clone();
+        ghost_style.back_color.a *= 0.5;
+        ghost_style.border_color_t.a *= 0.5;
+        ghost_style.border_color_b.a *= 0.5;
+        ghost_style.border_color_l.a *= 0.5;
+        ghost_style.border_color_r.a *= 0.5;
+
+        match self.ghost_pct() {
+            Some(ghost_pct) => {
+                match self.props.orientation {
+                    ScalerOrientation::Horizontal => ghost_style.pos_from_l = Percent(ghost_pct),
+                    ScalerOrientation::Vertical => ghost_style.pos_from_b = Percent(ghost_pct),
+                }
+            },
+            None => {
+                ghost_style.visibility = Visibility::Hide;
+            },
+        }
+
+        if self.stops.is_empty() {
+            knob_style.z_index = ZIndex::Offset(1);
+        } else {
+            ghost_style.z_index = ZIndex::Offset(1);
+            knob_style.z_index = ZIndex::Offset(2);
+        }
+
+        let mut style_update_batch = vec![
             (&self.container, container_style),
             (&self.track, track_style),
             (&self.confine, confine_style),
+            (&self.ghost, ghost_style),
             (&self.knob, knob_style),
-        ]);
+        ];
+
+        style_update_batch.extend(self.stops.iter().zip(stop_styles));
+
+        if let (Some(value_label), Some(format), Some(label_size)) = (
+            self.value_label.as_ref(),
+            self.props.value_label,
+            self.value_label_size(),
+        ) {
+            let mut value_label_style = BinStyle {
+                text_body: TextBody {
+                    spans: vec![Default::default()],
+                    vert_align: TextVertAlign::Center,
+                    text_wrap: TextWrap::None,
+                    base_attrs: TextAttrs {
+                        height: Pixels(self.theme.get().text_height),
+                        color: self.theme.get().colors.text1a,
+                        font_family: self.theme.get().font_family.clone(),
+                        font_weight: self.theme.get().value_font_weight(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            value_label_style.text_body.spans[0].text = format(self.val());
+
+            match self.props.orientation {
+                ScalerOrientation::Horizontal => {
+                    value_label_style.pos_from_t = Pixels(0.0);
+                    value_label_style.pos_from_b = Pixels(0.0);
+                    value_label_style.pos_from_r = Pixels(0.0);
+                    value_label_style.width = Pixels(label_size);
+                    value_label_style.text_body.hori_align = TextHoriAlign::Right;
+                },
+                ScalerOrientation::Vertical => {
+                    value_label_style.pos_from_b = Pixels(0.0);
+                    value_label_style.pos_from_l = Pixels(0.0);
+                    value_label_style.pos_from_r = Pixels(0.0);
+                    value_label_style.height = Pixels(label_size);
+                    value_label_style.text_body.hori_align = TextHoriAlign::Center;
+                },
+            }
+
+            style_update_batch.push((value_label, value_label_style));
+        }
+
+        Bin::style_update_batch(style_update_batch);
+    }
+}
+
+impl Widget for Scaler {
+    fn focus(&self) {
+        Scaler::focus(self)
+    }
+
+    fn blur(&self) {
+        Scaler::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        Scaler::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        Scaler::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        Scaler::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        if let Some(scaler) = self.this.upgrade() {
+            Scaler::set_theme(&scaler, theme);
+        }
+    }
+}
+
+/// The value at a percentage along the track.
+fn val_from_pct(pct: f32, [min, max]: [f32; 2]) -> f32 {
+    ((max - min) * (pct / 100.0)) + min
+}
+
+/// The percentage along the track of a value.
+fn pct_from_val(val: f32, [min, max]: [f32; 2]) -> f32 {
+    ((val - min) / (max - min)) * 100.0
+}
+
+/// The percentage along the track of the cursor, keeping the knob centered on the cursor.
+///
+/// `track` and `knob` are the top left, top right and bottom left corners.
+fn cursor_pct(
+    orientation: ScalerOrientation,
+    [cursor_x, cursor_y]: [f32; 2],
+    [track_tl, track_tr, track_bl]: [[f32; 2]; 3],
+    [knob_tl, knob_tr, knob_bl]: [[f32; 2]; 3],
+) -> f32 {
+    let pct = match orientation {
+        ScalerOrientation::Horizontal => {
+            let knob_width_1_2 = (knob_tr[0] - knob_tl[0]) / 2.0;
+            let cursor_x_min = track_tl[0] + knob_width_1_2;
+            let cursor_x_max = track_tr[0] - knob_width_1_2;
+            ((cursor_x - cursor_x_min) / (cursor_x_max - cursor_x_min)) * 100.0
+        },
+        ScalerOrientation::Vertical => {
+            let knob_height_1_2 = (knob_bl[1] - knob_tl[1]) / 2.0;
+            let cursor_y_min = track_tl[1] + knob_height_1_2;
+            let cursor_y_max = track_bl[1] - knob_height_1_2;
+            100.0 - (((cursor_y - cursor_y_min) / (cursor_y_max - cursor_y_min)) * 100.0)
+        },
+    };
+
+    pct.clamp(0.0, 100.0)
+}
+
+/// Round a value to the nearest multiple of `step`.
+fn round_step(val: f32, step: f32) -> f32 {
+    (val / step).round() * step
+}
+
+/// The fine step used when none is provided.
+///
+/// With [`ScalerRound::Step`] a smaller step would be rounded back to the current value.
+fn default_fine_step(small_step: f32, round: ScalerRound) -> f32 {
+    match round {
+        ScalerRound::Step => small_step,
+        _ => small_step / 10.0,
+    }
+}
+
+/// Round a value with the provided [`ScalerRound`] and clamp it to the range.
+fn apply_round(val: f32, round: ScalerRound, step: f32, [min, max]: [f32; 2]) -> f32 {
+    match round {
+        ScalerRound::None => val,
+        ScalerRound::Int => val.round(),
+        ScalerRound::Step => round_step(val, step),
+    }
+    .clamp(min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drag_snaps_to_step() {
+        let val = round_step(val_from_pct(37.0, [0.0, 10.0]), 1.0);
+        assert_eq!(val, 4.0);
+        assert_eq!(pct_from_val(val, [0.0, 10.0]), 40.0);
+    }
+
+    #[test]
+    fn step_is_multiple_of_small_step() {
+        assert_eq!(round_step(7.4, 2.5), 7.5);
+        assert_eq!(round_step(-1.2, 1.0), -1.0);
+    }
+
+    #[test]
+    fn initial_value_rounded_to_step() {
+        let val = apply_round(5.0, ScalerRound::Step, 3.0, [0.0, 10.0]);
+        assert_eq!(val, 6.0);
+        assert!((pct_from_val(val, [0.0, 10.0]) - 60.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn default_fine_step_moves_with_step_rounding() {
+        let fine_step = default_fine_step(2.0, ScalerRound::Step);
+        assert_eq!(
+            apply_round(4.0 + fine_step, ScalerRound::Step, 2.0, [0.0, 10.0]),
+            6.0
+        );
+        assert_eq!(
+            apply_round(4.0 - fine_step, ScalerRound::Step, 2.0, [0.0, 10.0]),
+            2.0
+        );
+    }
+
+    #[test]
+    fn default_fine_step_is_tenth_of_small_step() {
+        assert_eq!(default_fine_step(2.0, ScalerRound::None), 0.2);
+        assert_eq!(default_fine_step(2.0, ScalerRound::Int), 0.2);
+    }
+
+    #[test]
+    fn rounded_value_clamped_to_range() {
+        assert_eq!(apply_round(9.8, ScalerRound::Step, 3.0, [0.0, 10.0]), 9.0);
+        assert_eq!(apply_round(10.0, ScalerRound::Step, 4.0, [0.0, 10.0]), 10.0);
+        assert_eq!(apply_round(5.5, ScalerRound::Int, 3.0, [0.0, 10.0]), 6.0);
+        assert_eq!(apply_round(5.5, ScalerRound::None, 3.0, [0.0, 10.0]), 5.5);
+    }
+
+    #[test]
+    fn vertical_drag_uses_knob_height() {
+        // A knob wider than it is tall, within a track 100 tall.
+        let track = [[0.0, 0.0], [20.0, 0.0], [0.0, 100.0]];
+        let knob = [[0.0, 40.0], [20.0, 40.0], [0.0, 50.0]];
+        let pct = |y| cursor_pct(ScalerOrientation::Vertical, [10.0, y], track, knob);
+
+        assert_eq!(pct(5.0), 100.0);
+        assert_eq!(pct(50.0), 50.0);
+        assert_eq!(pct(95.0), 0.0);
+        assert_eq!(pct(-10.0), 100.0);
+        assert_eq!(pct(110.0), 0.0);
+    }
+
+    #[test]
+    fn horizontal_drag_uses_knob_width() {
+        // A knob taller than it is wide, within a track 100 wide.
+        let track = [[0.0, 0.0], [100.0, 0.0], [0.0, 20.0]];
+        let knob = [[40.0, 0.0], [50.0, 0.0], [40.0, 20.0]];
+        let pct = |x| cursor_pct(ScalerOrientation::Horizontal, [x, 10.0], track, knob);
+
+        assert_eq!(pct(5.0), 0.0);
+        assert_eq!(pct(50.0), 50.0);
+        assert_eq!(pct(95.0), 100.0);
     }
 }
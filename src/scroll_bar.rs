@@ -1,20 +1,21 @@
 use std::cell::RefCell;
 use std::f32::consts::PI;
-use std::sync::Arc;
 use std::sync::atomic::{self, AtomicBool};
+use std::sync::{Arc, Weak};
 use std::time::Duration;
 
 use basalt::image::ImageKey;
-use basalt::input::{InputHookCtrl, MouseButton};
+use basalt::input::{InputHookCtrl, MouseButton, Qwerty, WindowState};
 use basalt::interface::UnitValue::{
     PctOfHeight, PctOfHeightOffset, PctOfWidth, PctOfWidthOffset, Percent, Pixels,
 };
-use basalt::interface::{Bin, BinID, BinStyle, BinVertex, Color, Position};
+use basalt::interface::{Bin, BinID, BinStyle, BinVertex, Color, Position, Visibility};
 use parking_lot::ReentrantMutex;
 
 use crate::builder::WidgetBuilder;
 use crate::button::{BtnHookColors, button_hooks};
-use crate::{Theme, WidgetContainer, WidgetPlacement, ulps_eq};
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole, ulps_eq};
 
 /// Determintes the orientation and axis of the [`ScrollBar`].
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
@@ -28,8 +29,61 @@ pub enum ScrollAxis {
     Y,
 }
 
+/// The easing used by the animations of a [`ScrollBar`].
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    /// Move at a constant speed.
+    Linear,
+    /// Start and end slowly following a sine curve.
+    ///
+    /// **Note**: This is the default.
+    #[default]
+    EaseInOutSine,
+    /// Start quickly and end slowly following a sine curve.
+    EaseOutSine,
+    /// Start quickly and end slowly following a cubic curve.
+    EaseOutCubic,
+    /// A custom function.
+    ///
+    /// The function is given the linear progress of the animation from `0.0` to `1.0` and should
+    /// return the eased progress, which should be `0.0` at the start and `1.0` at the end.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::EaseInOutSine => (((t + 1.5) * PI).sin() + 1.0) / 2.0,
+            Self::EaseOutSine => ((t * PI) / 2.0).sin(),
+            Self::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            Self::Custom(method) => method(t),
+        }
+    }
+}
+
+/// The source of a scroll performed by a [`ScrollBar`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollSource {
+    /// The scroll wheel or a trackpad over the target or the [`ScrollBar`].
+    Wheel,
+    /// Dragging the bar.
+    Drag,
+    /// Pressing the trough on either side of the bar.
+    Trough,
+    /// Pressing one of the arrow buttons.
+    Arrow,
+    /// A key press within a widget that uses the [`ScrollBar`].
+    Keyboard,
+    /// A method called on the [`ScrollBar`], such as [`ScrollBar::scroll_to`].
+    ///
+    /// **Note**: This also includes corrections made when the target shrinks.
+    Programmatic,
+}
+
 struct Properties {
     target: Arc<Bin>,
+    content: Option<Arc<Bin>>,
     axis: ScrollAxis,
     smooth: bool,
     step: f32,
@@ -37,25 +91,41 @@ struct Properties {
     accel_pow: f32,
     max_accel_mult: f32,
     animation_duration: Duration,
+    easing: Easing,
+    grab_padding: f32,
+    min_bar_size: f32,
+    direction_change_threshold: f32,
+    show_arrows: bool,
+    shift_horizontal: bool,
+    interactive: bool,
     placement: WidgetPlacement,
 }
 
 #[derive(Default)]
 struct InitialState {
     scroll: Option<f32>,
+    scroll_fraction: Option<f32>,
 }
 
 impl Properties {
-    fn new(target: Arc<Bin>, placement: WidgetPlacement) -> Self {
+    fn new(target: Arc<Bin>, placement: WidgetPlacement, theme: &Theme) -> Self {
         Self {
             target,
+            content: None,
             axis: ScrollAxis::Y,
-            smooth: true,
+            smooth: !theme.reduce_motion,
             step: 50.0,
-            accel: true,
+            accel: !theme.reduce_motion,
             accel_pow: 1.2,
             max_accel_mult: 4.0,
             animation_duration: Duration::from_millis(100),
+            easing: Easing::EaseInOutSine,
+            grab_padding: 0.0,
+            min_bar_size: 0.0,
+            direction_change_threshold: 0.0,
+            show_arrows: true,
+            shift_horizontal: false,
+            interactive: true,
             placement,
         }
     }
@@ -67,6 +137,9 @@ pub struct ScrollBarBuilder<'a, C> {
     props: Properties,
     initial_state: InitialState,
     plmt_is_default: bool,
+    on_scroll: Vec<Box<dyn FnMut(&Arc<ScrollBar>, f32, ScrollSource) + Send + 'static>>,
+    on_reach_min: Vec<Box<dyn FnMut(&Arc<ScrollBar>) + Send + 'static>>,
+    on_reach_max: Vec<Box<dyn FnMut(&Arc<ScrollBar>) + Send + 'static>>,
 }
 
 impl<'a, C> ScrollBarBuilder<'a, C>
@@ -81,15 +154,33 @@ where
             plmt_is_default: builder.placement.is_none(),
             props: Properties::new(
                 target.container_bin().clone(),
-                builder.placement.take().unwrap_or_else(|| {
-                    ScrollBar::default_placement(&builder.theme, Default::default())
+                builder.take_placement(|theme| {
+                    ScrollBar::default_placement(theme, Default::default())
                 }),
+                &builder.theme,
             ),
             widget: builder,
             initial_state: Default::default(),
+            on_scroll: Vec::new(),
+            on_reach_min: Vec::new(),
+            on_reach_max: Vec::new(),
         }
     }
 
+    /// Set the bin that holds the scrollable content of the target.
+    ///
+    /// When set, the overflow is computed from the size of the content compared to the size of
+    /// the target, rather than from the overflow of the target itself. This is useful when the
+    /// content is nested within the target, such as a content bin within a clipping viewport.
+    ///
+    /// **Notes**:
+    /// - The target is still the bin that gets scrolled.
+    /// - If not set the overflow of the target is used.
+    pub fn content(mut self, content: &Arc<Bin>) -> Self {
+        self.props.content = Some(content.clone());
+        self
+    }
+
     /// Set the amount the target container should be scrolled initially.
     ///
     /// **Note**: If not set this defaults the current scroll amount defined by the target container.
@@ -98,6 +189,17 @@ where
         self
     }
 
+    /// Set the fraction of the overflow the target container should be scrolled initially.
+    ///
+    /// **Notes**:
+    /// - The overflow depends on layout, so this is applied once the target has been updated.
+    /// - `fraction` is clamped between `0.0` and `1.0`.
+    /// - This takes precedence over [`scroll`](ScrollBarBuilder::scroll).
+    pub fn scroll_fraction(mut self, fraction: f32) -> Self {
+        self.initial_state.scroll_fraction = Some(fraction.clamp(0.0, 1.0));
+        self
+    }
+
     /// Set the axis.
     ///
     /// See [`ScrollAxis`] docs for more information.
@@ -105,7 +207,9 @@ where
     /// **Note**: If not set this defaults to [`ScrollAxis::Y`].
     pub fn axis(mut self, axis: ScrollAxis) -> Self {
         if self.plmt_is_default {
-            self.props.placement = ScrollBar::default_placement(&self.widget.theme, axis);
+            self.props.placement = self
+                .widget
+                .apply_placement(ScrollBar::default_placement(&self.widget.theme, axis));
         }
 
         self.props.axis = axis;
@@ -114,7 +218,8 @@ where
 
     /// Set if smooth scroll is enabled.
     ///
-    /// **Note**: If not set this defaults to `true`.
+    /// **Note**: If not set this defaults to `true`, or `false` if the theme's `reduce_motion` is
+    /// enabled.
     pub fn smooth(mut self, smooth: bool) -> Self {
         self.props.smooth = smooth;
         self
@@ -139,7 +244,7 @@ where
     /// max acceleration multiplier.
     ///
     /// **Notes**:
-    /// - If not set this defaults to `true`.
+    /// - If not set this defaults to `true`, or `false` if the theme's `reduce_motion` is enabled.
     /// - Smooth scroll will be enabled if acceleration is enabled.
     pub fn accel(mut self, accel: bool) -> Self {
         self.props.accel = accel;
@@ -176,6 +281,148 @@ where
         self
     }
 
+    /// Set the easing of animations.
+    ///
+    /// See [`Easing`] docs for more information.
+    ///
+    /// **Notes**:
+    /// - If not set this defaults to [`Easing::EaseInOutSine`].
+    /// - Has no effect if smooth scroll or acceleration is not enabled.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.props.easing = easing;
+        self
+    }
+
+    /// Set the threshold that an opposite direction scroll must exceed to be considered a change
+    /// in direction.
+    ///
+    /// A change in direction resets the pending scroll amount and with it any acceleration. Input
+    /// devices such as trackpads may report small deltas in the opposite direction, which without a
+    /// threshold cause the scroll to stutter.
+    ///
+    /// The threshold is in pixels, the same unit as [`ScrollBar::scroll`]. A wheel scroll amounts
+    /// to its delta multiplied by the step, so a threshold below the step only affects
+    /// fractional deltas.
+    ///
+    /// **Notes**:
+    /// - If not set this defaults to `0.0`.
+    /// - Has no effect if smooth scroll or acceleration is not enabled.
+    /// - When acceleration is enabled, deltas under the threshold are still multiplied by the
+    ///   current acceleration.
+    pub fn direction_change_threshold(mut self, threshold: f32) -> Self {
+        self.props.direction_change_threshold = threshold.max(0.0);
+        self
+    }
+
+    /// Set the padding around the bar that will still start a drag when pressed.
+    ///
+    /// This makes the bar easier to grab without changing its visible size.
+    ///
+    /// **Notes**:
+    /// - If not set this defaults to `0.0`.
+    /// - The padding only extends the bar along the axis and only within the trough. Presses
+    ///   beside the [`ScrollBar`] or on its arrows are not affected.
+    pub fn grab_padding(mut self, grab_padding: f32) -> Self {
+        self.props.grab_padding = grab_padding.max(0.0);
+        self
+    }
+
+    /// Set the minimum length of the bar along the axis in pixels.
+    ///
+    /// With large amounts of overflow the bar can become too small to grab. When clamped, the
+    /// distance the bar can travel is reduced so that dragging it end to end still covers the full
+    /// scroll range.
+    ///
+    /// **Notes**:
+    /// - If not set this defaults to `0.0`.
+    /// - The bar is never longer than the space between the arrows.
+    pub fn min_bar_size(mut self, min_bar_size: f32) -> Self {
+        self.props.min_bar_size = min_bar_size.max(0.0);
+        self
+    }
+
+    /// Set if the arrow buttons on either end are shown.
+    ///
+    /// When hidden, the bar's track extends to fill the [`ScrollBar`].
+    ///
+    /// **Note**: If not set this defaults to `true`.
+    pub fn show_arrows(mut self, show_arrows: bool) -> Self {
+        self.props.show_arrows = show_arrows;
+        self
+    }
+
+    /// Set if the vertical wheel scrolls horizontally while shift is held.
+    ///
+    /// When enabled on a [`ScrollAxis::X`] bar, the vertical wheel delta is used while shift is
+    /// held. When enabled on a [`ScrollAxis::Y`] bar, the vertical wheel delta is ignored while
+    /// shift is held. Enable this on both bars of a target to have shift + wheel only scroll
+    /// horizontally.
+    ///
+    /// **Note**: If not set this defaults to `false`.
+    pub fn shift_horizontal(mut self, shift_horizontal: bool) -> Self {
+        self.props.shift_horizontal = shift_horizontal;
+        self
+    }
+
+    /// Set if the [`ScrollBar`] accepts input.
+    ///
+    /// When not interactive, the [`ScrollBar`] only indicates the scroll of the target. The bar
+    /// can't be dragged, the trough and arrows can't be pressed and the mouse wheel doesn't scroll.
+    ///
+    /// **Notes**:
+    /// - The target can still be scrolled with methods such as [`ScrollBar::scroll_to`].
+    /// - If not set this defaults to `true`.
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.props.interactive = interactive;
+        self
+    }
+
+    /// Add a callback to be called when the target is scrolled.
+    ///
+    /// The callback is called with the new scroll amount and the [`ScrollSource`] of the scroll.
+    ///
+    /// **Note**: When animated, this is called every frame of the animation.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_scroll<F>(mut self, on_scroll: F) -> Self
+    where
+        F: FnMut(&Arc<ScrollBar>, f32, ScrollSource) + Send + 'static,
+    {
+        self.on_scroll.push(Box::new(on_scroll));
+        self
+    }
+
+    /// Add a callback to be called when the target is scrolled to its start.
+    ///
+    /// **Note**: This is only called once the scroll reaches the start. Continuing to scroll at
+    /// the start won't call it again until the target has been scrolled away from it.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_reach_min<F>(mut self, on_reach_min: F) -> Self
+    where
+        F: FnMut(&Arc<ScrollBar>) + Send + 'static,
+    {
+        self.on_reach_min.push(Box::new(on_reach_min));
+        self
+    }
+
+    /// Add a callback to be called when the target is scrolled to its end.
+    ///
+    /// **Notes**:
+    /// - This is only called once the scroll reaches the end. Continuing to scroll at the end
+    /// won't call it again until the target has been scrolled away from it.
+    /// - If the overflow of the target grows while at the end, such as when content is appended,
+    /// the scroll is no longer at the end, so this is called again when the end is reached.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_reach_max<F>(mut self, on_reach_max: F) -> Self
+    where
+        F: FnMut(&Arc<ScrollBar>) + Send + 'static,
+    {
+        self.on_reach_max.push(Box::new(on_reach_max));
+        self
+    }
+
     /// Finish building the [`ScrollBar`].
     pub fn build(self) -> Arc<ScrollBar> {
         let window = self
@@ -185,20 +432,23 @@ where
             .window()
             .expect("The widget container must have an associated window.");
 
-        let mut new_bins = window.new_bins(5).into_iter();
+        let num_bins = if self.props.show_arrows { 5 } else { 3 };
+        let mut new_bins = window.new_bins(num_bins).into_iter();
         let container = new_bins.next().unwrap();
-        let upright = new_bins.next().unwrap();
-        let downleft = new_bins.next().unwrap();
         let confine = new_bins.next().unwrap();
         let bar = new_bins.next().unwrap();
+        let upright = new_bins.next();
+        let downleft = new_bins.next();
 
         self.widget
             .container
             .container_bin()
             .add_child(container.clone());
 
-        container.add_child(upright.clone());
-        container.add_child(downleft.clone());
+        for arrow in upright.iter().chain(downleft.iter()) {
+            container.add_child(arrow.clone());
+        }
+
         container.add_child(confine.clone());
         confine.add_child(bar.clone());
 
@@ -214,32 +464,45 @@ where
                 })
         });
 
-        let scroll_bar = Arc::new(ScrollBar {
-            theme: self.widget.theme,
-            props: self.props,
-            container,
-            upright,
-            downleft,
-            confine,
-            bar,
-            state: ReentrantMutex::new(State {
-                target: RefCell::new(TargetState {
-                    overflow: scroll,
-                    scroll,
-                    size: 0.0,
-                }),
-                smooth: RefCell::new(SmoothState {
-                    run: false,
-                    start: 0.0,
-                    target: 0.0,
-                    time: 0.0,
+        let scroll_bar = Arc::new_cyclic(|this| {
+            ScrollBar {
+                theme: ThemeCell::new(self.widget.theme),
+                name: self.widget.name,
+                aria_label: self.widget.aria_label,
+                role: self.widget.role.unwrap_or(WidgetRole::ScrollBar),
+                props: self.props,
+                container,
+                upright,
+                downleft,
+                confine,
+                bar,
+                state: ReentrantMutex::new(State {
+                    target: RefCell::new(TargetState {
+                        overflow: scroll,
+                        scroll,
+                        size: 0.0,
+                        // Considered at both ends initially so that nothing is called on creation.
+                        at_min: true,
+                        at_max: true,
+                    }),
+                    smooth: RefCell::new(SmoothState {
+                        run: false,
+                        start: 0.0,
+                        target: 0.0,
+                        time: 0.0,
+                        source: ScrollSource::Programmatic,
+                    }),
+                    drag: RefCell::new(DragState {
+                        cursor_start: 0.0,
+                        scroll_start: 0.0,
+                        scroll_per_px: 0.0,
+                    }),
+                    on_scroll: RefCell::new(self.on_scroll),
+                    on_reach_min: RefCell::new(self.on_reach_min),
+                    on_reach_max: RefCell::new(self.on_reach_max),
                 }),
-                drag: RefCell::new(DragState {
-                    cursor_start: 0.0,
-                    scroll_start: 0.0,
-                    scroll_per_px: 0.0,
-                }),
-            }),
+                this: this.clone(),
+            }
         });
 
         let scroll_bar_wk = Arc::downgrade(&scroll_bar);
@@ -266,6 +529,34 @@ where
             }
         });
 
+        if let Some(content) = scroll_bar.props.content.as_ref() {
+            let scroll_bar_wk = Arc::downgrade(&scroll_bar);
+
+            content.on_update(move |_, _| {
+                if let Some(scroll_bar) = scroll_bar_wk.upgrade() {
+                    scroll_bar.refresh();
+                }
+            });
+        }
+
+        if let Some(fraction) = self.initial_state.scroll_fraction {
+            let scroll_bar_wk = Arc::downgrade(&scroll_bar);
+
+            scroll_bar.props.target.on_update_once(move |_, _| {
+                if let Some(scroll_bar) = scroll_bar_wk.upgrade() {
+                    scroll_bar.refresh();
+                    let overflow = scroll_bar.state.lock().target.borrow().overflow;
+                    scroll_bar.jump_to(fraction * overflow);
+                }
+            });
+        }
+
+        if !scroll_bar.props.interactive {
+            scroll_bar.style_update();
+            self.widget.container.widget_built(scroll_bar.clone());
+            return scroll_bar;
+        }
+
         let scroll_bar_wk = Arc::downgrade(&scroll_bar);
 
         window
@@ -275,25 +566,13 @@ where
             .bin(&scroll_bar.props.target)
             .on_scroll()
             .upper_blocks(true)
-            .call(move |_, _, scroll_y, scroll_x| {
+            .call(move |_, w_state, scroll_y, scroll_x| {
                 let scroll_bar = match scroll_bar_wk.upgrade() {
                     Some(some) => some,
                     None => return InputHookCtrl::Remove,
                 };
 
-                match scroll_bar.props.axis {
-                    ScrollAxis::X => {
-                        if scroll_x != 0.0 {
-                            scroll_bar.scroll(scroll_x * scroll_bar.props.step);
-                        }
-                    },
-                    ScrollAxis::Y => {
-                        if scroll_y != 0.0 {
-                            scroll_bar.scroll(scroll_y * scroll_bar.props.step);
-                        }
-                    },
-                }
-
+                scroll_bar.wheel_scroll(w_state, scroll_y, scroll_x);
                 Default::default()
             })
             .finish()
@@ -308,25 +587,13 @@ where
             .bin(&scroll_bar.container)
             .on_scroll()
             .upper_blocks(true)
-            .call(move |_, _, scroll_y, scroll_x| {
+            .call(move |_, w_state, scroll_y, scroll_x| {
                 let scroll_bar = match scroll_bar_wk.upgrade() {
                     Some(some) => some,
                     None => return InputHookCtrl::Remove,
                 };
 
-                match scroll_bar.props.axis {
-                    ScrollAxis::X => {
-                        if scroll_x != 0.0 {
-                            scroll_bar.scroll(scroll_x * scroll_bar.props.step);
-                        }
-                    },
-                    ScrollAxis::Y => {
-                        if scroll_y != 0.0 {
-                            scroll_bar.scroll(scroll_y * scroll_bar.props.step);
-                        }
-                    },
-                }
-
+                scroll_bar.wheel_scroll(w_state, scroll_y, scroll_x);
                 Default::default()
             })
             .finish()
@@ -344,34 +611,21 @@ where
                     None => return InputHookCtrl::Remove,
                 };
 
-                let [cursor_x, cursor_y] = w_state.cursor_pos();
-
-                let cursor_start = match scroll_bar.props.axis {
-                    ScrollAxis::X => cursor_x,
-                    ScrollAxis::Y => cursor_y,
-                };
-
-                let state = scroll_bar.state.lock();
-                state.smooth.borrow_mut().run = false;
-
-                let mut drag_state = state.drag.borrow_mut();
-                drag_state.cursor_start = cursor_start;
-                drag_state.scroll_start = state.target.borrow().scroll;
-
+                scroll_bar.start_drag(w_state.cursor_pos());
                 cb_bar_held.store(true, atomic::Ordering::SeqCst);
                 Default::default()
             });
 
-        let cb_bar_held = bar_held.clone();
+        for target in [&scroll_bar.bar, &scroll_bar.confine] {
+            let cb_bar_held = bar_held.clone();
 
-        scroll_bar
-            .bar
-            .on_release(MouseButton::Left, move |_, _, _| {
+            target.on_release(MouseButton::Left, move |_, _, _| {
                 cb_bar_held.store(false, atomic::Ordering::SeqCst);
                 Default::default()
             });
+        }
 
-        let cb_bar_held = bar_held;
+        let cb_bar_held = bar_held.clone();
         let scroll_bar_wk = Arc::downgrade(&scroll_bar);
 
         scroll_bar
@@ -397,12 +651,13 @@ where
                         drag_state.scroll_start + (delta * drag_state.scroll_per_px)
                     };
 
-                    scroll_bar.jump_to(jump_to);
+                    scroll_bar.jump_to_from(jump_to, ScrollSource::Drag);
                 }
 
                 Default::default()
             }));
 
+        let cb_bar_held = bar_held;
         let scroll_bar_wk = Arc::downgrade(&scroll_bar);
 
         scroll_bar
@@ -415,6 +670,22 @@ where
 
                 let [cursor_x, cursor_y] = w_state.cursor_pos();
                 let bar_bpu = scroll_bar.bar.post_update();
+
+                if scroll_bar.props.grab_padding > 0.0 {
+                    let padding = scroll_bar.props.grab_padding;
+
+                    let [bar_start, bar_end, cursor] = match scroll_bar.props.axis {
+                        ScrollAxis::X => [bar_bpu.tli[0], bar_bpu.tri[0], cursor_x],
+                        ScrollAxis::Y => [bar_bpu.tli[1], bar_bpu.bli[1], cursor_y],
+                    };
+
+                    if cursor >= bar_start - padding && cursor <= bar_end + padding {
+                        scroll_bar.start_drag([cursor_x, cursor_y]);
+                        cb_bar_held.store(true, atomic::Ordering::SeqCst);
+                        return Default::default();
+                    }
+                }
+
                 let state = scroll_bar.state.lock();
 
                 let delta = match scroll_bar.props.axis {
@@ -429,71 +700,87 @@ where
                 let scroll_to =
                     state.target.borrow().scroll + (delta * state.drag.borrow().scroll_per_px);
 
-                scroll_bar.scroll_to(scroll_to);
+                scroll_bar.scroll_to_from(scroll_to, ScrollSource::Trough);
                 Default::default()
             });
 
-        let scroll_bar_wk = Arc::downgrade(&scroll_bar);
-
-        button_hooks(
-            &scroll_bar.upright,
-            BtnHookColors {
-                vert_clr: Some(scroll_bar.theme.colors.border1),
-                h_vert_clr: Some(scroll_bar.theme.colors.border3),
-                p_vert_clr: Some(scroll_bar.theme.colors.border2),
-                ..Default::default()
-            },
-            move |_| {
-                if let Some(scroll_bar) = scroll_bar_wk.upgrade() {
-                    scroll_bar.scroll(-scroll_bar.props.step);
-                }
-            },
-        );
-
-        let scroll_bar_wk = Arc::downgrade(&scroll_bar);
+        if let Some(upright) = scroll_bar.upright.as_ref() {
+            let scroll_bar_wk = Arc::downgrade(&scroll_bar);
+            let colors_scroll_bar_wk = scroll_bar_wk.clone();
+
+            button_hooks(
+                upright,
+                move || {
+                    colors_scroll_bar_wk
+                        .upgrade()
+                        .map(|scroll_bar| scroll_bar.arrow_colors())
+                        .unwrap_or_default()
+                },
+                move |_| {
+                    if let Some(scroll_bar) = scroll_bar_wk.upgrade() {
+                        scroll_bar.scroll_from(-scroll_bar.props.step, ScrollSource::Arrow);
+                    }
+                },
+            );
+        }
 
-        button_hooks(
-            &scroll_bar.downleft,
-            BtnHookColors {
-                vert_clr: Some(scroll_bar.theme.colors.border1),
-                h_vert_clr: Some(scroll_bar.theme.colors.border3),
-                p_vert_clr: Some(scroll_bar.theme.colors.border2),
-                ..Default::default()
-            },
-            move |_| {
-                if let Some(scroll_bar) = scroll_bar_wk.upgrade() {
-                    scroll_bar.scroll(scroll_bar.props.step);
-                }
-            },
-        );
+        if let Some(downleft) = scroll_bar.downleft.as_ref() {
+            let scroll_bar_wk = Arc::downgrade(&scroll_bar);
+            let colors_scroll_bar_wk = scroll_bar_wk.clone();
+
+            button_hooks(
+                downleft,
+                move || {
+                    colors_scroll_bar_wk
+                        .upgrade()
+                        .map(|scroll_bar| scroll_bar.arrow_colors())
+                        .unwrap_or_default()
+                },
+                move |_| {
+                    if let Some(scroll_bar) = scroll_bar_wk.upgrade() {
+                        scroll_bar.scroll_from(scroll_bar.props.step, ScrollSource::Arrow);
+                    }
+                },
+            );
+        }
 
         scroll_bar.style_update();
+        self.widget.container.widget_built(scroll_bar.clone());
         scroll_bar
     }
 }
 
 /// Scroll bar widget
 pub struct ScrollBar {
-    theme: Theme,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     container: Arc<Bin>,
-    upright: Arc<Bin>,
-    downleft: Arc<Bin>,
+    upright: Option<Arc<Bin>>,
+    downleft: Option<Arc<Bin>>,
     confine: Arc<Bin>,
     bar: Arc<Bin>,
     state: ReentrantMutex<State>,
+    this: Weak<ScrollBar>,
 }
 
 struct State {
     target: RefCell<TargetState>,
     smooth: RefCell<SmoothState>,
     drag: RefCell<DragState>,
+    on_scroll: RefCell<Vec<Box<dyn FnMut(&Arc<ScrollBar>, f32, ScrollSource) + Send + 'static>>>,
+    on_reach_min: RefCell<Vec<Box<dyn FnMut(&Arc<ScrollBar>) + Send + 'static>>>,
+    on_reach_max: RefCell<Vec<Box<dyn FnMut(&Arc<ScrollBar>) + Send + 'static>>>,
 }
 
 struct TargetState {
     overflow: f32,
     scroll: f32,
     size: f32,
+    at_min: bool,
+    at_max: bool,
 }
 
 struct SmoothState {
@@ -501,6 +788,7 @@ struct SmoothState {
     start: f32,
     target: f32,
     time: f32,
+    source: ScrollSource,
 }
 
 struct DragState {
@@ -516,44 +804,36 @@ impl ScrollBar {
     /// - This may be effected by acceleration.
     /// - If smooth scroll or acceleration are both disabled this uses [`ScrollBar::jump`].
     pub fn scroll(self: &Arc<Self>, amt: f32) {
+        self.scroll_from(amt, ScrollSource::Programmatic);
+    }
+
+    pub(crate) fn scroll_from(self: &Arc<Self>, amt: f32, source: ScrollSource) {
         let state = self.state.lock();
 
         if !self.props.accel && !self.props.smooth {
-            self.scroll_no_anim(amt);
+            self.scroll_no_anim(amt, source);
             return;
         }
 
         let target_state = state.target.borrow();
         let mut smooth_state = state.smooth.borrow_mut();
 
-        smooth_state.target = if !smooth_state.run {
-            target_state.scroll + amt
-        } else {
-            let direction_changes = !ulps_eq(
-                (smooth_state.target - target_state.scroll).signum(),
-                amt.signum(),
-                4,
-            );
-
-            if self.props.accel {
-                if direction_changes {
-                    target_state.scroll + amt
+        smooth_state.target = smooth_target(
+            target_state.scroll,
+            smooth_state.run.then_some(smooth_state.target),
+            amt,
+            self.props.direction_change_threshold,
+            |pending| {
+                if self.props.accel {
+                    (pending / self.props.step)
+                        .max(1.0)
+                        .powf(self.props.accel_pow)
+                        .clamp(1.0, self.props.max_accel_mult)
                 } else {
-                    smooth_state.target
-                        + (((smooth_state.target - target_state.scroll).abs() / self.props.step)
-                            .max(1.0)
-                            .powf(self.props.accel_pow)
-                            .clamp(1.0, self.props.max_accel_mult)
-                            * amt)
+                    1.0
                 }
-            } else {
-                if direction_changes {
-                    target_state.scroll + amt
-                } else {
-                    smooth_state.target + amt
-                }
-            }
-        };
+            },
+        );
 
         if ulps_eq(smooth_state.target, target_state.scroll, 4) {
             return;
@@ -566,16 +846,21 @@ impl ScrollBar {
 
         smooth_state.start = target_state.scroll;
         smooth_state.time = 0.0;
+        smooth_state.source = source;
     }
 
     /// Scroll to a certain amount of pixels.
     ///
     /// **Note**: If smooth scroll or acceleration are both disabled this uses [`ScrollBar::jump_to`].
     pub fn scroll_to(self: &Arc<Self>, to: f32) {
+        self.scroll_to_from(to, ScrollSource::Programmatic);
+    }
+
+    fn scroll_to_from(self: &Arc<Self>, to: f32, source: ScrollSource) {
         let state = self.state.lock();
 
         if !self.props.accel && !self.props.smooth {
-            self.jump_to(to);
+            self.jump_to_from(to, source);
             return;
         }
 
@@ -596,6 +881,7 @@ impl ScrollBar {
             smooth_state.start = target_state.scroll;
             smooth_state.target = to;
             smooth_state.time = 0.0;
+            smooth_state.source = source;
         }
     }
 
@@ -619,7 +905,7 @@ impl ScrollBar {
         self.scroll_to(max);
     }
 
-    fn scroll_no_anim(&self, amt: f32) {
+    fn scroll_no_anim(&self, amt: f32, source: ScrollSource) {
         let state = self.state.lock();
         let mut update = self.check_target_state();
 
@@ -650,7 +936,7 @@ impl ScrollBar {
         }
 
         if update {
-            self.update();
+            self.update(source);
         }
     }
 
@@ -660,17 +946,21 @@ impl ScrollBar {
     pub fn jump(&self, amt: f32) {
         let state = self.state.lock();
         state.smooth.borrow_mut().run = false;
-        self.scroll_no_anim(amt);
+        self.scroll_no_anim(amt, ScrollSource::Programmatic);
     }
 
     /// Jump to a certain amount of pixels.
     ///
     /// **Note**: This is the same as [`ScrollBar::scroll_to`] but does not animate.
     pub fn jump_to(&self, to: f32) {
-        self.jump_to_inner(to, true);
+        self.jump_to_from(to, ScrollSource::Programmatic);
+    }
+
+    pub(crate) fn jump_to_from(&self, to: f32, source: ScrollSource) {
+        self.jump_to_inner(to, true, source);
     }
 
-    fn jump_to_inner(&self, to: f32, cancel_smooth: bool) {
+    fn jump_to_inner(&self, to: f32, cancel_smooth: bool, source: ScrollSource) {
         let state = self.state.lock();
         let mut update = self.check_target_state();
 
@@ -700,7 +990,7 @@ impl ScrollBar {
         }
 
         if update {
-            self.update();
+            self.update(source);
         }
     }
 
@@ -724,7 +1014,7 @@ impl ScrollBar {
         }
 
         if update {
-            self.update();
+            self.update(ScrollSource::Programmatic);
         }
     }
 
@@ -748,10 +1038,60 @@ impl ScrollBar {
         }
 
         if update {
-            self.update();
+            self.update(ScrollSource::Programmatic);
         }
     }
 
+    /// Add a callback to be called when the target is scrolled.
+    ///
+    /// The callback is called with the new scroll amount and the [`ScrollSource`] of the scroll.
+    ///
+    /// **Note**: When animated, this is called every frame of the animation.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_scroll<F>(&self, on_scroll: F)
+    where
+        F: FnMut(&Arc<ScrollBar>, f32, ScrollSource) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_scroll
+            .borrow_mut()
+            .push(Box::new(on_scroll));
+    }
+
+    /// Add a callback to be called when the target is scrolled to its start.
+    ///
+    /// See [`ScrollBarBuilder::on_reach_min`] for details.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_reach_min<F>(&self, on_reach_min: F)
+    where
+        F: FnMut(&Arc<ScrollBar>) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_reach_min
+            .borrow_mut()
+            .push(Box::new(on_reach_min));
+    }
+
+    /// Add a callback to be called when the target is scrolled to its end.
+    ///
+    /// See [`ScrollBarBuilder::on_reach_max`] for details.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_reach_max<F>(&self, on_reach_max: F)
+    where
+        F: FnMut(&Arc<ScrollBar>) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_reach_max
+            .borrow_mut()
+            .push(Box::new(on_reach_max));
+    }
+
     /// Recheck the state and update if needed.
     ///
     /// **Note**: This may need to be called in certain cases.
@@ -759,7 +1099,7 @@ impl ScrollBar {
         let _state = self.state.lock();
 
         if self.check_target_state() {
-            self.update();
+            self.update(ScrollSource::Programmatic);
         }
     }
 
@@ -774,13 +1114,44 @@ impl ScrollBar {
     }
 
     /// The amount of overflow of the target on the axis that is controlled.
+    ///
+    /// **Note**: When [`ScrollBarBuilder::content`] is used, this is the amount the content is
+    /// larger than the target.
     pub fn target_overflow(&self) -> f32 {
+        if let Some(content) = self.props.content.as_ref() {
+            let content_bpu = content.post_update();
+
+            let content_size = match self.props.axis {
+                ScrollAxis::X => content_bpu.tri[0] - content_bpu.tli[0],
+                ScrollAxis::Y => content_bpu.bli[1] - content_bpu.tli[1],
+            };
+
+            return (content_size - self.target_size()).max(0.0);
+        }
+
         match self.props.axis {
             ScrollAxis::X => self.props.target.calc_hori_overflow(),
             ScrollAxis::Y => self.props.target.calc_vert_overflow(),
         }
     }
 
+    /// The size of the target that is visible on the axis that is controlled.
+    ///
+    /// Unlike [`ScrollBar::target_size`], this is the size as of the last update of the
+    /// [`ScrollBar`].
+    pub fn visible_size(&self) -> f32 {
+        self.state.lock().target.borrow().size
+    }
+
+    /// The amount to scroll to move by a page.
+    ///
+    /// This is the visible size less a step, so that some of the previous page remains visible.
+    ///
+    /// **Note**: This is never less than the step size.
+    pub fn page_size(&self) -> f32 {
+        (self.visible_size() - self.props.step).max(self.props.step)
+    }
+
     /// The current amount the target is scrolled.
     pub fn current_scroll(&self) -> f32 {
         self.state.lock().target.borrow().scroll
@@ -802,19 +1173,111 @@ impl ScrollBar {
         state.target.borrow().scroll
     }
 
+    fn arrow_colors(&self) -> BtnHookColors {
+        let theme = self.theme.get();
+
+        BtnHookColors {
+            vert_clr: Some(theme.colors.border1),
+            h_vert_clr: Some(theme.colors.border3),
+            p_vert_clr: Some(theme.colors.border2),
+            ..Default::default()
+        }
+    }
+
     // TODO: Public?
     pub(crate) fn size(theme: &Theme) -> f32 {
         (theme.base_size / 1.5) + theme.border.unwrap_or(0.0)
     }
 
+    /// Set if this [`ScrollBar`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`ScrollBar`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`ScrollBar`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`ScrollBar`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+        self.update(ScrollSource::Programmatic);
+    }
+
     pub(crate) fn has_bin_id(&self, bin_id: BinID) -> bool {
         bin_id == self.container.id()
-            || bin_id == self.upright.id()
-            || bin_id == self.downleft.id()
+            || self
+                .upright
+                .as_ref()
+                .is_some_and(|upright| bin_id == upright.id())
+            || self
+                .downleft
+                .as_ref()
+                .is_some_and(|downleft| bin_id == downleft.id())
             || bin_id == self.confine.id()
             || bin_id == self.bar.id()
     }
 
+    fn wheel_scroll(self: &Arc<Self>, w_state: &WindowState, scroll_y: f32, scroll_x: f32) {
+        let shift_held = self.props.shift_horizontal
+            && (w_state.is_key_pressed(Qwerty::LShift) || w_state.is_key_pressed(Qwerty::RShift));
+
+        let amt = match self.props.axis {
+            ScrollAxis::X => {
+                if shift_held && scroll_y != 0.0 {
+                    scroll_y
+                } else {
+                    scroll_x
+                }
+            },
+            ScrollAxis::Y => {
+                if shift_held {
+                    return;
+                }
+
+                scroll_y
+            },
+        };
+
+        if amt != 0.0 {
+            self.scroll_from(amt * self.props.step, ScrollSource::Wheel);
+        }
+    }
+
+    fn start_drag(&self, [cursor_x, cursor_y]: [f32; 2]) {
+        let cursor_start = match self.props.axis {
+            ScrollAxis::X => cursor_x,
+            ScrollAxis::Y => cursor_y,
+        };
+
+        let state = self.state.lock();
+        state.smooth.borrow_mut().run = false;
+
+        let mut drag_state = state.drag.borrow_mut();
+        drag_state.cursor_start = cursor_start;
+        drag_state.scroll_start = state.target.borrow().scroll;
+    }
+
     fn check_target_state(&self) -> bool {
         let target_overflow = self.target_overflow();
         let target_size = self.target_size();
@@ -844,6 +1307,7 @@ impl ScrollBar {
         if let Some(window) = self.container.window() {
             let scroll_bar = self.clone();
             let animation_duration = self.props.animation_duration.as_micros() as f32 / 1000.0;
+            let easing = self.props.easing;
 
             window.renderer_on_frame(move |elapsed_op| {
                 let state = scroll_bar.state.lock();
@@ -859,17 +1323,24 @@ impl ScrollBar {
 
                 let delta = smooth_state.target - smooth_state.start;
                 let linear_t = (smooth_state.time / animation_duration).clamp(0.0, 1.0);
-                let smooth_t = (((linear_t + 1.5) * PI).sin() + 1.0) / 2.0;
-                scroll_bar.jump_to_inner(smooth_state.start + (delta * smooth_t), false);
+                let smooth_t = easing.apply(linear_t);
+                let jump_to = smooth_state.start + (delta * smooth_t);
+                let source = smooth_state.source;
                 smooth_state.run = smooth_state.time < animation_duration;
-                smooth_state.run
+                let run = smooth_state.run;
+
+                // on_scroll callbacks may start another scroll, so the borrow must be released.
+                drop(smooth_state);
+                scroll_bar.jump_to_inner(jump_to, false, source);
+                run
             });
         }
     }
 
-    fn update(&self) {
+    fn update(&self, source: ScrollSource) {
         let state = self.state.lock();
         let target_state = state.target.borrow();
+        let scroll = target_state.scroll;
         let confine_bpu = self.confine.post_update();
 
         let confine_size = match self.props.axis {
@@ -882,23 +1353,14 @@ impl ScrollBar {
             ScrollAxis::Y => confine_bpu.tri[0] - confine_bpu.tli[0],
         };
 
-        let [scroll_per_px, bar_size_pct, bar_offset_pct] =
-            if target_state.overflow > 0.0 && target_state.size > 0.0 {
-                let overflow_ratio = target_state.overflow / target_state.size;
-                let max_space_size = confine_size - confine_sec_size;
-
-                let space_size = ((-1.0 / ((0.25 * overflow_ratio) + 0.5) + 2.0)
-                    * (max_space_size / 2.0))
-                    .clamp(0.0, max_space_size);
-
-                let scroll_per_px = target_state.overflow / space_size;
-                let bar_size_pct = ((confine_size - space_size) / confine_size) * 100.0;
-                let bar_offset_pct = ((target_state.scroll / scroll_per_px) / confine_size) * 100.0;
-
-                [scroll_per_px, bar_size_pct, bar_offset_pct]
-            } else {
-                [0.0, 100.0, 0.0]
-            };
+        let [scroll_per_px, bar_size_pct, bar_offset_pct] = bar_metrics(
+            target_state.overflow,
+            target_state.size,
+            target_state.scroll,
+            confine_size,
+            confine_sec_size,
+            self.props.min_bar_size,
+        );
 
         state.drag.borrow_mut().scroll_per_px = scroll_per_px;
 
@@ -927,11 +1389,68 @@ impl ScrollBar {
             },
         }
 
+        let at_min = target_state.scroll <= 0.0;
+        let at_max = target_state.overflow > 0.0 && target_state.scroll >= target_state.overflow;
+        drop(target_state);
+
+        let [reached_min, reached_max] = {
+            let mut target_state = state.target.borrow_mut();
+            let reached = [
+                at_min && !target_state.at_min,
+                at_max && !target_state.at_max,
+            ];
+            target_state.at_min = at_min;
+            target_state.at_max = at_max;
+            reached
+        };
+
         if target_style_update {
             Bin::style_update_batch([(&self.props.target, target_style), (&self.bar, bar_style)]);
         } else {
             self.bar.style_update(bar_style).expect_valid();
         }
+
+        // The ScrollBar is being dropped, so there is no one to notify.
+        let scroll_bar = match self.this.upgrade() {
+            Some(some) => some,
+            None => return,
+        };
+
+        if target_style_update && let Ok(mut on_scroll_cbs) = state.on_scroll.try_borrow_mut() {
+            for on_scroll in on_scroll_cbs.iter_mut() {
+                on_scroll(&scroll_bar, scroll, source);
+            }
+        }
+
+        if reached_min && let Ok(mut on_reach_min_cbs) = state.on_reach_min.try_borrow_mut() {
+            for on_reach_min in on_reach_min_cbs.iter_mut() {
+                on_reach_min(&scroll_bar);
+            }
+        }
+
+        if reached_max && let Ok(mut on_reach_max_cbs) = state.on_reach_max.try_borrow_mut() {
+            for on_reach_max in on_reach_max_cbs.iter_mut() {
+                on_reach_max(&scroll_bar);
+            }
+        }
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::ScrollBar`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
     }
 
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`) and
@@ -960,11 +1479,12 @@ impl ScrollBar {
     }
 
     fn style_update(&self) {
-        let spacing = (self.theme.spacing / 10.0).ceil();
-        let border_size = self.theme.border.unwrap_or(0.0);
+        let spacing = (self.theme.get().spacing / 10.0).ceil();
+        let border_size = self.theme.get().border.unwrap_or(0.0);
 
         let mut container_style = BinStyle {
-            back_color: self.theme.colors.back2,
+            back_color: self.theme.get().colors.back2,
+            visibility: self.container.style_inspect(|style| style.visibility),
             ..self.props.placement.clone().into_style()
         };
 
@@ -982,7 +1502,7 @@ impl ScrollBar {
 
         let mut bar_style = BinStyle {
             position: Position::Anchor,
-            back_color: self.theme.colors.accent1,
+            back_color: self.theme.get().colors.accent1,
             ..Default::default()
         };
 
@@ -994,7 +1514,7 @@ impl ScrollBar {
                 upright_style.width = PctOfHeight(100.0);
                 upright_style.user_vertexes = vec![(
                     ImageKey::INVALID,
-                    right_symbol_verts(10.0, self.theme.colors.border1),
+                    right_symbol_verts(10.0, self.theme.get().colors.border1),
                 )];
 
                 downleft_style.pos_from_t = Pixels(0.0);
@@ -1003,7 +1523,7 @@ impl ScrollBar {
                 downleft_style.width = PctOfHeight(100.0);
                 downleft_style.user_vertexes = vec![(
                     ImageKey::INVALID,
-                    left_symbol_verts(10.0, self.theme.colors.border1),
+                    left_symbol_verts(10.0, self.theme.get().colors.border1),
                 )];
 
                 confine_style.pos_from_t = Pixels(spacing);
@@ -1023,7 +1543,7 @@ impl ScrollBar {
                 upright_style.height = PctOfWidth(100.0);
                 upright_style.user_vertexes = vec![(
                     ImageKey::INVALID,
-                    up_symbol_verts(10.0, self.theme.colors.border1),
+                    up_symbol_verts(10.0, self.theme.get().colors.border1),
                 )];
 
                 downleft_style.pos_from_b = Pixels(0.0);
@@ -1032,7 +1552,7 @@ impl ScrollBar {
                 downleft_style.height = PctOfWidth(100.0);
                 downleft_style.user_vertexes = vec![(
                     ImageKey::INVALID,
-                    down_symbol_verts(10.0, self.theme.colors.border1),
+                    down_symbol_verts(10.0, self.theme.get().colors.border1),
                 )];
 
                 confine_style.pos_from_t = PctOfWidthOffset(100.0, border_size);
@@ -1047,38 +1567,38 @@ impl ScrollBar {
             },
         }
 
-        if let Some(border_size) = self.theme.border {
+        if let Some(border_size) = self.theme.get().border {
             bar_style.border_size_t = Pixels(border_size);
             bar_style.border_size_b = Pixels(border_size);
             bar_style.border_size_l = Pixels(border_size);
             bar_style.border_size_r = Pixels(border_size);
-            bar_style.border_color_t = self.theme.colors.border3;
-            bar_style.border_color_b = self.theme.colors.border3;
-            bar_style.border_color_l = self.theme.colors.border3;
-            bar_style.border_color_r = self.theme.colors.border3;
+            bar_style.border_color_t = self.theme.get().colors.border3;
+            bar_style.border_color_b = self.theme.get().colors.border3;
+            bar_style.border_color_l = self.theme.get().colors.border3;
+            bar_style.border_color_r = self.theme.get().colors.border3;
 
             if !container_style.border_size_t.is_defined() {
                 container_style.border_size_t = Pixels(border_size);
-                container_style.border_color_t = self.theme.colors.border1;
+                container_style.border_color_t = self.theme.get().colors.border1;
             }
 
             if !container_style.border_size_b.is_defined() {
                 container_style.border_size_b = Pixels(border_size);
-                container_style.border_color_b = self.theme.colors.border1;
+                container_style.border_color_b = self.theme.get().colors.border1;
             }
 
             if !container_style.border_size_l.is_defined() {
                 container_style.border_size_l = Pixels(border_size);
-                container_style.border_color_l = self.theme.colors.border1;
+                container_style.border_color_l = self.theme.get().colors.border1;
             }
 
             if !container_style.border_size_r.is_defined() {
                 container_style.border_size_r = Pixels(border_size);
-                container_style.border_color_r = self.theme.colors.border1;
+                container_style.border_color_r = self.theme.get().colors.border1;
             }
         }
 
-        if let Some(border_radius) = self.theme.roundness {
+        if let Some(border_radius) = self.theme.get().roundness {
             match self.props.axis {
                 ScrollAxis::X => {
                     bar_style.border_radius_tl = PctOfHeight(50.0);
@@ -1147,17 +1667,60 @@ impl ScrollBar {
             }
         }
 
-        Bin::style_update_batch([
+        if !self.props.show_arrows {
+            match self.props.axis {
+                ScrollAxis::X => {
+                    confine_style.pos_from_l = Pixels(spacing);
+                    confine_style.pos_from_r = Pixels(spacing);
+                },
+                ScrollAxis::Y => {
+                    confine_style.pos_from_t = Pixels(spacing);
+                    confine_style.pos_from_b = Pixels(spacing);
+                },
+            }
+        }
+
+        let mut style_update_batch = vec![
             (&self.container, container_style),
-            (&self.upright, upright_style),
-            (&self.downleft, downleft_style),
             (&self.confine, confine_style),
             (&self.bar, bar_style),
-        ]);
+        ];
+
+        if let Some(upright) = self.upright.as_ref() {
+            style_update_batch.push((upright, upright_style));
+        }
+
+        if let Some(downleft) = self.downleft.as_ref() {
+            style_update_batch.push((downleft, downleft_style));
+        }
+
+        Bin::style_update_batch(style_update_batch);
     }
 }
 
-fn up_symbol_verts(space_pct: f32, color: Color) -> Vec<BinVertex> {
+impl Widget for ScrollBar {
+    fn focus(&self) {}
+
+    fn blur(&self) {}
+
+    fn is_visible(&self) -> bool {
+        ScrollBar::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        ScrollBar::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        ScrollBar::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        ScrollBar::set_theme(self, theme)
+    }
+}
+
+pub(crate) fn up_symbol_verts(space_pct: f32, color: Color) -> Vec<BinVertex> {
     symbol_verts(
         color,
         &[
@@ -1179,6 +1742,75 @@ pub(crate) fn down_symbol_verts(space_pct: f32, color: Color) -> Vec<BinVertex>
     )
 }
 
+/// Compute the scroll per pixel of bar movement along with the bar size and offset percentages.
+fn bar_metrics(
+    overflow: f32,
+    size: f32,
+    scroll: f32,
+    confine_size: f32,
+    confine_sec_size: f32,
+    min_bar_size: f32,
+) -> [f32; 3] {
+    if overflow <= 0.0 || size <= 0.0 || confine_size <= 0.0 {
+        return [0.0, 100.0, 0.0];
+    }
+
+    let overflow_ratio = overflow / size;
+    // When the confine is shorter than it is wide there is no space for the bar to move.
+    let max_space_size = (confine_size - confine_sec_size).max(0.0);
+
+    let space_size = ((-1.0 / ((0.25 * overflow_ratio) + 0.5) + 2.0) * (max_space_size / 2.0))
+        .clamp(0.0, max_space_size);
+
+    // Clamping the travel space keeps the bar at least the minimum size. Since scroll_per_px is
+    // derived from it, dragging end to end still covers the overflow.
+    let space_size = space_size.min((confine_size - min_bar_size).max(0.0));
+
+    if space_size <= 0.0 {
+        return [0.0, 100.0, 0.0];
+    }
+
+    let scroll_per_px = overflow / space_size;
+    let bar_size_pct = (((confine_size - space_size) / confine_size) * 100.0).clamp(0.0, 100.0);
+
+    let bar_offset_pct =
+        (((scroll / scroll_per_px) / confine_size) * 100.0).clamp(0.0, 100.0 - bar_size_pct);
+
+    [scroll_per_px, bar_size_pct, bar_offset_pct]
+}
+
+/// Check if a scroll amount changes the direction of the pending scroll.
+fn changes_direction(pending: f32, amt: f32, threshold: f32) -> bool {
+    !ulps_eq(pending.signum(), amt.signum(), 4) && amt.abs() >= threshold
+}
+
+/// The target of a smooth scroll after scrolling `amt` from `scroll`.
+///
+/// `target` is the target of the running smooth scroll if there is one. `accel_mult` provides the
+/// multiplier of `amt` given the pending scroll amount. A change in direction discards the pending
+/// scroll amount.
+fn smooth_target<A>(
+    scroll: f32,
+    target: Option<f32>,
+    amt: f32,
+    threshold: f32,
+    accel_mult: A,
+) -> f32
+where
+    A: FnOnce(f32) -> f32,
+{
+    let target = match target {
+        Some(some) => some,
+        None => return scroll + amt,
+    };
+
+    if changes_direction(target - scroll, amt, threshold) {
+        scroll + amt
+    } else {
+        target + (accel_mult((target - scroll).abs()) * amt)
+    }
+}
+
 fn left_symbol_verts(space_pct: f32, color: Color) -> Vec<BinVertex> {
     symbol_verts(
         color,
@@ -1214,3 +1846,102 @@ fn symbol_verts(color: Color, unit_points: &[[f32; 2]; 3]) -> Vec<BinVertex> {
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn direction_change_below_threshold() {
+        let mut pending: f32 = 10.0;
+
+        for i in 0..8 {
+            let amt = if i % 2 == 0 { -1.0 } else { 1.0 };
+            assert!(!changes_direction(pending, amt, 2.0));
+            pending += amt;
+        }
+
+        assert_eq!(pending, 10.0);
+    }
+
+    #[test]
+    fn direction_change_at_threshold() {
+        assert!(changes_direction(10.0, -2.0, 2.0));
+        assert!(changes_direction(-10.0, 3.0, 2.0));
+        assert!(!changes_direction(10.0, 3.0, 2.0));
+    }
+
+    #[test]
+    fn smooth_target_suppresses_small_reversal() {
+        // Without a threshold the reversal discards the pending 100.0.
+        assert_eq!(smooth_target(0.0, Some(100.0), -10.0, 0.0, |_| 1.0), -10.0);
+
+        // Below the threshold it is applied to the pending scroll instead.
+        assert_eq!(smooth_target(0.0, Some(100.0), -10.0, 20.0, |_| 1.0), 90.0);
+        assert_eq!(smooth_target(0.0, Some(100.0), -10.0, 20.0, |_| 2.0), 80.0);
+
+        // At the threshold it is a change in direction.
+        assert_eq!(smooth_target(0.0, Some(100.0), -20.0, 20.0, |_| 2.0), -20.0);
+    }
+
+    #[test]
+    fn smooth_target_without_running_scroll() {
+        assert_eq!(smooth_target(50.0, None, -10.0, 20.0, |_| 2.0), 40.0);
+    }
+
+    #[test]
+    fn direction_change_without_threshold() {
+        assert!(changes_direction(10.0, -1.0, 0.0));
+        assert!(changes_direction(-10.0, 1.0, 0.0));
+        assert!(!changes_direction(10.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn bar_fills_small_confine() {
+        // Shorter than it is wide: no space for the bar to move.
+        assert_eq!(
+            bar_metrics(500.0, 100.0, 250.0, 10.0, 12.0, 0.0),
+            [0.0, 100.0, 0.0]
+        );
+
+        // Collapsed confine.
+        assert_eq!(
+            bar_metrics(500.0, 100.0, 250.0, 0.0, 12.0, 0.0),
+            [0.0, 100.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn bar_within_small_confine() {
+        for scroll in [0.0, 250.0, 500.0, 1000.0] {
+            let [scroll_per_px, bar_size_pct, bar_offset_pct] =
+                bar_metrics(500.0, 100.0, scroll, 16.0, 12.0, 0.0);
+
+            assert!(scroll_per_px > 0.0);
+            assert!((0.0..=100.0).contains(&bar_size_pct));
+            assert!(bar_offset_pct >= 0.0);
+            assert!(bar_offset_pct + bar_size_pct <= 100.0 + 0.001);
+        }
+    }
+
+    #[test]
+    fn clamped_bar_dragged_end_to_end() {
+        let overflow = 10000.0;
+        let confine_size = 100.0;
+        let min_bar_size = 30.0;
+        let metrics =
+            |scroll| bar_metrics(overflow, 100.0, scroll, confine_size, 10.0, min_bar_size);
+
+        let [scroll_per_px, bar_size_pct, bar_offset_pct] = metrics(0.0);
+        assert!((bar_size_pct - 30.0).abs() < 0.001);
+        assert_eq!(bar_offset_pct, 0.0);
+
+        // Dragging the bar across its travel distance covers the entire overflow.
+        let travel = confine_size - min_bar_size;
+        assert!(((travel * scroll_per_px) - overflow).abs() < 0.01);
+
+        // At the end of the overflow the bar is at the end of the confine.
+        let [_, bar_size_pct, bar_offset_pct] = metrics(overflow);
+        assert!((bar_offset_pct + bar_size_pct - 100.0).abs() < 0.001);
+    }
+}
@@ -0,0 +1,568 @@
+use std::cell::RefCell;
+use std::f32::consts::FRAC_PI_2;
+use std::sync::Arc;
+use std::time::Duration;
+
+use basalt::image::ImageKey;
+use basalt::interface::UnitValue::{PctOfHeight, PctOffset, Percent, Pixels};
+use basalt::interface::{
+    Bin, BinStyle, BinVertex, Position, TextAttrs, TextBody, TextHoriAlign, TextVertAlign,
+    TextWrap, Visibility,
+};
+use parking_lot::ReentrantMutex;
+
+use crate::builder::WidgetBuilder;
+use crate::button::{BtnHookColors, button_hooks};
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
+
+/// Builder for [`Section`]
+pub struct SectionBuilder<'a, C> {
+    widget: WidgetBuilder<'a, C>,
+    props: Properties,
+    collapsed: bool,
+    on_toggle: Vec<Box<dyn FnMut(&Arc<Section>, bool) + Send + 'static>>,
+}
+
+struct Properties {
+    title: String,
+    content_height: f32,
+    animation_duration: Duration,
+    placement: WidgetPlacement,
+}
+
+impl<'a, C> SectionBuilder<'a, C>
+where
+    C: WidgetContainer,
+{
+    pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
+        Self {
+            props: Properties {
+                title: String::new(),
+                content_height: (builder.theme.spacing + builder.theme.base_size) * 4.0,
+                animation_duration: if builder.theme.reduce_motion {
+                    Duration::ZERO
+                } else {
+                    Duration::from_millis(150)
+                },
+                placement: builder.take_placement(Section::default_placement),
+            },
+            widget: builder,
+            collapsed: false,
+            on_toggle: Vec::new(),
+        }
+    }
+
+    /// Set the title displayed within the header.
+    ///
+    /// **Note**: When this isn't used the title will be empty.
+    pub fn title<T>(mut self, title: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.props.title = title.into();
+        self
+    }
+
+    /// Set if the [`Section`] is collapsed once built.
+    ///
+    /// **Note**: If not set this defaults to `false`.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Set the height of the content when expanded.
+    ///
+    /// **Note**: If not set this defaults to four times the theme's `spacing` + `base_size`.
+    pub fn content_height(mut self, height: f32) -> Self {
+        self.props.content_height = height.max(0.0);
+        self
+    }
+
+    /// Set the duration of the collapse and expand animation.
+    ///
+    /// **Note**: If not set this defaults to 150 ms, or zero if the theme's `reduce_motion` is
+    /// enabled.
+    pub fn animation_duration(mut self, animation_duration: Duration) -> Self {
+        self.props.animation_duration = animation_duration;
+        self
+    }
+
+    /// Add a callback to be called when the [`Section`] is collapsed or expanded.
+    ///
+    /// The callback is called with `true` when collapsed.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_toggle<F>(mut self, on_toggle: F) -> Self
+    where
+        F: FnMut(&Arc<Section>, bool) + Send + 'static,
+    {
+        self.on_toggle.push(Box::new(on_toggle));
+        self
+    }
+
+    /// Finish building the [`Section`].
+    pub fn build(self) -> Arc<Section> {
+        let window = self
+            .widget
+            .container
+            .container_bin()
+            .window()
+            .expect("The widget container must have an associated window.");
+
+        let mut new_bins = window.new_bins(3).into_iter();
+        let container = new_bins.next().unwrap();
+        let header = new_bins.next().unwrap();
+        let content = new_bins.next().unwrap();
+
+        self.widget
+            .container
+            .container_bin()
+            .add_child(container.clone());
+
+        container.add_child(header.clone());
+        container.add_child(content.clone());
+
+        let progress = if self.collapsed { 0.0 } else { 1.0 };
+
+        let section = Arc::new(Section {
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::Section),
+            props: self.props,
+            container,
+            header,
+            content,
+            state: ReentrantMutex::new(State {
+                anim: RefCell::new(AnimState {
+                    collapsed: self.collapsed,
+                    progress,
+                    hooked: false,
+                }),
+                on_toggle: RefCell::new(self.on_toggle),
+            }),
+        });
+
+        let cb_section = section.clone();
+        let section_wk = Arc::downgrade(&section);
+
+        button_hooks(
+            &section.header,
+            move || {
+                section_wk
+                    .upgrade()
+                    .map(|section| section.header_colors())
+                    .unwrap_or_default()
+            },
+            move |_| {
+                cb_section.toggle();
+            },
+        );
+
+        section.style_update();
+        self.widget.container.widget_built(section.clone());
+        section
+    }
+}
+
+/// Section widget
+///
+/// A header with a title that collapses and expands the content below it when pressed.
+///
+/// Widgets are added to the content by using the [`Section`] as their container.
+///
+/// **Note**: The height of the placement is ignored, as the [`Section`] sizes itself to its header
+/// and the height of its content.
+pub struct Section {
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
+    props: Properties,
+    container: Arc<Bin>,
+    header: Arc<Bin>,
+    content: Arc<Bin>,
+    state: ReentrantMutex<State>,
+}
+
+struct State {
+    anim: RefCell<AnimState>,
+    on_toggle: RefCell<Vec<Box<dyn FnMut(&Arc<Section>, bool) + Send + 'static>>>,
+}
+
+struct AnimState {
+    collapsed: bool,
+    progress: f32,
+    hooked: bool,
+}
+
+impl Section {
+    /// Obtain the [`Bin`] that contains the content.
+    pub fn content(&self) -> &Arc<Bin> {
+        &self.content
+    }
+
+    /// Obtain the title displayed within the header.
+    pub fn title(&self) -> &str {
+        &self.props.title
+    }
+
+    /// Collapse the [`Section`], hiding its content.
+    pub fn collapse(self: &Arc<Self>) {
+        self.set_collapsed(true);
+    }
+
+    /// Expand the [`Section`], showing its content.
+    pub fn expand(self: &Arc<Self>) {
+        self.set_collapsed(false);
+    }
+
+    /// Toggle between collapsed and expanded.
+    ///
+    /// Returns `true` if the [`Section`] is now collapsed.
+    pub fn toggle(self: &Arc<Self>) -> bool {
+        let state = self.state.lock();
+        let collapsed = !state.anim.borrow().collapsed;
+        self.set_collapsed(collapsed);
+        collapsed
+    }
+
+    /// Check if the [`Section`] is collapsed.
+    ///
+    /// **Note**: This is `true` as soon as a collapse starts animating.
+    pub fn is_collapsed(&self) -> bool {
+        self.state.lock().anim.borrow().collapsed
+    }
+
+    /// Add a callback to be called when the [`Section`] is collapsed or expanded.
+    ///
+    /// The callback is called with `true` when collapsed.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_toggle<F>(&self, on_toggle: F)
+    where
+        F: FnMut(&Arc<Section>, bool) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_toggle
+            .borrow_mut()
+            .push(Box::new(on_toggle));
+    }
+
+    /// Set if this [`Section`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`Section`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`Section`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`Section`] and re-apply its styling.
+    ///
+    /// **Note**: Widgets within the content keep their own theme. The theme is only used for
+    /// widgets built within this [`Section`] afterwards.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::Section`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
+    /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
+    pub fn default_placement(theme: &Theme) -> WidgetPlacement {
+        WidgetPlacement {
+            position: Position::Floating,
+            margin_t: Pixels(theme.spacing),
+            margin_b: Pixels(theme.spacing),
+            margin_l: Pixels(theme.spacing),
+            margin_r: Pixels(theme.spacing),
+            width: PctOffset(100.0, -2.0 * theme.spacing),
+            height: Pixels(Self::header_height(theme)),
+            ..Default::default()
+        }
+    }
+
+    fn header_colors(&self) -> BtnHookColors {
+        let theme = self.theme.get();
+
+        BtnHookColors {
+            back_clr: Some(theme.colors.back3),
+            h_back_clr: Some(theme.colors.back4),
+            p_back_clr: Some(theme.colors.back4),
+            ..Default::default()
+        }
+    }
+
+    fn header_height(theme: &Theme) -> f32 {
+        theme.spacing + theme.base_size
+    }
+
+    fn set_collapsed(self: &Arc<Self>, collapsed: bool) {
+        let state = self.state.lock();
+
+        {
+            let mut anim = state.anim.borrow_mut();
+
+            if anim.collapsed == collapsed {
+                return;
+            }
+
+            anim.collapsed = collapsed;
+        }
+
+        if !collapsed {
+            self.content.style_modify(|style| {
+                style.visibility = Visibility::Inheirt;
+            });
+        }
+
+        self.check_hook();
+
+        if let Ok(mut on_toggle_cbs) = state.on_toggle.try_borrow_mut() {
+            for on_toggle in on_toggle_cbs.iter_mut() {
+                on_toggle(self, collapsed);
+            }
+        }
+    }
+
+    fn check_hook(self: &Arc<Self>) {
+        let state = self.state.lock();
+        let mut anim = state.anim.borrow_mut();
+
+        if anim.hooked {
+            return;
+        }
+
+        let window = match self.container.window() {
+            Some(some) => some,
+            None => return,
+        };
+
+        anim.hooked = true;
+        drop(anim);
+        let section_wk = Arc::downgrade(self);
+        let duration = self.props.animation_duration.as_micros().max(1) as f32;
+
+        window.renderer_on_frame(move |elapsed_op| {
+            let section = match section_wk.upgrade() {
+                Some(some) => some,
+                None => return false,
+            };
+
+            let state = section.state.lock();
+            let mut anim = state.anim.borrow_mut();
+            let target = if anim.collapsed { 0.0 } else { 1.0 };
+
+            if let Some(elapsed) = elapsed_op {
+                let step = elapsed.as_micros() as f32 / duration;
+
+                anim.progress = if target > anim.progress {
+                    (anim.progress + step).min(target)
+                } else {
+                    (anim.progress - step).max(target)
+                };
+            }
+
+            let finished = anim.progress == target;
+
+            if finished {
+                anim.hooked = false;
+            }
+
+            let progress = anim.progress;
+            drop(anim);
+            section.apply_progress(progress, finished && target == 0.0);
+            !finished
+        });
+    }
+
+    fn apply_progress(&self, progress: f32, hide_content: bool) {
+        let height =
+            Self::header_height(&self.theme.get()) + (self.props.content_height * progress);
+        let mut container_style = self.container.style_copy();
+        container_style.height = Pixels(height);
+        let mut header_style = self.header.style_copy();
+        header_style.user_vertexes = self.chevron_verts(progress);
+        let mut style_update_batch = vec![
+            (&self.container, container_style),
+            (&self.header, header_style),
+        ];
+
+        if hide_content {
+            let mut content_style = self.content.style_copy();
+            content_style.visibility = Visibility::Hide;
+            style_update_batch.push((&self.content, content_style));
+        }
+
+        Bin::style_update_batch(style_update_batch);
+    }
+
+    fn chevron_verts(&self, progress: f32) -> Vec<(ImageKey, Vec<BinVertex>)> {
+        // A right-pointing chevron that rotates to point down as the section expands.
+        let angle = FRAC_PI_2 * progress;
+        let color = self.theme.get().colors.text1a;
+        let [sin, cos] = [angle.sin(), angle.cos()];
+
+        let vertexes = [[37.5, 25.0], [37.5, 75.0], [62.5, 50.0]]
+            .into_iter()
+            .map(|[x, y]: [f32; 2]| {
+                let [x, y] = [x - 50.0, y - 50.0];
+
+                BinVertex {
+                    x: PctOfHeight(50.0 + (x * cos) - (y * sin)),
+                    y: Percent(50.0 + (x * sin) + (y * cos)),
+                    color,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        vec![(ImageKey::INVALID, vertexes)]
+    }
+
+    fn style_update(&self) {
+        let state = self.state.lock();
+        let anim = state.anim.borrow();
+        let header_height = Self::header_height(&self.theme.get());
+        let border_size = self.theme.get().border.unwrap_or(0.0);
+
+        let container_style = BinStyle {
+            height: Pixels(header_height + (self.props.content_height * anim.progress)),
+            visibility: self.container.style_inspect(|style| style.visibility),
+            ..self.props.placement.clone().into_style()
+        };
+
+        let mut header_style = BinStyle {
+            pos_from_t: Pixels(0.0),
+            pos_from_l: Pixels(0.0),
+            pos_from_r: Pixels(0.0),
+            height: Pixels(header_height),
+            padding_l: PctOfHeight(100.0),
+            padding_r: Pixels(self.theme.get().spacing),
+            back_color: self.theme.get().colors.back3,
+            user_vertexes: self.chevron_verts(anim.progress),
+            text_body: TextBody {
+                hori_align: TextHoriAlign::Left,
+                vert_align: TextVertAlign::Center,
+                text_wrap: TextWrap::None,
+                base_attrs: TextAttrs {
+                    height: Pixels(self.theme.get().text_height),
+                    color: self.theme.get().colors.text1a,
+                    font_family: self.theme.get().font_family.clone(),
+                    font_weight: self.theme.get().label_font_weight(),
+                    ..Default::default()
+                },
+                ..TextBody::from(self.props.title.clone())
+            },
+            ..Default::default()
+        };
+
+        let content_style = BinStyle {
+            pos_from_t: Pixels(header_height + border_size),
+            pos_from_l: Pixels(0.0),
+            pos_from_r: Pixels(0.0),
+            height: Pixels(self.props.content_height),
+            visibility: if anim.collapsed && anim.progress == 0.0 {
+                Visibility::Hide
+            } else {
+                Visibility::Inheirt
+            },
+            ..Default::default()
+        };
+
+        if let Some(border_size) = self.theme.get().border {
+            header_style.border_size_t = Pixels(border_size);
+            header_style.border_size_b = Pixels(border_size);
+            header_style.border_size_l = Pixels(border_size);
+            header_style.border_size_r = Pixels(border_size);
+            header_style.border_color_t = self.theme.get().colors.border1;
+            header_style.border_color_b = self.theme.get().colors.border1;
+            header_style.border_color_l = self.theme.get().colors.border1;
+            header_style.border_color_r = self.theme.get().colors.border1;
+        }
+
+        if let Some(border_radius) = self.theme.get().roundness {
+            header_style.border_radius_tl = Pixels(border_radius);
+            header_style.border_radius_tr = Pixels(border_radius);
+            header_style.border_radius_bl = Pixels(border_radius);
+            header_style.border_radius_br = Pixels(border_radius);
+        }
+
+        Bin::style_update_batch([
+            (&self.container, container_style),
+            (&self.header, header_style),
+            (&self.content, content_style),
+        ]);
+    }
+}
+
+impl WidgetContainer for Arc<Section> {
+    fn container_bin(&self) -> &Arc<Bin> {
+        &self.content
+    }
+
+    fn default_theme(&self) -> Theme {
+        self.theme.get().clone()
+    }
+}
+
+impl Widget for Section {
+    fn focus(&self) {}
+
+    fn blur(&self) {}
+
+    fn is_visible(&self) -> bool {
+        Section::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        Section::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        Section::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        Section::set_theme(self, theme)
+    }
+}
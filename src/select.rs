@@ -1,40 +1,54 @@
 use std::cell::RefCell;
 use std::collections::BTreeMap;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 use basalt::image::ImageKey;
 use basalt::input::{MouseButton, Qwerty};
-use basalt::interface::UnitValue::{PctOfHeight, PctOffset, Pixels};
+use basalt::interface::UnitValue::{self, PctOfHeight, PctOffset, Pixels};
 use basalt::interface::{
-    Bin, BinStyle, Position, TextAttrs, TextBody, TextHoriAlign, TextVertAlign, TextWrap,
-    Visibility, ZIndex,
+    Bin, BinStyle, BinVertex, FontFamily, FontWeight, Position, TextAttrs, TextBody, TextHoriAlign,
+    TextVertAlign, TextWrap, Visibility, ZIndex,
 };
 use parking_lot::ReentrantMutex;
 
 use crate::builder::WidgetBuilder;
+use crate::error::BuildError;
 use crate::scroll_bar::down_symbol_verts;
-use crate::{ScrollBar, Theme, WidgetContainer, WidgetPlacement};
+use crate::theme::ThemeCell;
+use crate::{ScrollBar, ScrollSource, Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
 
 /// Builder for [`Select`]
 pub struct SelectBuilder<'a, C, I> {
     widget: WidgetBuilder<'a, C>,
     props: Properties,
     select: Option<I>,
-    options: BTreeMap<I, String>,
+    options: BTreeMap<I, (String, Option<Vec<BinVertex>>)>,
     on_select: Vec<Box<dyn FnMut(&Arc<Select<I>>, Option<I>) + Send + 'static>>,
+    on_open: Vec<Box<dyn FnMut(&Arc<Select<I>>) + Send + 'static>>,
+    on_close: Vec<Box<dyn FnMut(&Arc<Select<I>>) + Send + 'static>>,
 }
 
 struct Properties {
     no_selection_label: String,
+    empty_text: String,
     drop_down_items: usize,
+    option_height: f32,
+    option_align: TextHoriAlign,
+    font: Option<(FontFamily, FontWeight)>,
+    disabled: bool,
     placement: WidgetPlacement,
 }
 
 impl Properties {
-    fn new(placement: WidgetPlacement) -> Self {
+    fn new(placement: WidgetPlacement, theme: &Theme) -> Self {
         Self {
             no_selection_label: String::new(),
+            empty_text: String::from("No options"),
             drop_down_items: 3,
+            option_height: theme.spacing + theme.base_size,
+            option_align: TextHoriAlign::Left,
+            font: None,
+            disabled: false,
             placement,
         }
     }
@@ -43,20 +57,20 @@ impl Properties {
 impl<'a, C, I> SelectBuilder<'a, C, I>
 where
     C: WidgetContainer,
-    I: Ord + Copy + Send + 'static,
+    I: Ord + Clone + Send + 'static,
 {
     pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
         Self {
             props: Properties::new(
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| Select::<()>::default_placement(&builder.theme)),
+                builder.take_placement(Select::<()>::default_placement),
+                &builder.theme,
             ),
             widget: builder,
             select: None,
             options: BTreeMap::new(),
             on_select: Vec::new(),
+            on_open: Vec::new(),
+            on_close: Vec::new(),
         }
     }
 
@@ -68,7 +82,25 @@ where
     where
         L: Into<String>,
     {
-        self.options.insert(option_id, label.into());
+        self.options.insert(option_id, (label.into(), None));
+        self
+    }
+
+    /// Add an option with the provided id, label and icon.
+    ///
+    /// The icon is displayed in a box before the label, both within the drop down and when the
+    /// option is selected.
+    ///
+    /// **Notes**:
+    /// - The vertexes of the icon are relative to the top-left of a square box of the theme's
+    /// `base_size`, so their `x` and `y` must be [`Pixels`](basalt::interface::UnitValue::Pixels).
+    /// - Ids must be unique. Adding an option of the same id as a previously added id will
+    /// overwrite the existing option.
+    pub fn add_option_with_icon<L>(mut self, option_id: I, label: L, icon: Vec<BinVertex>) -> Self
+    where
+        L: Into<String>,
+    {
+        self.options.insert(option_id, (label.into(), Some(icon)));
         self
     }
 
@@ -89,14 +121,59 @@ where
         self
     }
 
+    /// Set the text displayed within the drop down when there are no options.
+    ///
+    /// **Note**: This defaults to `"No options"`.
+    pub fn empty_text<T>(mut self, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.props.empty_text = text.into();
+        self
+    }
+
     /// Set the number of options to be displayed within the drop down.
     ///
-    /// **Note**: If there are more options than what is specified to be displayed they'll be scrollable.
+    /// **Notes**:
+    /// - If there are more options than what is specified to be displayed they'll be scrollable.
+    /// - If there are fewer options, the drop down will shrink to fit them.
     pub fn drop_down_items(mut self, count: usize) -> Self {
         self.props.drop_down_items = count;
         self
     }
 
+    /// Set the height of each option within the drop down.
+    ///
+    /// **Note**: This defaults to the theme's `spacing` + `base_size`.
+    pub fn option_height(mut self, height: f32) -> Self {
+        self.props.option_height = height;
+        self
+    }
+
+    /// Set the horizontal alignment of the text of each option within the drop down.
+    ///
+    /// **Note**: This defaults to [`TextHoriAlign::Left`].
+    pub fn option_align(mut self, align: TextHoriAlign) -> Self {
+        self.props.option_align = align;
+        self
+    }
+
+    /// Set the font used, overriding the font of the theme.
+    ///
+    /// **Note**: When this isn't used the theme's font is used.
+    pub fn font(mut self, family: FontFamily, weight: FontWeight) -> Self {
+        self.props.font = Some((family, weight));
+        self
+    }
+
+    /// Set if the [`Select`] is disabled.
+    ///
+    /// **Note**: When this isn't used the [`Select`] will be enabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.props.disabled = disabled;
+        self
+    }
+
     /// Add a callback to be called when the selection changed.
     ///
     /// **Note**: When changing the state within the callback, no callbacks on this [`Select`]
@@ -111,20 +188,56 @@ where
         self
     }
 
+    /// Add a callback to be called when the drop down is opened.
+    ///
+    /// The callback is called before the drop down is displayed, so options added within the
+    /// callback will be displayed.
+    ///
+    /// **Panics**: When adding a callback within the callback to this [`Select`].
+    pub fn on_open<F>(mut self, on_open: F) -> Self
+    where
+        F: FnMut(&Arc<Select<I>>) + Send + 'static,
+    {
+        self.on_open.push(Box::new(on_open));
+        self
+    }
+
+    /// Add a callback to be called when the drop down is closed.
+    ///
+    /// **Note**: This is called for any reason the drop down closes, such as a selection being
+    /// made, escape being pressed or focus being lost.
+    ///
+    /// **Panics**: When adding a callback within the callback to this [`Select`].
+    pub fn on_close<F>(mut self, on_close: F) -> Self
+    where
+        F: FnMut(&Arc<Select<I>>) + Send + 'static,
+    {
+        self.on_close.push(Box::new(on_close));
+        self
+    }
+
     /// Finish building the [`Select`].
-    pub fn build(self) -> Arc<Select<I>> {
+    pub fn build(self) -> Result<Arc<Select<I>>, BuildError> {
         let window = self
             .widget
             .container
             .container_bin()
             .window()
-            .expect("The widget container must have an associated window.");
+            .ok_or(BuildError::NoWindow)?;
 
-        let mut new_bins = window.new_bins(4 + self.options.len()).into_iter();
+        let num_bins = 5 + self.options.len();
+        let new_bins = window.new_bins(num_bins);
+
+        if new_bins.len() != num_bins {
+            return Err(BuildError::BinAllocationFailed);
+        }
+
+        let mut new_bins = new_bins.into_iter();
         let container = new_bins.next().unwrap();
         let popup = new_bins.next().unwrap();
         let arrow_down = new_bins.next().unwrap();
         let option_list = new_bins.next().unwrap();
+        let empty_placeholder = new_bins.next().unwrap();
 
         self.widget
             .container
@@ -134,21 +247,18 @@ where
         container.add_child(arrow_down.clone());
         container.add_child(popup.clone());
         popup.add_child(option_list.clone());
+        option_list.add_child(empty_placeholder.clone());
 
         let scroll_bar = popup
             .create_widget()
             .with_theme(self.widget.theme.clone())
             .scroll_bar(option_list.clone())
-            .step(
-                self.widget.theme.spacing
-                    + self.widget.theme.base_size
-                    + self.widget.theme.border.unwrap_or(0.0),
-            )
+            .step(self.props.option_height + self.widget.theme.border.unwrap_or(0.0))
             .build();
 
         let select_id = match self.select {
             Some(select_id) => {
-                if self.options.keys().find(|id| **id == select_id).is_some() {
+                if self.options.contains_key(&select_id) {
                     Some(select_id)
                 } else {
                     None
@@ -158,36 +268,49 @@ where
         };
 
         let options_state = RefCell::new(BTreeMap::from_iter(self.options.into_iter().map(
-            |(id, label)| {
+            |(id, (label, icon))| {
                 let bin = new_bins.next().unwrap();
                 option_list.add_child(bin.clone());
                 (
                     id,
                     OptionState {
                         label,
+                        icon,
                         bin,
                     },
                 )
             },
         )));
 
-        let select = Arc::new(Select {
-            theme: self.widget.theme,
-            props: self.props,
-            container,
-            popup,
-            arrow_down,
-            scroll_bar,
-            option_list,
-            state: ReentrantMutex::new(State {
-                select: RefCell::new(select_id),
-                options: options_state,
-                on_select: RefCell::new(self.on_select),
-                popup: RefCell::new(PopupState {
-                    visible: false,
-                    select_i: None,
+        let disabled = self.props.disabled;
+
+        let select = Arc::new_cyclic(|this| {
+            Select {
+                this: this.clone(),
+                theme: ThemeCell::new(self.widget.theme),
+                name: self.widget.name,
+                aria_label: self.widget.aria_label,
+                role: self.widget.role.unwrap_or(WidgetRole::ListBox),
+                props: self.props,
+                container,
+                popup,
+                arrow_down,
+                scroll_bar,
+                option_list,
+                empty_placeholder,
+                state: ReentrantMutex::new(State {
+                    select: RefCell::new(select_id),
+                    disabled: RefCell::new(disabled),
+                    options: options_state,
+                    on_select: RefCell::new(self.on_select),
+                    on_open: RefCell::new(self.on_open),
+                    on_close: RefCell::new(self.on_close),
+                    popup: RefCell::new(PopupState {
+                        visible: false,
+                        select_i: None,
+                    }),
                 }),
-            }),
+            }
         });
 
         for target in [&select.container, &select.arrow_down] {
@@ -251,6 +374,7 @@ where
                             || bin_id == cb_select.popup.id()
                             || bin_id == cb_select.arrow_down.id()
                             || bin_id == cb_select.option_list.id()
+                            || bin_id == cb_select.empty_placeholder.id()
                             || cb_select.scroll_bar.has_bin_id(bin_id)
                     },
                     None => false,
@@ -275,36 +399,46 @@ where
             .borrow()
             .iter()
             .for_each(|(id, option_state)| {
-                select.add_option_select_hook(*id, &option_state.bin);
+                select.add_option_select_hook(id.clone(), &option_state.bin);
             });
 
         select.style_update();
         select.rebuild_list();
-        select
+        self.widget.container.widget_built(select.clone());
+        Ok(select)
     }
 }
 
 /// Select widget
 pub struct Select<I> {
-    theme: Theme,
+    this: Weak<Select<I>>,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     container: Arc<Bin>,
     popup: Arc<Bin>,
     arrow_down: Arc<Bin>,
     scroll_bar: Arc<ScrollBar>,
     option_list: Arc<Bin>,
+    empty_placeholder: Arc<Bin>,
     state: ReentrantMutex<State<I>>,
 }
 
 struct State<I> {
     select: RefCell<Option<I>>,
+    disabled: RefCell<bool>,
     options: RefCell<BTreeMap<I, OptionState>>,
     on_select: RefCell<Vec<Box<dyn FnMut(&Arc<Select<I>>, Option<I>) + Send + 'static>>>,
+    on_open: RefCell<Vec<Box<dyn FnMut(&Arc<Select<I>>) + Send + 'static>>>,
+    on_close: RefCell<Vec<Box<dyn FnMut(&Arc<Select<I>>) + Send + 'static>>>,
     popup: RefCell<PopupState>,
 }
 
 struct OptionState {
     label: String,
+    icon: Option<Vec<BinVertex>>,
     bin: Arc<Bin>,
 }
 
@@ -315,7 +449,7 @@ struct PopupState {
 
 impl<I> Select<I>
 where
-    I: Ord + Copy + Send + 'static,
+    I: Ord + Clone + Send + 'static,
 {
     /// Set the currently selected id.
     ///
@@ -329,6 +463,33 @@ where
         self.select_inner(None);
     }
 
+    /// Set if this [`Select`] is disabled.
+    ///
+    /// While disabled the drop down can't be opened and the [`Select`] is displayed muted.
+    ///
+    /// **Notes**:
+    /// - Disabling the [`Select`] also closes its drop down.
+    /// - The selection can still be changed with [`select`](Select::select).
+    pub fn set_disabled(self: &Arc<Self>, disabled: bool) {
+        let state = self.state.lock();
+
+        if *state.disabled.borrow() == disabled {
+            return;
+        }
+
+        if disabled {
+            self.hide_popup();
+        }
+
+        *state.disabled.borrow_mut() = disabled;
+        self.style_update();
+    }
+
+    /// Check if this [`Select`] is disabled.
+    pub fn is_disabled(&self) -> bool {
+        *self.state.lock().disabled.borrow()
+    }
+
     /// Add an option with the provided id and label.
     ///
     /// **Note**: Ids must be unique. Adding an option of the same id as a previously added id will
@@ -337,17 +498,37 @@ where
     where
         L: Into<String>,
     {
+        self.add_option_inner(option_id, label.into(), None);
+    }
+
+    /// Add an option with the provided id, label and icon.
+    ///
+    /// **Note**: See [`SelectBuilder::add_option_with_icon`] for how the icon is displayed.
+    pub fn add_option_with_icon<L>(self: &Arc<Self>, option_id: I, label: L, icon: Vec<BinVertex>)
+    where
+        L: Into<String>,
+    {
+        self.add_option_inner(option_id, label.into(), Some(icon));
+    }
+
+    fn add_option_inner(
+        self: &Arc<Self>,
+        option_id: I,
+        label: String,
+        icon: Option<Vec<BinVertex>>,
+    ) {
         let bin = self.container.window().unwrap().new_bin();
         let state = self.state.lock();
 
         {
             let mut options = state.options.borrow_mut();
-            self.add_option_select_hook(option_id, &bin);
+            self.add_option_select_hook(option_id.clone(), &bin);
 
             options.insert(
                 option_id,
                 OptionState {
-                    label: label.into(),
+                    label,
+                    icon,
                     bin,
                 },
             );
@@ -356,13 +537,86 @@ where
         self.rebuild_list();
     }
 
+    /// Replace all of the options with the provided ids and labels.
+    ///
+    /// This is more efficient than removing and adding options individually as the list is only
+    /// rebuilt once.
+    ///
+    /// **Notes**:
+    /// - If the currently selected id is still present, it will remain selected. Otherwise the
+    /// selection will be cleared.
+    /// - Like [`add_option`](`Select::add_option`), a later option with the same id as an earlier
+    /// one will overwrite it.
+    pub fn set_options<O, L>(self: &Arc<Self>, options: O)
+    where
+        O: IntoIterator<Item = (I, L)>,
+        L: Into<String>,
+    {
+        let options = options
+            .into_iter()
+            .map(|(id, label)| (id, label.into()))
+            .collect::<BTreeMap<I, String>>();
+
+        let state = self.state.lock();
+        let window = self.container.window().unwrap();
+        let bins = window.new_bins(options.len());
+
+        let new_options = options
+            .into_iter()
+            .zip(bins)
+            .map(|((id, label), bin)| {
+                self.option_list.add_child(bin.clone());
+                self.add_option_select_hook(id.clone(), &bin);
+
+                (
+                    id,
+                    OptionState {
+                        label,
+                        icon: None,
+                        bin,
+                    },
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let num_options = new_options.len();
+        // The previous option bins are dropped here, removing them.
+        *state.options.borrow_mut() = new_options;
+
+        {
+            let mut popup_state = state.popup.borrow_mut();
+
+            if popup_state.select_i.is_some_and(|i| i >= num_options) {
+                popup_state.select_i = None;
+            }
+        }
+
+        let select_id = state.select.borrow().clone();
+
+        if let Some(select_id) = select_id {
+            let selected = state
+                .options
+                .borrow()
+                .get(&select_id)
+                .map(|option_state| (option_state.label.clone(), option_state.icon.clone()));
+
+            match selected {
+                Some((label, icon)) => self.set_container_label(label, icon),
+                None => self.clear_selection(),
+            }
+        }
+
+        self.rebuild_list();
+        self.scroll_bar.refresh();
+    }
+
     /// Same as [`add_option`](`Select::add_option`), but selects the newly added option after it has been added.
     pub fn add_option_selected<L>(self: &Arc<Self>, option_id: I, label: L)
     where
         L: Into<String>,
     {
         let _state = self.state.lock();
-        self.add_option(option_id, label);
+        self.add_option(option_id.clone(), label);
         self.select_inner(Some(option_id));
     }
 
@@ -377,9 +631,9 @@ where
         let select = state.select.borrow();
 
         if options.remove(&option_id).is_some() {
-            let clear_selection = match *select {
+            let clear_selection = match select.as_ref() {
                 Some(select_id) => {
-                    if select_id == option_id {
+                    if *select_id == option_id {
                         true
                     } else {
                         false
@@ -419,23 +673,57 @@ where
             .push(Box::new(on_select));
     }
 
+    /// Add a callback to be called when the drop down is opened.
+    ///
+    /// The callback is called before the drop down is displayed, so options added within the
+    /// callback will be displayed.
+    ///
+    /// **Panics**: When adding a callback within the callback to this [`Select`].
+    pub fn on_open<F>(&self, on_open: F)
+    where
+        F: FnMut(&Arc<Select<I>>) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_open
+            .borrow_mut()
+            .push(Box::new(on_open));
+    }
+
+    /// Add a callback to be called when the drop down is closed.
+    ///
+    /// **Note**: This is called for any reason the drop down closes, such as a selection being
+    /// made, escape being pressed or focus being lost.
+    ///
+    /// **Panics**: When adding a callback within the callback to this [`Select`].
+    pub fn on_close<F>(&self, on_close: F)
+    where
+        F: FnMut(&Arc<Select<I>>) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_close
+            .borrow_mut()
+            .push(Box::new(on_close));
+    }
+
     fn select_inner(self: &Arc<Self>, option_id_op: Option<I>) {
         let state = self.state.lock();
 
-        let label = {
+        let (label, icon) = {
             let mut select = state.select.borrow_mut();
             let options = state.options.borrow();
 
-            match option_id_op {
+            match option_id_op.as_ref() {
                 Some(option_id) => {
-                    match options.get(&option_id) {
+                    match options.get(option_id) {
                         Some(option_state) => {
-                            if select.is_some() && select.unwrap() == option_id {
+                            if select.as_ref() == Some(option_id) {
                                 return;
                             }
 
-                            *select = option_id_op;
-                            option_state.label.clone()
+                            *select = option_id_op.clone();
+                            (option_state.label.clone(), option_state.icon.clone())
                         },
                         None => {
                             if select.is_none() {
@@ -443,7 +731,7 @@ where
                             }
 
                             *select = None;
-                            self.props.no_selection_label.clone()
+                            (self.props.no_selection_label.clone(), None)
                         },
                     }
                 },
@@ -453,32 +741,41 @@ where
                     }
 
                     *select = None;
-                    self.props.no_selection_label.clone()
+                    (self.props.no_selection_label.clone(), None)
                 },
             }
         };
 
-        self.container.style_modify(move |style| {
-            style.text_body.spans[0].text = label.clone(); // TODO: Why Clone???
-        });
+        self.set_container_label(label, icon);
 
         if let Ok(mut callbacks) = state.on_select.try_borrow_mut() {
             for callback in callbacks.iter_mut() {
-                callback(self, option_id_op);
+                callback(self, option_id_op.clone());
             }
         }
     }
 
+    fn set_container_label(&self, label: String, icon: Option<Vec<BinVertex>>) {
+        let padding_l = self.label_padding(icon.is_some());
+        let user_vertexes = self.icon_vertexes(icon.as_ref());
+
+        self.container.style_modify(move |style| {
+            style.text_body.spans[0].text = label.clone(); // TODO: Why Clone???
+            style.padding_l = padding_l;
+            style.user_vertexes = user_vertexes.clone();
+        });
+    }
+
     fn add_option_select_hook(self: &Arc<Self>, id: I, bin: &Arc<Bin>) {
         let cb_select = self.clone();
 
         bin.on_press(MouseButton::Left, move |_, _, _| {
-            cb_select.select(id);
+            cb_select.select(id.clone());
             Default::default()
         });
     }
 
-    fn toggle_popup(&self) {
+    fn toggle_popup(self: &Arc<Self>) {
         let state = self.state.lock();
 
         if state.popup.borrow().visible {
@@ -488,8 +785,19 @@ where
         }
     }
 
-    fn show_popup(&self) {
+    fn show_popup(self: &Arc<Self>) {
         let state = self.state.lock();
+
+        if state.popup.borrow().visible || *state.disabled.borrow() {
+            return;
+        }
+
+        if let Ok(mut on_open_cbs) = state.on_open.try_borrow_mut() {
+            for on_open in on_open_cbs.iter_mut() {
+                on_open(self);
+            }
+        }
+
         let select = state.select.borrow();
         let options = state.options.borrow();
         let mut popup_state = state.popup.borrow_mut();
@@ -499,16 +807,16 @@ where
         popup_style.visibility = Visibility::Inheirt;
         style_update_batch.push((&self.popup, popup_style));
 
-        if self.theme.roundness.is_some() {
+        if self.theme.get().roundness.is_some() {
             let mut container_style = self.container.style_copy();
             container_style.border_radius_bl = Default::default();
             container_style.border_radius_br = Default::default();
             style_update_batch.push((&self.container, container_style));
         }
 
-        let index = match *select {
+        let index = match select.as_ref() {
             Some(sel_id) => {
-                match options.keys().enumerate().find(|(_, id)| **id == sel_id) {
+                match options.keys().enumerate().find(|(_, id)| *id == sel_id) {
                     Some(some) => Some(some.0),
                     None => None,
                 }
@@ -521,9 +829,12 @@ where
 
         for (i, option_state) in options.values().enumerate() {
             let [back_color, text_color] = if index.is_some() && i == index.unwrap() {
-                [self.theme.colors.accent1, self.theme.colors.text1b]
+                [
+                    self.theme.get().colors.accent1,
+                    self.theme.get().colors.text1b,
+                ]
             } else {
-                [Default::default(), self.theme.colors.text1a]
+                [Default::default(), self.theme.get().colors.text1a]
             };
 
             if let Some(mut option_style) = option_state.bin.style_inspect(|style| {
@@ -540,20 +851,24 @@ where
             }
         }
 
-        self.popup_jump_to(index.unwrap_or(0));
+        self.popup_jump_to(index.unwrap_or(0), ScrollSource::Programmatic);
         Bin::style_update_batch(style_update_batch);
     }
 
-    fn hide_popup(&self) {
+    fn hide_popup(self: &Arc<Self>) {
         let state = self.state.lock();
         let mut popup_state = state.popup.borrow_mut();
 
+        if !popup_state.visible {
+            return;
+        }
+
         let mut style_update_batch = Vec::new();
         let mut popup_style = self.popup.style_copy();
         popup_style.visibility = Visibility::Hide;
         style_update_batch.push((&self.popup, popup_style));
 
-        if let Some(border_radius) = self.theme.roundness {
+        if let Some(border_radius) = self.theme.get().roundness {
             let mut container_style = self.container.style_copy();
             container_style.border_radius_bl = Pixels(border_radius);
             container_style.border_radius_br = Pixels(border_radius);
@@ -562,17 +877,24 @@ where
 
         popup_state.visible = false;
         Bin::style_update_batch(style_update_batch);
+        drop(popup_state);
+
+        if let Ok(mut on_close_cbs) = state.on_close.try_borrow_mut() {
+            for on_close in on_close_cbs.iter_mut() {
+                on_close(self);
+            }
+        }
     }
 
-    fn popup_jump_to(&self, index: usize) {
+    fn popup_jump_to(&self, index: usize, source: ScrollSource) {
         let jump_index = index
             .checked_sub(self.props.drop_down_items / 3)
             .unwrap_or(0);
 
-        let jump_to = jump_index as f32
-            * (self.theme.base_size + self.theme.spacing + self.theme.border.unwrap_or(0.0));
+        let jump_to =
+            jump_index as f32 * (self.props.option_height + self.theme.get().border.unwrap_or(0.0));
 
-        self.scroll_bar.jump_to(jump_to);
+        self.scroll_bar.jump_to_from(jump_to, source);
     }
 
     fn popup_select_prev(&self) {
@@ -601,6 +923,11 @@ where
         let state = self.state.lock();
         let options = state.options.borrow();
         let mut popup = state.popup.borrow_mut();
+
+        if *state.disabled.borrow() || options.is_empty() {
+            return;
+        }
+
         index = index.min(options.len().checked_sub(1).unwrap_or(0));
 
         if popup.select_i.is_some() && index == popup.select_i.unwrap() {
@@ -613,12 +940,12 @@ where
             if popup.select_i.is_some() && i == popup.select_i.unwrap() {
                 let mut option_style = option_state.bin.style_copy();
                 option_style.back_color = Default::default();
-                option_style.text_body.base_attrs.color = self.theme.colors.text1a;
+                option_style.text_body.base_attrs.color = self.theme.get().colors.text1a;
                 style_update_batch.push((&option_state.bin, option_style));
             } else if i == index {
                 let mut option_style = option_state.bin.style_copy();
-                option_style.back_color = self.theme.colors.accent1;
-                option_style.text_body.base_attrs.color = self.theme.colors.text1b;
+                option_style.back_color = self.theme.get().colors.accent1;
+                option_style.text_body.base_attrs.color = self.theme.get().colors.text1b;
                 style_update_batch.push((&option_state.bin, option_style));
             }
 
@@ -629,7 +956,7 @@ where
 
         popup.select_i = Some(index);
         Bin::style_update_batch(style_update_batch);
-        self.popup_jump_to(index);
+        self.popup_jump_to(index, ScrollSource::Keyboard);
     }
 
     fn popup_finish(self: &Arc<Self>, esc: bool) {
@@ -638,7 +965,7 @@ where
 
         if !esc {
             let option_id_op = match state.popup.borrow().select_i {
-                Some(select_i) => state.options.borrow().keys().skip(select_i).next().copied(),
+                Some(select_i) => state.options.borrow().keys().skip(select_i).next().cloned(),
                 None => None,
             };
 
@@ -646,79 +973,194 @@ where
         }
     }
 
+    fn popup_rows(&self, num_options: usize) -> usize {
+        popup_rows(self.props.drop_down_items, num_options)
+    }
+
+    fn popup_height(&self, num_options: usize) -> f32 {
+        popup_height(
+            self.popup_rows(num_options),
+            self.props.option_height,
+            self.theme.get().border.unwrap_or(0.0),
+        )
+    }
+
+    fn font(&self) -> (FontFamily, FontWeight) {
+        self.props.font.clone().unwrap_or_else(|| {
+            (
+                self.theme.get().font_family.clone(),
+                self.theme.get().label_font_weight(),
+            )
+        })
+    }
+
+    fn label_padding(&self, has_icon: bool) -> UnitValue {
+        if has_icon {
+            Pixels(self.theme.get().spacing + self.theme.get().base_size)
+        } else {
+            Pixels(self.theme.get().spacing)
+        }
+    }
+
+    fn icon_vertexes(&self, icon: Option<&Vec<BinVertex>>) -> Vec<(ImageKey, Vec<BinVertex>)> {
+        let icon = match icon {
+            Some(some) => some,
+            None => return Vec::new(),
+        };
+
+        // Place the icon's box before the label, centered vertically.
+        let box_l = self.theme.get().spacing / 2.0;
+        let box_t = self.theme.get().base_size / -2.0;
+
+        let vertexes = icon
+            .iter()
+            .map(|vertex| {
+                BinVertex {
+                    x: match vertex.x {
+                        Pixels(x) => Pixels(box_l + x),
+                        x => x,
+                    },
+                    y: match vertex.y {
+                        Pixels(y) => PctOffset(50.0, box_t + y),
+                        y => y,
+                    },
+                    ..vertex.clone()
+                }
+            })
+            .collect();
+
+        vec![(ImageKey::INVALID, vertexes)]
+    }
+
     fn rebuild_list(&self) {
+        let (font_family, font_weight) = self.font();
         let state = self.state.lock();
         let options = state.options.borrow();
         let num_options = options.len();
+        let scrollable = num_options > self.popup_rows(num_options);
+        let mut style_update_batch = Vec::with_capacity(num_options + 3);
 
-        if !options.is_empty() {
-            let mut styles = Vec::with_capacity(num_options);
-
-            for (i, option_state) in options.values().enumerate() {
-                let mut option_style = BinStyle {
-                    pos_from_t: Pixels(
-                        i as f32
-                            * (self.theme.spacing
-                                + self.theme.base_size
-                                + self.theme.border.unwrap_or(0.0)),
-                    ),
-                    pos_from_l: Pixels(0.0),
-                    pos_from_r: Pixels(0.0),
-                    height: Pixels(self.theme.spacing + self.theme.base_size),
-                    padding_l: Pixels(self.theme.spacing),
-                    padding_r: Pixels(self.theme.spacing),
-                    text_body: TextBody {
-                        hori_align: TextHoriAlign::Left,
-                        vert_align: TextVertAlign::Center,
-                        text_wrap: TextWrap::None,
-                        base_attrs: TextAttrs {
-                            height: Pixels(self.theme.text_height),
-                            color: self.theme.colors.text1a,
-                            font_family: self.theme.font_family.clone(),
-                            font_weight: self.theme.font_weight,
-                            ..Default::default()
-                        },
-                        ..TextBody::from(option_state.label.clone())
+        let mut popup_style = self.popup.style_copy();
+        popup_style.height = Pixels(self.popup_height(num_options));
+        style_update_batch.push((&self.popup, popup_style));
+
+        let mut option_list_style = self.option_list.style_copy();
+
+        option_list_style.pos_from_r = if scrollable {
+            Pixels(ScrollBar::size(&self.theme.get()))
+        } else {
+            Pixels(0.0)
+        };
+
+        style_update_batch.push((&self.option_list, option_list_style));
+        self.scroll_bar.set_visible(scrollable);
+
+        // The placeholder has no press hook, so it can't be selected.
+        let empty_placeholder_style = if num_options == 0 {
+            BinStyle {
+                pos_from_t: Pixels(0.0),
+                pos_from_l: Pixels(0.0),
+                pos_from_r: Pixels(0.0),
+                height: Pixels(self.props.option_height),
+                padding_l: Pixels(self.theme.get().spacing),
+                padding_r: Pixels(self.theme.get().spacing),
+                text_body: TextBody {
+                    hori_align: self.props.option_align,
+                    vert_align: TextVertAlign::Center,
+                    text_wrap: TextWrap::None,
+                    base_attrs: TextAttrs {
+                        height: Pixels(self.theme.get().text_height),
+                        color: self.theme.get().colors.border2,
+                        font_family: font_family.clone(),
+                        font_weight,
+                        ..Default::default()
                     },
-                    ..Default::default()
-                };
+                    ..TextBody::from(self.props.empty_text.clone())
+                },
+                ..Default::default()
+            }
+        } else {
+            BinStyle {
+                visibility: Visibility::Hide,
+                ..Default::default()
+            }
+        };
 
-                if i != num_options - 1 {
-                    if let Some(border_size) = self.theme.border {
-                        option_style.border_size_b = Pixels(border_size);
-                        option_style.border_color_b = self.theme.colors.border2;
-                    }
-                }
+        style_update_batch.push((&self.empty_placeholder, empty_placeholder_style));
 
-                styles.push(option_style);
+        for (i, option_state) in options.values().enumerate() {
+            let mut option_style = BinStyle {
+                pos_from_t: Pixels(
+                    i as f32 * (self.props.option_height + self.theme.get().border.unwrap_or(0.0)),
+                ),
+                pos_from_l: Pixels(0.0),
+                pos_from_r: Pixels(0.0),
+                height: Pixels(self.props.option_height),
+                padding_l: self.label_padding(option_state.icon.is_some()),
+                padding_r: Pixels(self.theme.get().spacing),
+                text_body: TextBody {
+                    hori_align: self.props.option_align,
+                    vert_align: TextVertAlign::Center,
+                    text_wrap: TextWrap::None,
+                    base_attrs: TextAttrs {
+                        height: Pixels(self.theme.get().text_height),
+                        color: self.theme.get().colors.text1a,
+                        font_family: font_family.clone(),
+                        font_weight,
+                        ..Default::default()
+                    },
+                    ..TextBody::from(option_state.label.clone())
+                },
+                user_vertexes: self.icon_vertexes(option_state.icon.as_ref()),
+                ..Default::default()
+            };
+
+            if i != num_options - 1 {
+                if let Some(border_size) = self.theme.get().border {
+                    option_style.border_size_b = Pixels(border_size);
+                    option_style.border_color_b = self.theme.get().colors.border2;
+                }
             }
 
-            Bin::style_update_batch(
-                options
-                    .values()
-                    .map(|option_state| &option_state.bin)
-                    .zip(styles),
-            );
+            style_update_batch.push((&option_state.bin, option_style));
         }
+
+        Bin::style_update_batch(style_update_batch);
     }
 
     fn style_update(&self) {
-        let border_size = self.theme.border.unwrap_or(0.0);
+        let border_size = self.theme.get().border.unwrap_or(0.0);
+        let (font_family, font_weight) = self.font();
+
+        let [back_color, border_color, arrow_color] = if self.is_disabled() {
+            [
+                self.theme.get().colors.back4,
+                self.theme.get().colors.border2,
+                self.theme.get().colors.border2,
+            ]
+        } else {
+            [
+                self.theme.get().colors.back3,
+                self.theme.get().colors.border1,
+                self.theme.get().colors.text1a,
+            ]
+        };
 
         let mut container_style = BinStyle {
-            padding_l: Pixels(self.theme.spacing),
+            padding_l: Pixels(self.theme.get().spacing),
             padding_r: PctOfHeight(100.0),
-            back_color: self.theme.colors.back3,
+            back_color,
+            visibility: self.container.style_inspect(|style| style.visibility),
             text_body: TextBody {
                 spans: vec![Default::default()],
                 hori_align: TextHoriAlign::Left,
                 vert_align: TextVertAlign::Center,
                 text_wrap: TextWrap::None,
                 base_attrs: TextAttrs {
-                    height: Pixels(self.theme.text_height),
-                    color: self.theme.colors.text1a,
-                    font_family: self.theme.font_family.clone(),
-                    font_weight: self.theme.font_weight,
+                    height: Pixels(self.theme.get().text_height),
+                    color: self.theme.get().colors.text1a,
+                    font_family,
+                    font_weight,
                     ..Default::default()
                 },
                 ..Default::default()
@@ -731,13 +1173,13 @@ where
             pos_from_b: Pixels(0.0),
             pos_from_r: Pixels(0.0),
             width: PctOfHeight(100.0),
-            user_vertexes: vec![(
-                ImageKey::INVALID,
-                down_symbol_verts(33.0, self.theme.colors.text1a),
-            )],
+            user_vertexes: vec![(ImageKey::INVALID, down_symbol_verts(33.0, arrow_color))],
             ..Default::default()
         };
 
+        let num_options = self.state.lock().options.borrow().len();
+        let scrollable = num_options > self.popup_rows(num_options);
+
         let mut popup_style = BinStyle {
             position: Position::Anchor,
             z_index: ZIndex::Offset(100),
@@ -745,57 +1187,57 @@ where
             pos_from_t: PctOffset(100.0, border_size),
             pos_from_l: Pixels(0.0),
             pos_from_r: Pixels(0.0),
-            height: PctOffset(
-                100.0 * self.props.drop_down_items as f32,
-                border_size * self.props.drop_down_items.checked_sub(1).unwrap_or(0) as f32,
-            ),
-            back_color: self.theme.colors.back2,
+            height: Pixels(self.popup_height(num_options)),
+            back_color: self.theme.get().colors.back2,
             ..Default::default()
         };
 
         let option_list_style = BinStyle {
             pos_from_t: Pixels(0.0),
             pos_from_l: Pixels(0.0),
-            pos_from_r: Pixels(ScrollBar::size(&self.theme)),
+            pos_from_r: if scrollable {
+                Pixels(ScrollBar::size(&self.theme.get()))
+            } else {
+                Pixels(0.0)
+            },
             pos_from_b: Pixels(0.0),
             ..Default::default()
         };
 
-        container_style.text_body.spans[0].text = {
+        {
             let state = self.state.lock();
             let select = state.select.borrow();
             let options = state.options.borrow();
 
-            match *select {
-                Some(select_id) => {
-                    match options.get(&select_id) {
-                        Some(option_state) => option_state.label.clone(),
-                        None => self.props.no_selection_label.clone(),
-                    }
-                },
-                None => self.props.no_selection_label.clone(),
-            }
-        };
+            let (label, icon) = match select.as_ref().and_then(|id| options.get(id)) {
+                Some(option_state) => (option_state.label.clone(), option_state.icon.as_ref()),
+                None => (self.props.no_selection_label.clone(), None),
+            };
 
-        if let Some(border_size) = self.theme.border {
+            container_style.text_body.spans[0].text = label;
+            container_style.padding_l = self.label_padding(icon.is_some());
+            container_style.user_vertexes = self.icon_vertexes(icon);
+        }
+
+        if let Some(border_size) = self.theme.get().border {
             container_style.border_size_t = Pixels(border_size);
             container_style.border_size_b = Pixels(border_size);
             container_style.border_size_l = Pixels(border_size);
             container_style.border_size_r = Pixels(border_size);
-            container_style.border_color_t = self.theme.colors.border1;
-            container_style.border_color_b = self.theme.colors.border1;
-            container_style.border_color_l = self.theme.colors.border1;
-            container_style.border_color_r = self.theme.colors.border1;
+            container_style.border_color_t = border_color;
+            container_style.border_color_b = border_color;
+            container_style.border_color_l = border_color;
+            container_style.border_color_r = border_color;
 
             popup_style.border_size_b = Pixels(border_size);
             popup_style.border_size_l = Pixels(border_size);
             popup_style.border_size_r = Pixels(border_size);
-            popup_style.border_color_b = self.theme.colors.border1;
-            popup_style.border_color_l = self.theme.colors.border1;
-            popup_style.border_color_r = self.theme.colors.border1;
+            popup_style.border_color_b = self.theme.get().colors.border1;
+            popup_style.border_color_l = self.theme.get().colors.border1;
+            popup_style.border_color_r = self.theme.get().colors.border1;
         }
 
-        if let Some(border_radius) = self.theme.roundness {
+        if let Some(border_radius) = self.theme.get().roundness {
             container_style.border_radius_tl = Pixels(border_radius);
             container_style.border_radius_tr = Pixels(border_radius);
             container_style.border_radius_bl = Pixels(border_radius);
@@ -815,6 +1257,79 @@ where
 }
 
 impl<I> Select<I> {
+    /// Give keyboard focus to this [`Select`].
+    pub fn focus(&self) {
+        crate::focus_bin(&self.container);
+    }
+
+    /// Remove keyboard focus from this [`Select`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`Select`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.container);
+    }
+
+    /// Set if this [`Select`] is visible.
+    ///
+    /// **Note**: Hiding the [`Select`] also closes its popup.
+    pub fn set_visible(self: &Arc<Self>, visible: bool) {
+        if !visible {
+            self.hide_popup();
+        }
+
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`Select`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`Select`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`Select`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.scroll_bar.set_theme(theme.clone());
+        self.theme.set(theme);
+        self.style_update();
+        self.rebuild_list();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::ListBox`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
     pub fn default_placement(theme: &Theme) -> WidgetPlacement {
         let height = theme.spacing + theme.base_size;
@@ -832,3 +1347,80 @@ impl<I> Select<I> {
         }
     }
 }
+
+impl<I> Widget for Select<I>
+where
+    I: Ord + Clone + Send + 'static,
+{
+    fn focus(&self) {
+        Select::focus(self)
+    }
+
+    fn blur(&self) {
+        Select::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        Select::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        if let Some(select) = self.this.upgrade() {
+            Select::set_visible(&select, visible);
+        }
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        Select::bounds(self)
+    }
+
+    fn is_disabled(&self) -> bool {
+        Select::is_disabled(self)
+    }
+
+    fn set_disabled(&self, disabled: bool) {
+        if let Some(select) = self.this.upgrade() {
+            Select::set_disabled(&select, disabled);
+        }
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        Select::set_theme(self, theme)
+    }
+}
+
+/// The number of rows the drop down displays for the number of options.
+pub(crate) fn popup_rows(drop_down_items: usize, num_options: usize) -> usize {
+    drop_down_items.min(num_options).max(1)
+}
+
+/// The height of the drop down when displaying the provided number of rows.
+pub(crate) fn popup_height(rows: usize, option_height: f32, border_size: f32) -> f32 {
+    (option_height * rows as f32) + (border_size * rows.saturating_sub(1) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn popup_fits_few_options() {
+        let rows = popup_rows(5, 2);
+        assert_eq!(rows, 2);
+        assert_eq!(popup_height(rows, 30.0, 1.0), 61.0);
+    }
+
+    #[test]
+    fn popup_scrolls_many_options() {
+        let rows = popup_rows(5, 8);
+        assert_eq!(rows, 5);
+        assert_eq!(popup_height(rows, 30.0, 1.0), 154.0);
+    }
+
+    #[test]
+    fn popup_without_options() {
+        let rows = popup_rows(5, 0);
+        assert_eq!(rows, 1);
+        assert_eq!(popup_height(rows, 30.0, 1.0), 30.0);
+    }
+}
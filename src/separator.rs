@@ -0,0 +1,260 @@
+use std::sync::Arc;
+
+use basalt::interface::UnitValue::{PctOffset, Pixels};
+use basalt::interface::{Bin, BinStyle, Position, Visibility};
+
+use crate::builder::WidgetBuilder;
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
+
+/// The orientation of the [`Separator`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeparatorOrientation {
+    /// Display the [`Separator`] horizontally, filling the width of its container.
+    ///
+    /// **Note**: This is the default.
+    #[default]
+    Horizontal,
+    /// Display the [`Separator`] vertically, filling the height of its container.
+    Vertical,
+}
+
+/// Builder for [`Separator`]
+pub struct SeparatorBuilder<'a, C> {
+    widget: WidgetBuilder<'a, C>,
+    props: Properties,
+    plmt_is_default: bool,
+}
+
+struct Properties {
+    orientation: SeparatorOrientation,
+    thickness: f32,
+    placement: WidgetPlacement,
+}
+
+impl Properties {
+    fn new(placement: WidgetPlacement, theme: &Theme) -> Self {
+        Self {
+            orientation: SeparatorOrientation::Horizontal,
+            thickness: Separator::default_thickness(theme),
+            placement,
+        }
+    }
+}
+
+impl<'a, C> SeparatorBuilder<'a, C>
+where
+    C: WidgetContainer,
+{
+    pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
+        Self {
+            plmt_is_default: builder.placement.is_none(),
+            props: Properties::new(
+                builder.take_placement(|theme| {
+                    Separator::default_placement(theme, Default::default())
+                }),
+                &builder.theme,
+            ),
+            widget: builder,
+        }
+    }
+
+    /// Set the orientation.
+    ///
+    /// **Note**: If not set this defaults to [`SeparatorOrientation::Horizontal`].
+    pub fn orientation(mut self, orientation: SeparatorOrientation) -> Self {
+        self.props.orientation = orientation;
+        self.update_default_placement();
+        self
+    }
+
+    /// Set the thickness of the line.
+    ///
+    /// **Notes**:
+    /// - If not set this defaults to the theme's border size or `1.0` if there is none.
+    /// - Has no effect if a placement was provided.
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.props.thickness = thickness;
+        self.update_default_placement();
+        self
+    }
+
+    /// Finish building the [`Separator`].
+    pub fn build(self) -> Arc<Separator> {
+        let window = self
+            .widget
+            .container
+            .container_bin()
+            .window()
+            .expect("The widget container must have an associated window.");
+
+        let container = window.new_bin();
+
+        self.widget
+            .container
+            .container_bin()
+            .add_child(container.clone());
+
+        let separator = Arc::new(Separator {
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::Separator),
+            props: self.props,
+            container,
+        });
+
+        separator.style_update();
+        self.widget.container.widget_built(separator.clone());
+        separator
+    }
+
+    fn update_default_placement(&mut self) {
+        if self.plmt_is_default {
+            let placement = Separator::placement_with_thickness(
+                &self.widget.theme,
+                self.props.orientation,
+                self.props.thickness,
+            );
+
+            self.props.placement = self.widget.apply_placement(placement);
+        }
+    }
+}
+
+/// Separator widget
+pub struct Separator {
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
+    props: Properties,
+    container: Arc<Bin>,
+}
+
+impl Separator {
+    /// Obtain the [`SeparatorOrientation`] of this [`Separator`].
+    pub fn orientation(&self) -> SeparatorOrientation {
+        self.props.orientation
+    }
+
+    /// Set if this [`Separator`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`Separator`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`Separator`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`Separator`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::Separator`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
+    /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`) and
+    /// the [`SeparatorOrientation`](`SeparatorOrientation`).
+    pub fn default_placement(theme: &Theme, orientation: SeparatorOrientation) -> WidgetPlacement {
+        Self::placement_with_thickness(theme, orientation, Self::default_thickness(theme))
+    }
+
+    fn default_thickness(theme: &Theme) -> f32 {
+        theme.border.unwrap_or(1.0)
+    }
+
+    fn placement_with_thickness(
+        theme: &Theme,
+        orientation: SeparatorOrientation,
+        thickness: f32,
+    ) -> WidgetPlacement {
+        let (width, height) = match orientation {
+            SeparatorOrientation::Horizontal => {
+                (PctOffset(100.0, -2.0 * theme.spacing), Pixels(thickness))
+            },
+            SeparatorOrientation::Vertical => {
+                (Pixels(thickness), PctOffset(100.0, -2.0 * theme.spacing))
+            },
+        };
+
+        WidgetPlacement {
+            position: Position::Floating,
+            margin_t: Pixels(theme.spacing),
+            margin_b: Pixels(theme.spacing),
+            margin_l: Pixels(theme.spacing),
+            margin_r: Pixels(theme.spacing),
+            width,
+            height,
+            ..Default::default()
+        }
+    }
+
+    fn style_update(&self) {
+        let container_style = BinStyle {
+            back_color: self.theme.get().colors.border1,
+            visibility: self.container.style_inspect(|style| style.visibility),
+            ..self.props.placement.clone().into_style()
+        };
+
+        self.container.style_update(container_style).expect_valid();
+    }
+}
+
+impl Widget for Separator {
+    fn focus(&self) {}
+
+    fn blur(&self) {}
+
+    fn is_visible(&self) -> bool {
+        Separator::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        Separator::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        Separator::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        Separator::set_theme(self, theme)
+    }
+}
@@ -1,24 +1,30 @@
 use std::cell::RefCell;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 
 use basalt::image::ImageKey;
 use basalt::input::{InputHookCtrl, Qwerty, WindowState};
-use basalt::interface::UnitValue::{PctOfHeight, PctOfHeightOffset, Percent, Pixels};
+use basalt::interface::UnitValue::{PctOfHeight, PctOfHeightOffset, PctOffset, Percent, Pixels};
 use basalt::interface::{
     Bin, BinPostUpdate, BinStyle, BinVertex, Color, Position, TextAttrs, TextBody, TextHoriAlign,
-    TextVertAlign, TextWrap, ZIndex,
+    TextVertAlign, TextWrap, Visibility, ZIndex,
 };
 use parking_lot::ReentrantMutex;
 
 use crate::builder::WidgetBuilder;
 use crate::button::{BtnHookColors, button_hooks};
-use crate::{Theme, WidgetContainer, WidgetPlacement, text_hooks};
+use crate::error::BuildError;
+use crate::scroll_bar::{down_symbol_verts, up_symbol_verts};
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole, text_hooks};
 
 /// Builder for [`SpinButton`]
 pub struct SpinButtonBuilder<'a, C> {
     widget: WidgetBuilder<'a, C>,
     props: Properties,
+    plmt_is_default: bool,
     on_change: Vec<Box<dyn FnMut(&Arc<SpinButton>, i32) + Send + 'static>>,
+    on_submit: Vec<Box<dyn FnMut(&Arc<SpinButton>, i32) + Send + 'static>>,
+    on_commit: Vec<Box<dyn FnMut(&Arc<SpinButton>, i32) + Send + 'static>>,
 }
 
 /// An error than can occur from [`SpinButtonBuilder::build`].
@@ -30,6 +36,28 @@ pub enum SpinButtonError {
     /// Value provided by [`SpinButtonBuilder::set_value`] is not in range specified by
     /// [`SpinButtonBuilder::min_value`] and [`SpinButtonBuilder::max_value`].
     SetValNotInRange,
+    /// The widget failed to build.
+    Build(BuildError),
+}
+
+impl From<BuildError> for SpinButtonError {
+    fn from(error: BuildError) -> Self {
+        Self::Build(error)
+    }
+}
+
+/// How the buttons of a [`SpinButton`] are arranged.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpinButtonLayout {
+    /// Display the decrement and increment buttons next to each other after the entry.
+    ///
+    /// This is the default.
+    #[default]
+    SideBySide,
+    /// Display an up arrow above a down arrow after the entry.
+    ///
+    /// This takes up less space as both buttons share a single column.
+    Stacked,
 }
 
 struct Properties {
@@ -39,6 +67,8 @@ struct Properties {
     small_step: i32,
     medium_step: i32,
     large_step: i32,
+    editable: bool,
+    layout: SpinButtonLayout,
     placement: WidgetPlacement,
 }
 
@@ -51,6 +81,8 @@ impl Properties {
             small_step: 1,
             medium_step: 1,
             large_step: 1,
+            editable: true,
+            layout: SpinButtonLayout::SideBySide,
             placement,
         }
     }
@@ -62,14 +94,16 @@ where
 {
     pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
         Self {
+            plmt_is_default: builder.placement.is_none(),
             props: Properties::new(
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| SpinButton::default_placement(&builder.theme)),
+                builder.take_placement(|theme| {
+                    SpinButton::default_placement(theme, Default::default())
+                }),
             ),
             widget: builder,
             on_change: Vec::new(),
+            on_submit: Vec::new(),
+            on_commit: Vec::new(),
         }
     }
 
@@ -110,7 +144,7 @@ where
     /// Set the value of a medium step.
     ///
     /// **Notes**:
-    /// - This when either [`Qwerty::LCtrl`](basalt::input::Qwerty::LCtrl) or
+    /// - This is used when either [`Qwerty::LCtrl`](basalt::input::Qwerty::LCtrl) or
     /// [`Qwerty::RCtrl`](basalt::input::Qwerty::RCtrl) is used.
     /// - Dragging the knob with the mouse will not be effected by this value.
     /// - When this isn't used the medium step will be `1`.
@@ -122,7 +156,7 @@ where
     /// Set the value of a large step.
     ///
     /// **Notes**:
-    /// - This when either [`Qwerty::LShift`](basalt::input::Qwerty::LShift) or
+    /// - This is used when either [`Qwerty::LShift`](basalt::input::Qwerty::LShift) or
     /// [`Qwerty::RShift`](basalt::input::Qwerty::RShift) is used.
     /// - Dragging the knob with the mouse will not be effected by this value.
     /// - When this isn't used the large step will be `1`.
@@ -131,6 +165,33 @@ where
         self
     }
 
+    /// Set if the value can be entered with the keyboard.
+    ///
+    /// When not editable, the value is displayed centered and can only be changed with the
+    /// buttons or methods such as [`SpinButton::set`].
+    ///
+    /// **Note**: When this isn't used the value will be editable.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.props.editable = editable;
+        self
+    }
+
+    /// Set how the buttons are arranged.
+    ///
+    /// See [`SpinButtonLayout`] docs for more information.
+    ///
+    /// **Note**: When this isn't used the layout will be [`SpinButtonLayout::SideBySide`].
+    pub fn layout(mut self, layout: SpinButtonLayout) -> Self {
+        if self.plmt_is_default {
+            self.props.placement = self
+                .widget
+                .apply_placement(SpinButton::default_placement(&self.widget.theme, layout));
+        }
+
+        self.props.layout = layout;
+        self
+    }
+
     /// Add a callback to be called when the [`SpinButton`]'s value changed.
     ///
     /// **Note**: When changing the value within the callback, no callbacks will be called with
@@ -145,6 +206,44 @@ where
         self
     }
 
+    /// Add a callback to be called when enter is pressed within the [`SpinButton`]'s entry.
+    ///
+    /// The callback is called after the entered value has been applied.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_submit<F>(mut self, on_submit: F) -> Self
+    where
+        F: FnMut(&Arc<SpinButton>, i32) + Send + 'static,
+    {
+        self.on_submit.push(Box::new(on_submit));
+        self
+    }
+
+    /// Add a callback to be called when the [`SpinButton`]'s value is committed.
+    ///
+    /// Unlike [`on_change`](Self::on_change), this is only called once the user is done with
+    /// the value: when enter is pressed within the entry, when the [`SpinButton`] loses focus or
+    /// when one of the buttons is pressed.
+    ///
+    /// **Notes**:
+    /// - Stepping with the arrow and page keys doesn't commit the value, it is committed once one
+    /// of the above happens.
+    /// - This is called after the [`on_change`](Self::on_change) callbacks and before the
+    /// [`on_submit`](Self::on_submit) callbacks.
+    /// - Using [`SpinButton::set`] will call both the [`on_change`](Self::on_change) and these
+    /// callbacks.
+    /// - This will not be called when the committed value is the same as the previously committed
+    /// value.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_commit<F>(mut self, on_commit: F) -> Self
+    where
+        F: FnMut(&Arc<SpinButton>, i32) + Send + 'static,
+    {
+        self.on_commit.push(Box::new(on_commit));
+        self
+    }
+
     /// Finish building the [`SpinButton`].
     pub fn build(self) -> Result<Arc<SpinButton>, SpinButtonError> {
         if self.props.max < self.props.min {
@@ -160,13 +259,20 @@ where
             .container
             .container_bin()
             .window()
-            .expect("The widget container must have an associated window.");
+            .ok_or(BuildError::NoWindow)?;
 
-        let mut new_bins = window.new_bins(4).into_iter();
+        let new_bins = window.new_bins(5);
+
+        if new_bins.len() != 5 {
+            return Err(BuildError::BinAllocationFailed.into());
+        }
+
+        let mut new_bins = new_bins.into_iter();
         let container = new_bins.next().unwrap();
         let entry = new_bins.next().unwrap();
         let sub_button = new_bins.next().unwrap();
         let add_button = new_bins.next().unwrap();
+        let error_message = new_bins.next().unwrap();
 
         self.widget
             .container
@@ -176,33 +282,43 @@ where
         container.add_child(entry.clone());
         container.add_child(sub_button.clone());
         container.add_child(add_button.clone());
+        container.add_child(error_message.clone());
         let initial_val = self.props.val;
 
-        let spin_button = Arc::new(SpinButton {
-            theme: self.widget.theme,
-            props: self.props,
-            container,
-            entry,
-            sub_button,
-            add_button,
-            state: ReentrantMutex::new(State {
-                val: RefCell::new(initial_val),
-                on_change: RefCell::new(self.on_change),
-            }),
+        let spin_button = Arc::new_cyclic(|this| {
+            SpinButton {
+                this: this.clone(),
+                theme: ThemeCell::new(self.widget.theme),
+                name: self.widget.name,
+                aria_label: self.widget.aria_label,
+                role: self.widget.role.unwrap_or(WidgetRole::SpinButton),
+                props: self.props,
+                container,
+                entry,
+                sub_button,
+                add_button,
+                error_message,
+                state: ReentrantMutex::new(State {
+                    val: RefCell::new(initial_val),
+                    committed: RefCell::new(initial_val),
+                    on_change: RefCell::new(self.on_change),
+                    on_submit: RefCell::new(self.on_submit),
+                    on_commit: RefCell::new(self.on_commit),
+                    error: RefCell::new(None),
+                }),
+            }
         });
 
         let cb_spin_button = spin_button.clone();
+        let spin_button_wk = Arc::downgrade(&spin_button);
 
         button_hooks(
             &spin_button.sub_button,
-            BtnHookColors {
-                back_clr: Some(spin_button.theme.colors.back3),
-                vert_clr: Some(spin_button.theme.colors.border2),
-                h_back_clr: Some(spin_button.theme.colors.accent1),
-                h_vert_clr: Some(spin_button.theme.colors.back2),
-                p_back_clr: Some(spin_button.theme.colors.accent2),
-                p_vert_clr: Some(spin_button.theme.colors.back2),
-                ..Default::default()
+            move || {
+                spin_button_wk
+                    .upgrade()
+                    .map(|spin_button| spin_button.button_colors())
+                    .unwrap_or_default()
             },
             move |w_state| {
                 let step = cb_spin_button.step_size(w_state);
@@ -211,17 +327,15 @@ where
         );
 
         let cb_spin_button = spin_button.clone();
+        let spin_button_wk = Arc::downgrade(&spin_button);
 
         button_hooks(
             &spin_button.add_button,
-            BtnHookColors {
-                back_clr: Some(spin_button.theme.colors.back3),
-                vert_clr: Some(spin_button.theme.colors.border2),
-                h_back_clr: Some(spin_button.theme.colors.accent1),
-                h_vert_clr: Some(spin_button.theme.colors.back2),
-                p_back_clr: Some(spin_button.theme.colors.accent2),
-                p_vert_clr: Some(spin_button.theme.colors.back2),
-                ..Default::default()
+            move || {
+                spin_button_wk
+                    .upgrade()
+                    .map(|spin_button| spin_button.button_colors())
+                    .unwrap_or_default()
             },
             move |w_state| {
                 let step = cb_spin_button.step_size(w_state);
@@ -229,126 +343,207 @@ where
             },
         );
 
-        let spin_button_wk = Arc::downgrade(&spin_button);
-
-        text_hooks::create(
-            text_hooks::Properties::ENTRY,
-            spin_button.entry.clone(),
-            spin_button.theme.clone(),
-            Some(Arc::new(move |updated| {
-                let text_hooks::Updated {
-                    cursor: _,
-                    cursor_bounds,
-                    body_line_count: _,
-                    cursor_line_col: _,
-                    editor_bpu,
-                } = updated;
-
-                if let Some(cursor_bounds) = cursor_bounds
-                    && let Some(spin_button) = spin_button_wk.upgrade()
-                {
-                    spin_button.check_cursor_in_view(editor_bpu, cursor_bounds);
-                }
-            })),
-            None,
-        );
-
-        let spin_button_wk = Arc::downgrade(&spin_button);
-
-        window
-            .basalt_ref()
-            .input_ref()
-            .hook()
-            .bin(&spin_button.entry)
-            .on_character()
-            .weight(1)
-            .call(move |_, _, c| {
-                let spin_button = match spin_button_wk.upgrade() {
-                    Some(some) => some,
-                    None => return InputHookCtrl::Remove,
-                };
-
-                let val = match c.0 {
-                    '\r' | '\n' => {
-                        spin_button
-                            .entry
-                            .style_inspect(|style| style.text_body.spans[0].text.parse::<i32>())
-                            .unwrap_or(*spin_button.state.lock().val.borrow())
-                    },
-                    '\u{1b}' => *spin_button.state.lock().val.borrow(),
-                    _ => return Default::default(),
-                };
-
-                let window_id = match spin_button.entry.window() {
-                    Some(window) => window.id(),
-                    None => return Default::default(),
-                };
-
-                spin_button
-                    .entry
-                    .basalt_ref()
-                    .input_ref()
-                    .clear_bin_focus(window_id);
-
-                spin_button.set(val);
-                InputHookCtrl::RetainNoPass
-            })
-            .finish()
-            .unwrap();
-
-        let cb_spin_button = spin_button.clone();
-
-        spin_button.entry.on_focus(move |_, _| {
-            let border_size = cb_spin_button.theme.border.unwrap_or(1.0);
+        if spin_button.props.editable {
+            let spin_button_wk = Arc::downgrade(&spin_button);
+
+            text_hooks::create(
+                text_hooks::Properties::ENTRY,
+                spin_button.entry.clone(),
+                spin_button.theme.get().clone(),
+                Some(Arc::new(move |updated| {
+                    let text_hooks::Updated {
+                        cursor: _,
+                        cursor_bounds,
+                        body_line_count: _,
+                        cursor_line_col: _,
+                        editor_bpu,
+                    } = updated;
+
+                    if let Some(cursor_bounds) = cursor_bounds
+                        && let Some(spin_button) = spin_button_wk.upgrade()
+                    {
+                        spin_button.check_cursor_in_view(editor_bpu, cursor_bounds);
+                    }
+                })),
+                None,
+            );
+
+            let spin_button_wk = Arc::downgrade(&spin_button);
+
+            window
+                .basalt_ref()
+                .input_ref()
+                .hook()
+                .bin(&spin_button.entry)
+                .on_character()
+                .weight(1)
+                .call(move |_, _, c| {
+                    let spin_button = match spin_button_wk.upgrade() {
+                        Some(some) => some,
+                        None => return InputHookCtrl::Remove,
+                    };
+
+                    let (val, submit) = match c.0 {
+                        '\r' | '\n' => {
+                            (
+                                spin_button
+                                    .entry
+                                    .style_inspect(|style| {
+                                        style.text_body.spans[0].text.parse::<i32>()
+                                    })
+                                    .unwrap_or(*spin_button.state.lock().val.borrow()),
+                                true,
+                            )
+                        },
+                        '\u{1b}' => (*spin_button.state.lock().val.borrow(), false),
+                        _ => return Default::default(),
+                    };
+
+                    // Move focus to the container rather than clearing the window's focus, so that
+                    // keyboard input stays within the widget.
+                    spin_button
+                        .entry
+                        .basalt_ref()
+                        .input_ref()
+                        .set_bin_focused(&spin_button.container);
+
+                    spin_button.set(val);
+
+                    if submit {
+                        spin_button.submit(val);
+                    }
+
+                    InputHookCtrl::RetainNoPass
+                })
+                .finish()
+                .unwrap();
+
+            let cb_spin_button = spin_button.clone();
+
+            spin_button.entry.on_focus(move |_, _| {
+                let border_size = cb_spin_button.theme.get().border.unwrap_or(1.0);
+
+                cb_spin_button.entry.style_modify(|style| {
+                    style.border_size_t = Pixels(border_size);
+                    style.border_size_b = Pixels(border_size);
+                    style.border_size_l = Pixels(border_size);
+                    style.border_size_r = Pixels(border_size);
+                });
+
+                Default::default()
+            });
 
-            cb_spin_button.entry.style_modify(|style| {
-                style.border_size_t = Pixels(border_size);
-                style.border_size_b = Pixels(border_size);
-                style.border_size_l = Pixels(border_size);
-                style.border_size_r = Pixels(border_size);
+            let cb_spin_button = spin_button.clone();
+
+            spin_button.entry.on_focus_lost(move |_, _| {
+                cb_spin_button.entry.style_modify(|style| {
+                    style.border_size_t = Default::default();
+                    style.border_size_b = Default::default();
+                    style.border_size_l = Default::default();
+                    style.border_size_r = Default::default();
+                    style.scroll_x = 0.0;
+                    style.text_body.spans =
+                        vec![format!("{}", *cb_spin_button.state.lock().val.borrow()).into()];
+                });
+
+                cb_spin_button.commit();
+                Default::default()
             });
+        }
 
-            Default::default()
-        });
+        for bin in [&spin_button.container, &spin_button.entry] {
+            for key in [
+                Qwerty::ArrowUp,
+                Qwerty::ArrowDown,
+                Qwerty::PageUp,
+                Qwerty::PageDown,
+            ] {
+                let spin_button_wk = Arc::downgrade(&spin_button);
+
+                bin.on_press(key, move |_, w_state, _| {
+                    let spin_button = match spin_button_wk.upgrade() {
+                        Some(some) => some,
+                        None => return InputHookCtrl::Remove,
+                    };
+
+                    let val = match key {
+                        Qwerty::ArrowUp => spin_button.incremented(spin_button.step_size(w_state)),
+                        Qwerty::ArrowDown => {
+                            spin_button.decremented(spin_button.step_size(w_state))
+                        },
+                        Qwerty::PageUp => spin_button.incremented(spin_button.props.large_step),
+                        Qwerty::PageDown => spin_button.decremented(spin_button.props.large_step),
+                        _ => unreachable!(),
+                    };
+
+                    spin_button.set_inner(val);
+                    InputHookCtrl::RetainNoPass
+                });
+            }
+        }
 
-        let cb_spin_button = spin_button.clone();
+        let spin_button_wk = Arc::downgrade(&spin_button);
 
-        spin_button.entry.on_focus_lost(move |_, _| {
-            cb_spin_button.entry.style_modify(|style| {
-                style.border_size_t = Default::default();
-                style.border_size_b = Default::default();
-                style.border_size_l = Default::default();
-                style.border_size_r = Default::default();
-                style.scroll_x = 0.0;
-                style.text_body.spans =
-                    vec![format!("{}", *cb_spin_button.state.lock().val.borrow()).into()];
-            });
+        spin_button.container.on_focus_lost(move |_, _| {
+            let spin_button = match spin_button_wk.upgrade() {
+                Some(some) => some,
+                None => return InputHookCtrl::Remove,
+            };
 
+            spin_button.commit();
             Default::default()
         });
 
         spin_button.style_update();
+        spin_button.set_error(None);
+        self.widget.container.widget_built(spin_button.clone());
         Ok(spin_button)
     }
 }
 
 /// Spin button widget
+///
+/// **Note**: When focused, the arrow up and down keys step the value by the step of the modifier
+/// keys held, while page up and down step the value by the large step.
 pub struct SpinButton {
-    theme: Theme,
+    this: Weak<SpinButton>,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     container: Arc<Bin>,
     entry: Arc<Bin>,
     sub_button: Arc<Bin>,
     add_button: Arc<Bin>,
+    error_message: Arc<Bin>,
     state: ReentrantMutex<State>,
 }
 
 struct State {
     val: RefCell<i32>,
+    committed: RefCell<i32>,
     on_change: RefCell<Vec<Box<dyn FnMut(&Arc<SpinButton>, i32) + Send + 'static>>>,
+    on_submit: RefCell<Vec<Box<dyn FnMut(&Arc<SpinButton>, i32) + Send + 'static>>>,
+    on_commit: RefCell<Vec<Box<dyn FnMut(&Arc<SpinButton>, i32) + Send + 'static>>>,
+    error: RefCell<Option<String>>,
 }
 
 impl SpinButton {
+    fn button_colors(&self) -> BtnHookColors {
+        let theme = self.theme.get();
+
+        BtnHookColors {
+            back_clr: Some(theme.colors.back3),
+            vert_clr: Some(theme.colors.border2),
+            h_back_clr: Some(theme.colors.accent1),
+            h_vert_clr: Some(theme.colors.back2),
+            p_back_clr: Some(theme.colors.accent2),
+            p_vert_clr: Some(theme.colors.back2),
+            ..Default::default()
+        }
+    }
+
     fn step_size(&self, w_state: &WindowState) -> i32 {
         if w_state.is_key_pressed(Qwerty::LCtrl) || w_state.is_key_pressed(Qwerty::RCtrl) {
             self.props.medium_step
@@ -361,22 +556,13 @@ impl SpinButton {
 
     /// Set the value to the provided valued.
     ///
-    /// **Note**: This value will be clamped to values provided by [`SpinButtonBuilder::min_value`]
+    /// **Notes**:
+    /// - This value will be clamped to values provided by [`SpinButtonBuilder::min_value`]
     /// and [`SpinButtonBuilder::max_value`].
+    /// - This commits the value, so both the `on_change` and `on_commit` callbacks are called.
     pub fn set(self: &Arc<Self>, val: i32) {
-        let state = self.state.lock();
-        let val = val.clamp(self.props.min, self.props.max);
-        *state.val.borrow_mut() = val;
-
-        self.entry.style_modify(|style| {
-            style.text_body.spans = vec![format!("{}", val).into()];
-        });
-
-        if let Ok(mut on_change_cbs) = state.on_change.try_borrow_mut() {
-            for on_change in on_change_cbs.iter_mut() {
-                on_change(self, val);
-            }
-        }
+        self.set_inner(val);
+        self.commit();
     }
 
     /// Get the current value.
@@ -384,20 +570,39 @@ impl SpinButton {
         *self.state.lock().val.borrow()
     }
 
+    /// Get the current value as a fraction of the range, from `0.0` at the minimum to `1.0` at
+    /// the maximum.
+    ///
+    /// **Note**: If the minimum and maximum are equal, this is `0.0`.
+    pub fn normalized(&self) -> f32 {
+        let range = self.props.max as f32 - self.props.min as f32;
+
+        if range <= 0.0 {
+            return 0.0;
+        }
+
+        ((self.val() as f32 - self.props.min as f32) / range).clamp(0.0, 1.0)
+    }
+
+    /// Set the value from a fraction of the range, where `0.0` is the minimum and `1.0` is the
+    /// maximum.
+    ///
+    /// **Notes**:
+    /// - The fraction is clamped to `0.0..=1.0` and the resulting value is rounded to the nearest
+    /// integer.
+    /// - This commits the value like [`SpinButton::set`].
+    pub fn set_normalized(self: &Arc<Self>, fraction: f32) {
+        let range = self.props.max as f64 - self.props.min as f64;
+        let offset = (range * fraction.clamp(0.0, 1.0) as f64).round();
+        self.set((self.props.min as f64 + offset) as i32);
+    }
+
     /// Increment the value by the provided amount.
     ///
     /// **Note**: The resulting value will be clamped to values provided by [`SpinButtonBuilder::min_value`]
     /// and [`SpinButtonBuilder::max_value`].
     pub fn increment(self: &Arc<Self>, amt: i32) {
-        let state = self.state.lock();
-
-        let val = state
-            .val
-            .borrow()
-            .checked_add(amt)
-            .unwrap_or(self.props.max);
-
-        self.set(val);
+        self.set(self.incremented(amt));
     }
 
     /// Decrement the value by the provided amount.
@@ -405,15 +610,7 @@ impl SpinButton {
     /// **Note**: The resulting value will be clamped to values provided by [`SpinButtonBuilder::min_value`]
     /// and [`SpinButtonBuilder::max_value`].
     pub fn decrement(self: &Arc<Self>, amt: i32) {
-        let state = self.state.lock();
-
-        let val = state
-            .val
-            .borrow()
-            .checked_sub(amt)
-            .unwrap_or(self.props.min);
-
-        self.set(val);
+        self.set(self.decremented(amt));
     }
 
     /// Add a callback to be called when the [`SpinButton`]'s value changed.
@@ -433,10 +630,212 @@ impl SpinButton {
             .push(Box::new(on_change));
     }
 
-    /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
-    pub fn default_placement(theme: &Theme) -> WidgetPlacement {
+    /// Add a callback to be called when enter is pressed within the [`SpinButton`]'s entry.
+    ///
+    /// The callback is called after the entered value has been applied.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_submit<F>(&self, on_submit: F)
+    where
+        F: FnMut(&Arc<SpinButton>, i32) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_submit
+            .borrow_mut()
+            .push(Box::new(on_submit));
+    }
+
+    /// Add a callback to be called when the [`SpinButton`]'s value is committed.
+    ///
+    /// See [`SpinButtonBuilder::on_commit`] for when this is called.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_commit<F>(&self, on_commit: F)
+    where
+        F: FnMut(&Arc<SpinButton>, i32) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_commit
+            .borrow_mut()
+            .push(Box::new(on_commit));
+    }
+
+    /// Set the value without committing it.
+    fn set_inner(self: &Arc<Self>, val: i32) {
+        let state = self.state.lock();
+        let val = val.clamp(self.props.min, self.props.max);
+        *state.val.borrow_mut() = val;
+
+        self.entry.style_modify(|style| {
+            style.text_body.spans = vec![format!("{}", val).into()];
+        });
+
+        if let Ok(mut on_change_cbs) = state.on_change.try_borrow_mut() {
+            for on_change in on_change_cbs.iter_mut() {
+                on_change(self, val);
+            }
+        }
+    }
+
+    fn incremented(&self, amt: i32) -> i32 {
+        self.state
+            .lock()
+            .val
+            .borrow()
+            .checked_add(amt)
+            .unwrap_or(self.props.max)
+    }
+
+    fn decremented(&self, amt: i32) -> i32 {
+        self.state
+            .lock()
+            .val
+            .borrow()
+            .checked_sub(amt)
+            .unwrap_or(self.props.min)
+    }
+
+    fn commit(self: &Arc<Self>) {
+        let state = self.state.lock();
+        let val = *state.val.borrow();
+
+        if *state.committed.borrow() == val {
+            return;
+        }
+
+        *state.committed.borrow_mut() = val;
+
+        if let Ok(mut on_commit_cbs) = state.on_commit.try_borrow_mut() {
+            for on_commit in on_commit_cbs.iter_mut() {
+                on_commit(self, val);
+            }
+        }
+    }
+
+    fn submit(self: &Arc<Self>, val: i32) {
+        let state = self.state.lock();
+
+        if let Ok(mut on_submit_cbs) = state.on_submit.try_borrow_mut() {
+            for on_submit in on_submit_cbs.iter_mut() {
+                on_submit(self, val);
+            }
+        }
+    }
+
+    /// Re-apply the layout and styling of this [`SpinButton`].
+    ///
+    /// **Note**: Any text entered but not yet applied will be replaced with the current value.
+    pub fn refresh_layout(self: &Arc<Self>) {
+        self.style_update();
+    }
+
+    /// Set or clear the error of this [`SpinButton`].
+    ///
+    /// When `Some`, the border is colored with the theme's `error` color and the message is
+    /// displayed below the [`SpinButton`]. When `None`, the normal styling is restored.
+    ///
+    /// **Notes**:
+    /// - The message may overlap neighboring widgets.
+    /// - The border is only displayed when the theme has a border.
+    pub fn set_error(&self, error: Option<String>) {
+        let state = self.state.lock();
+        crate::set_field_error(
+            &self.theme.get(),
+            &self.container,
+            &self.error_message,
+            error.as_deref(),
+            false,
+        );
+        *state.error.borrow_mut() = error;
+    }
+
+    /// Obtain the current error provided by [`set_error`](Self::set_error).
+    pub fn error(&self) -> Option<String> {
+        self.state.lock().error.borrow().clone()
+    }
+
+    /// Give keyboard focus to this [`SpinButton`].
+    ///
+    /// **Note**: When editable this focuses the entry, allowing a value to be typed.
+    pub fn focus(&self) {
+        if self.props.editable {
+            crate::focus_bin(&self.entry);
+        } else {
+            crate::focus_bin(&self.container);
+        }
+    }
+
+    /// Remove keyboard focus from this [`SpinButton`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`SpinButton`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.container);
+    }
+
+    /// Set if this [`SpinButton`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`SpinButton`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`SpinButton`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`SpinButton`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(self: &Arc<Self>, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::SpinButton`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
+    /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`) and
+    /// [`SpinButtonLayout`].
+    pub fn default_placement(theme: &Theme, layout: SpinButtonLayout) -> WidgetPlacement {
         let height = theme.spacing + theme.base_size;
-        let width = height * 3.5;
+
+        let width = match layout {
+            SpinButtonLayout::SideBySide => height * 3.5,
+            SpinButtonLayout::Stacked => height * 2.5,
+        };
 
         WidgetPlacement {
             position: Position::Floating,
@@ -469,8 +868,9 @@ impl SpinButton {
     }
 
     fn style_update(self: &Arc<Self>) {
-        let border_size = self.theme.border.unwrap_or(0.0);
+        let border_size = self.theme.get().border.unwrap_or(0.0);
         let mut container_style = self.props.placement.clone().into_style();
+        container_style.visibility = self.container.style_inspect(|style| style.visibility);
 
         let mut entry_style = BinStyle {
             position: Position::Anchor,
@@ -479,22 +879,21 @@ impl SpinButton {
             pos_from_l: Pixels(0.0),
             pos_from_b: Pixels(0.0),
             pos_from_r: PctOfHeightOffset(200.0, border_size * 2.0),
-            back_color: self.theme.colors.back2,
-            border_color_t: self.theme.colors.accent1,
-            border_color_b: self.theme.colors.accent1,
-            border_color_l: self.theme.colors.accent1,
-            border_color_r: self.theme.colors.accent1,
-            padding_l: Pixels(self.theme.spacing),
+            back_color: self.theme.get().colors.back2,
+            border_color_t: self.theme.get().colors.accent1,
+            border_color_b: self.theme.get().colors.accent1,
+            border_color_l: self.theme.get().colors.accent1,
+            border_color_r: self.theme.get().colors.accent1,
             text_body: TextBody {
-                spans: vec![format!("{}", self.props.val).into()],
+                spans: vec![format!("{}", self.val()).into()],
                 hori_align: TextHoriAlign::Left,
                 vert_align: TextVertAlign::Center,
                 text_wrap: TextWrap::None,
                 base_attrs: TextAttrs {
-                    height: Pixels(self.theme.text_height),
-                    color: self.theme.colors.text1a,
-                    font_family: self.theme.font_family.clone(),
-                    font_weight: self.theme.font_weight,
+                    height: Pixels(self.theme.get().text_height),
+                    color: self.theme.get().colors.text1a,
+                    font_family: self.theme.get().font_family.clone(),
+                    font_weight: self.theme.get().value_font_weight(),
                     ..Default::default()
                 },
                 ..Default::default()
@@ -507,13 +906,13 @@ impl SpinButton {
             pos_from_r: PctOfHeightOffset(100.0, border_size),
             pos_from_b: Pixels(0.0),
             width: PctOfHeight(100.0),
-            back_color: self.theme.colors.back3,
+            back_color: self.theme.get().colors.back3,
             user_vertexes: vec![(
                 ImageKey::INVALID,
                 sub_symbol_verts(
-                    self.theme.text_height,
-                    self.theme.spacing,
-                    self.theme.colors.border2,
+                    self.theme.get().text_height,
+                    self.theme.get().spacing,
+                    self.theme.get().colors.border2,
                 ),
             )],
             ..Default::default()
@@ -524,36 +923,45 @@ impl SpinButton {
             pos_from_r: Pixels(0.0),
             pos_from_b: Pixels(0.0),
             width: PctOfHeight(100.0),
-            back_color: self.theme.colors.back3,
+            back_color: self.theme.get().colors.back3,
             user_vertexes: vec![(
                 ImageKey::INVALID,
                 add_symbol_verts(
-                    self.theme.text_height,
-                    self.theme.spacing,
-                    self.theme.colors.border2,
+                    self.theme.get().text_height,
+                    self.theme.get().spacing,
+                    self.theme.get().colors.border2,
                 ),
             )],
             ..Default::default()
         };
 
-        if let Some(border_size) = self.theme.border {
+        if self.props.editable {
+            entry_style.padding_l = Pixels(self.theme.get().spacing);
+        } else {
+            entry_style.text_body.hori_align = TextHoriAlign::Center;
+        }
+
+        if let Some(border_size) = self.theme.get().border {
+            let border_color =
+                crate::field_border_color(&self.theme.get(), self.error().is_some(), false);
+
             container_style.border_size_t = Pixels(border_size);
             container_style.border_size_b = Pixels(border_size);
             container_style.border_size_l = Pixels(border_size);
             container_style.border_size_r = Pixels(border_size);
-            container_style.border_color_t = self.theme.colors.border1;
-            container_style.border_color_b = self.theme.colors.border1;
-            container_style.border_color_l = self.theme.colors.border1;
-            container_style.border_color_r = self.theme.colors.border1;
+            container_style.border_color_t = border_color;
+            container_style.border_color_b = border_color;
+            container_style.border_color_l = border_color;
+            container_style.border_color_r = border_color;
 
             sub_button_style.border_size_l = Pixels(border_size);
-            sub_button_style.border_color_l = self.theme.colors.border2;
+            sub_button_style.border_color_l = self.theme.get().colors.border2;
 
             add_button_style.border_size_l = Pixels(border_size);
-            add_button_style.border_color_l = self.theme.colors.border2;
+            add_button_style.border_color_l = self.theme.get().colors.border2;
         }
 
-        if let Some(border_radius) = self.theme.roundness {
+        if let Some(border_radius) = self.theme.get().roundness {
             container_style.border_radius_tl = Pixels(border_radius);
             container_style.border_radius_tr = Pixels(border_radius);
             container_style.border_radius_bl = Pixels(border_radius);
@@ -566,6 +974,48 @@ impl SpinButton {
             add_button_style.border_radius_br = Pixels(border_radius);
         }
 
+        if self.props.layout == SpinButtonLayout::Stacked {
+            // Both buttons share the column of the increment button, split at the middle.
+            entry_style.pos_from_r = PctOfHeightOffset(100.0, border_size);
+
+            add_button_style.pos_from_b = PctOffset(50.0, border_size / 2.0);
+            add_button_style.border_radius_br = Default::default();
+            add_button_style.user_vertexes = vec![(
+                ImageKey::INVALID,
+                up_symbol_verts(33.0, self.theme.get().colors.border2),
+            )];
+
+            sub_button_style.pos_from_t = PctOffset(50.0, border_size / 2.0);
+            sub_button_style.pos_from_r = Pixels(0.0);
+            sub_button_style.user_vertexes = vec![(
+                ImageKey::INVALID,
+                down_symbol_verts(33.0, self.theme.get().colors.border2),
+            )];
+
+            if let Some(border_size) = self.theme.get().border {
+                sub_button_style.border_size_t = Pixels(border_size);
+                sub_button_style.border_color_t = self.theme.get().colors.border2;
+            }
+
+            if let Some(border_radius) = self.theme.get().roundness {
+                sub_button_style.border_radius_br = Pixels(border_radius);
+            }
+        }
+
+        if self.theme.get().rtl {
+            for style in [
+                &mut entry_style,
+                &mut sub_button_style,
+                &mut add_button_style,
+            ] {
+                mirror_hori(style);
+            }
+
+            if self.props.editable {
+                entry_style.text_body.hori_align = TextHoriAlign::Right;
+            }
+        }
+
         Bin::style_update_batch([
             (&self.container, container_style),
             (&self.entry, entry_style),
@@ -575,6 +1025,16 @@ impl SpinButton {
     }
 }
 
+/// Swap the left and right sides of a style.
+fn mirror_hori(style: &mut BinStyle) {
+    std::mem::swap(&mut style.pos_from_l, &mut style.pos_from_r);
+    std::mem::swap(&mut style.padding_l, &mut style.padding_r);
+    std::mem::swap(&mut style.border_size_l, &mut style.border_size_r);
+    std::mem::swap(&mut style.border_color_l, &mut style.border_color_r);
+    std::mem::swap(&mut style.border_radius_tl, &mut style.border_radius_tr);
+    std::mem::swap(&mut style.border_radius_bl, &mut style.border_radius_br);
+}
+
 fn sub_symbol_verts(_target_size: f32, _spacing: f32, color: Color) -> Vec<BinVertex> {
     const PCT_PTS: [[f32; 2]; 4] = [[25.0, 47.0], [75.0, 47.0], [25.0, 53.0], [75.0, 53.0]];
 
@@ -615,3 +1075,31 @@ fn add_symbol_verts(_target_size: f32, _spacing: f32, color: Color) -> Vec<BinVe
         })
         .collect()
 }
+
+impl Widget for SpinButton {
+    fn focus(&self) {
+        SpinButton::focus(self)
+    }
+
+    fn blur(&self) {
+        SpinButton::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        SpinButton::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        SpinButton::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        SpinButton::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        if let Some(spin_button) = self.this.upgrade() {
+            SpinButton::set_theme(&spin_button, theme);
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use std::cell::RefCell;
+use std::f32::consts::PI;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use basalt::image::ImageKey;
+use basalt::interface::UnitValue::Pixels;
+use basalt::interface::{Bin, BinStyle, BinVertex, Color, Position, Visibility};
+use parking_lot::ReentrantMutex;
+
+use crate::builder::WidgetBuilder;
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
+
+/// Builder for [`Spinner`]
+pub struct SpinnerBuilder<'a, C> {
+    widget: WidgetBuilder<'a, C>,
+    props: Properties,
+    plmt_is_default: bool,
+}
+
+struct Properties {
+    speed: Duration,
+    running: bool,
+    placement: WidgetPlacement,
+}
+
+impl<'a, C> SpinnerBuilder<'a, C>
+where
+    C: WidgetContainer,
+{
+    pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
+        Self {
+            plmt_is_default: builder.placement.is_none(),
+            props: Properties {
+                speed: Duration::from_millis(800),
+                running: true,
+                placement: builder.take_placement(Spinner::default_placement),
+            },
+            widget: builder,
+        }
+    }
+
+    /// Set the width and height of the [`Spinner`].
+    ///
+    /// **Notes**:
+    /// - If not set this defaults to the theme's base size.
+    /// - Has no effect if a placement was provided.
+    pub fn size(mut self, size: f32) -> Self {
+        if self.plmt_is_default {
+            self.props.placement = self.widget.apply_placement(WidgetPlacement {
+                width: Pixels(size),
+                height: Pixels(size),
+                ..Spinner::default_placement(&self.widget.theme)
+            });
+        }
+
+        self
+    }
+
+    /// Set the duration of a full revolution.
+    ///
+    /// **Note**: If not set this defaults to 800 ms.
+    pub fn speed(mut self, speed: Duration) -> Self {
+        self.props.speed = speed;
+        self
+    }
+
+    /// Set if the [`Spinner`] is running once built.
+    ///
+    /// **Note**: If not set this defaults to `true`.
+    pub fn running(mut self, running: bool) -> Self {
+        self.props.running = running;
+        self
+    }
+
+    /// Finish building the [`Spinner`].
+    pub fn build(self) -> Arc<Spinner> {
+        let window = self
+            .widget
+            .container
+            .container_bin()
+            .window()
+            .expect("The widget container must have an associated window.");
+
+        let container = window.new_bin();
+
+        self.widget
+            .container
+            .container_bin()
+            .add_child(container.clone());
+
+        let running = self.props.running;
+
+        let spinner = Arc::new_cyclic(|this| {
+            Spinner {
+                this: this.clone(),
+                theme: ThemeCell::new(self.widget.theme),
+                name: self.widget.name,
+                aria_label: self.widget.aria_label,
+                role: self.widget.role.unwrap_or(WidgetRole::ProgressBar),
+                props: self.props,
+                container,
+                state: ReentrantMutex::new(State {
+                    spin: RefCell::new(SpinState {
+                        running: false,
+                        visible: true,
+                        hooked: false,
+                        angle: 0.0,
+                    }),
+                }),
+            }
+        });
+
+        spinner.style_update();
+
+        if running {
+            spinner.start();
+        }
+
+        self.widget.container.widget_built(spinner.clone());
+        spinner
+    }
+}
+
+/// Spinner widget
+///
+/// An indeterminate indicator that something is in progress.
+pub struct Spinner {
+    this: Weak<Spinner>,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
+    props: Properties,
+    container: Arc<Bin>,
+    state: ReentrantMutex<State>,
+}
+
+struct State {
+    spin: RefCell<SpinState>,
+}
+
+struct SpinState {
+    running: bool,
+    visible: bool,
+    hooked: bool,
+    angle: f32,
+}
+
+impl Spinner {
+    /// Start the animation.
+    pub fn start(self: &Arc<Self>) {
+        let state = self.state.lock();
+        state.spin.borrow_mut().running = true;
+        self.check_hook();
+    }
+
+    /// Stop the animation.
+    ///
+    /// **Note**: Nothing is displayed while stopped.
+    pub fn stop(&self) {
+        let state = self.state.lock();
+        state.spin.borrow_mut().running = false;
+    }
+
+    /// Check if the animation is running.
+    pub fn is_running(&self) -> bool {
+        self.state.lock().spin.borrow().running
+    }
+
+    /// Set if this [`Spinner`] is visible.
+    ///
+    /// **Note**: The animation is paused while hidden.
+    pub fn set_visible(self: &Arc<Self>, visible: bool) {
+        let state = self.state.lock();
+        state.spin.borrow_mut().visible = visible;
+
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+
+        self.check_hook();
+    }
+
+    /// Check if this [`Spinner`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.state.lock().spin.borrow().visible
+    }
+
+    /// Obtain the bounds of this [`Spinner`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`Spinner`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::ProgressBar`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
+    /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
+    pub fn default_placement(theme: &Theme) -> WidgetPlacement {
+        WidgetPlacement {
+            position: Position::Floating,
+            margin_t: Pixels(theme.spacing),
+            margin_b: Pixels(theme.spacing),
+            margin_l: Pixels(theme.spacing),
+            margin_r: Pixels(theme.spacing),
+            width: Pixels(theme.base_size),
+            height: Pixels(theme.base_size),
+            ..Default::default()
+        }
+    }
+
+    fn check_hook(self: &Arc<Self>) {
+        let state = self.state.lock();
+        let mut spin_state = state.spin.borrow_mut();
+
+        if spin_state.hooked || !spin_state.running || !spin_state.visible {
+            return;
+        }
+
+        let window = match self.container.window() {
+            Some(some) => some,
+            None => return,
+        };
+
+        spin_state.hooked = true;
+        drop(spin_state);
+        let spinner_wk = Arc::downgrade(self);
+        let revolution = self.props.speed.as_micros().max(1) as f32;
+
+        window.renderer_on_frame(move |elapsed_op| {
+            let spinner = match spinner_wk.upgrade() {
+                Some(some) => some,
+                None => return false,
+            };
+
+            let state = spinner.state.lock();
+            let mut spin_state = state.spin.borrow_mut();
+
+            // The hook is removed while stopped or hidden so no frames are wasted.
+            if !spin_state.running || !spin_state.visible {
+                spin_state.hooked = false;
+
+                if !spin_state.running {
+                    spinner.container.style_modify(|style| {
+                        style.user_vertexes.clear();
+                    });
+                }
+
+                return false;
+            }
+
+            if let Some(elapsed) = elapsed_op {
+                spin_state.angle = (spin_state.angle
+                    + ((elapsed.as_micros() as f32 / revolution) * PI * 2.0))
+                    % (PI * 2.0);
+            }
+
+            let bpu = spinner.container.post_update();
+            let width = bpu.tri[0] - bpu.tli[0];
+            let height = bpu.bli[1] - bpu.tli[1];
+
+            let vertexes = arc_verts(
+                [width / 2.0, height / 2.0],
+                width.min(height) / 2.0,
+                spin_state.angle,
+                spinner.theme.get().colors.accent1,
+            );
+
+            spinner.container.style_modify(|style| {
+                style.user_vertexes = vec![(ImageKey::INVALID, vertexes)];
+            });
+
+            true
+        });
+    }
+
+    fn style_update(&self) {
+        self.container
+            .style_update(BinStyle {
+                visibility: self.container.style_inspect(|style| style.visibility),
+                ..self.props.placement.clone().into_style()
+            })
+            .expect_valid();
+    }
+}
+
+impl Widget for Spinner {
+    fn focus(&self) {}
+
+    fn blur(&self) {}
+
+    fn is_visible(&self) -> bool {
+        Spinner::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        if let Some(spinner) = self.this.upgrade() {
+            Spinner::set_visible(&spinner, visible);
+        }
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        Spinner::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        Spinner::set_theme(self, theme)
+    }
+}
+
+const ARC_SEGMENTS: usize = 24;
+const ARC_LENGTH: f32 = PI * 1.5;
+
+/// Create the vertexes of a spinner's arc starting at `angle`.
+pub(crate) fn arc_verts(
+    center: [f32; 2],
+    outer_r: f32,
+    angle: f32,
+    color: Color,
+) -> Vec<BinVertex> {
+    let inner_r = outer_r * 0.7;
+    let mut vertexes = Vec::new();
+
+    if outer_r <= 0.0 {
+        return vertexes;
+    }
+
+    let point = |r: f32, a: f32| {
+        BinVertex {
+            x: Pixels(center[0] + (r * a.cos())),
+            y: Pixels(center[1] + (r * a.sin())),
+            color,
+            ..Default::default()
+        }
+    };
+
+    for i in 0..ARC_SEGMENTS {
+        let a1 = angle + (ARC_LENGTH * (i as f32 / ARC_SEGMENTS as f32));
+        let a2 = angle + (ARC_LENGTH * ((i + 1) as f32 / ARC_SEGMENTS as f32));
+
+        vertexes.extend([
+            point(outer_r, a1),
+            point(inner_r, a1),
+            point(inner_r, a2),
+            point(outer_r, a1),
+            point(inner_r, a2),
+            point(outer_r, a2),
+        ]);
+    }
+
+    vertexes
+}
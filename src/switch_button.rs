@@ -1,13 +1,14 @@
 use std::cell::RefCell;
 use std::sync::Arc;
 
-use basalt::input::MouseButton;
+use basalt::input::{MouseButton, Qwerty};
 use basalt::interface::UnitValue::{PctOfHeight, PctOffset, Percent, Pixels};
-use basalt::interface::{Bin, BinStyle, Position};
+use basalt::interface::{Bin, BinStyle, Color, Position, Visibility};
 use parking_lot::ReentrantMutex;
 
 use crate::builder::WidgetBuilder;
-use crate::{Theme, WidgetContainer, WidgetPlacement};
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
 
 /// Builder for [`SwitchButton`]
 pub struct SwitchButtonBuilder<'a, C> {
@@ -19,6 +20,9 @@ pub struct SwitchButtonBuilder<'a, C> {
 #[derive(Default)]
 struct Properties {
     enabled: bool,
+    on_color: Option<Color>,
+    off_color: Option<Color>,
+    knob_color: Option<Color>,
     placement: WidgetPlacement,
 }
 
@@ -26,6 +30,9 @@ impl Properties {
     fn new(placement: WidgetPlacement) -> Self {
         Self {
             enabled: false,
+            on_color: None,
+            off_color: None,
+            knob_color: None,
             placement,
         }
     }
@@ -37,12 +44,7 @@ where
 {
     pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
         Self {
-            props: Properties::new(
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| SwitchButton::default_placement(&builder.theme)),
-            ),
+            props: Properties::new(builder.take_placement(SwitchButton::default_placement)),
             widget: builder,
             on_change: Vec::new(),
         }
@@ -56,6 +58,30 @@ where
         self
     }
 
+    /// Set the color of the track when enabled.
+    ///
+    /// **Note**: When this isn't used the theme's `accent1` color will be used.
+    pub fn on_color(mut self, color: Color) -> Self {
+        self.props.on_color = Some(color);
+        self
+    }
+
+    /// Set the color of the track when disabled.
+    ///
+    /// **Note**: When this isn't used the theme's `back3` color will be used.
+    pub fn off_color(mut self, color: Color) -> Self {
+        self.props.off_color = Some(color);
+        self
+    }
+
+    /// Set the color of the knob.
+    ///
+    /// **Note**: When this isn't used the theme's `back1` color will be used.
+    pub fn knob_color(mut self, color: Color) -> Self {
+        self.props.knob_color = Some(color);
+        self
+    }
+
     /// Add a callback to be called when the [`SwitchButton`]'s value changed.
     ///
     /// **Note**: When changing the value within the callback, no callbacks will be called with
@@ -91,43 +117,59 @@ where
         container.add_child(knob.clone());
         let enabled = self.props.enabled;
 
+        let colors = SwitchColors {
+            on: self.props.on_color,
+            off: self.props.off_color,
+            knob: self.props.knob_color,
+        };
+
         let switch_button = Arc::new(SwitchButton {
-            theme: self.widget.theme,
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::Switch),
             props: self.props,
             container,
             knob,
             state: ReentrantMutex::new(State {
                 enabled: RefCell::new(enabled),
+                colors: RefCell::new(colors),
                 on_change: RefCell::new(self.on_change),
             }),
         });
 
-        let cb_switch_button = switch_button.clone();
+        for target in [&switch_button.container, &switch_button.knob] {
+            let cb_switch_button = switch_button.clone();
 
-        switch_button
-            .container
-            .on_press(MouseButton::Left, move |_, _, _| {
+            target.on_press(MouseButton::Left, move |_, _, _| {
                 cb_switch_button.toggle();
                 Default::default()
             });
 
-        let cb_switch_button = switch_button.clone();
+            for key in [Qwerty::Space, Qwerty::Enter] {
+                let cb_switch_button = switch_button.clone();
 
-        switch_button
-            .knob
-            .on_press(MouseButton::Left, move |_, _, _| {
-                cb_switch_button.toggle();
-                Default::default()
-            });
+                target.on_press(key, move |_, _, _| {
+                    cb_switch_button.toggle();
+                    Default::default()
+                });
+            }
+        }
 
         switch_button.style_update();
+        self.widget.container.widget_built(switch_button.clone());
         switch_button
     }
 }
 
 /// Switch button widget
+///
+/// **Note**: When focused, the [`SwitchButton`] can be toggled with space or enter.
 pub struct SwitchButton {
-    theme: Theme,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     container: Arc<Bin>,
     knob: Arc<Bin>,
@@ -136,28 +178,38 @@ pub struct SwitchButton {
 
 struct State {
     enabled: RefCell<bool>,
+    colors: RefCell<SwitchColors>,
     on_change: RefCell<Vec<Box<dyn FnMut(&Arc<SwitchButton>, bool) + Send + 'static>>>,
 }
 
+/// Colors set by the user, the theme's colors are used for those that are `None`.
+#[derive(Clone, Copy)]
+struct SwitchColors {
+    on: Option<Color>,
+    off: Option<Color>,
+    knob: Option<Color>,
+}
+
 impl SwitchButton {
     /// Set the enabled state.
     pub fn set(self: &Arc<Self>, enabled: bool) {
         let state = self.state.lock();
         *state.enabled.borrow_mut() = enabled;
+        let [on_color, off_color, _] = self.colors();
 
         if enabled {
             Bin::style_update_batch([
                 (
                     &self.container,
                     BinStyle {
-                        back_color: self.theme.colors.accent1,
+                        back_color: on_color,
                         ..self.container.style_copy()
                     },
                 ),
                 (
                     &self.knob,
                     BinStyle {
-                        pos_from_r: PctOffset(10.0, -self.theme.border.unwrap_or(0.0)),
+                        pos_from_r: PctOffset(10.0, -self.theme.get().border.unwrap_or(0.0)),
                         pos_from_l: Default::default(),
                         ..self.knob.style_copy()
                     },
@@ -168,14 +220,14 @@ impl SwitchButton {
                 (
                     &self.container,
                     BinStyle {
-                        back_color: self.theme.colors.back3,
+                        back_color: off_color,
                         ..self.container.style_copy()
                     },
                 ),
                 (
                     &self.knob,
                     BinStyle {
-                        pos_from_l: PctOffset(10.0, -self.theme.border.unwrap_or(0.0)),
+                        pos_from_l: PctOffset(10.0, -self.theme.get().border.unwrap_or(0.0)),
                         pos_from_r: Default::default(),
                         ..self.knob.style_copy()
                     },
@@ -203,6 +255,24 @@ impl SwitchButton {
         *self.state.lock().enabled.borrow()
     }
 
+    /// Set the color of the track when enabled.
+    pub fn set_on_color(&self, color: Color) {
+        self.state.lock().colors.borrow_mut().on = Some(color);
+        self.style_update();
+    }
+
+    /// Set the color of the track when disabled.
+    pub fn set_off_color(&self, color: Color) {
+        self.state.lock().colors.borrow_mut().off = Some(color);
+        self.style_update();
+    }
+
+    /// Set the color of the knob.
+    pub fn set_knob_color(&self, color: Color) {
+        self.state.lock().colors.borrow_mut().knob = Some(color);
+        self.style_update();
+    }
+
     /// Add a callback to be called when the [`SwitchButton`]'s value changed.
     ///
     /// **Note**: When changing the value within the callback, no callbacks will be called with
@@ -220,6 +290,74 @@ impl SwitchButton {
             .push(Box::new(on_change));
     }
 
+    /// Give keyboard focus to this [`SwitchButton`].
+    pub fn focus(&self) {
+        crate::focus_bin(&self.container);
+    }
+
+    /// Remove keyboard focus from this [`SwitchButton`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`SwitchButton`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.container);
+    }
+
+    /// Set if this [`SwitchButton`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`SwitchButton`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`SwitchButton`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`SwitchButton`] and re-apply its styling.
+    ///
+    /// **Note**: Colors set with the builder or setters, such as
+    /// [`set_on_color`](Self::set_on_color), are kept.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::Switch`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
     pub fn default_placement(theme: &Theme) -> WidgetPlacement {
         let height = theme.base_size;
@@ -237,14 +375,28 @@ impl SwitchButton {
         }
     }
 
+    fn colors(&self) -> [Color; 3] {
+        let colors = *self.state.lock().colors.borrow();
+        let theme = self.theme.get();
+
+        [
+            colors.on.unwrap_or(theme.colors.accent1),
+            colors.off.unwrap_or(theme.colors.back3),
+            colors.knob.unwrap_or(theme.colors.back1),
+        ]
+    }
+
     fn style_update(&self) {
-        let enabled = *self.state.lock().enabled.borrow();
+        let state = self.state.lock();
+        let enabled = *state.enabled.borrow();
+        let [on_color, off_color, knob_color] = self.colors();
 
         let mut container_style = BinStyle {
             border_radius_tl: PctOfHeight(50.0),
             border_radius_tr: PctOfHeight(50.0),
             border_radius_bl: PctOfHeight(50.0),
             border_radius_br: PctOfHeight(50.0),
+            visibility: self.container.style_inspect(|style| style.visibility),
             ..self.props.placement.clone().into_style()
         };
 
@@ -252,7 +404,7 @@ impl SwitchButton {
             pos_from_t: Percent(10.0),
             pos_from_b: Percent(10.0),
             width: PctOfHeight(80.0),
-            back_color: self.theme.colors.back1,
+            back_color: knob_color,
             border_radius_tl: PctOfHeight(50.0),
             border_radius_tr: PctOfHeight(50.0),
             border_radius_bl: PctOfHeight(50.0),
@@ -261,33 +413,59 @@ impl SwitchButton {
         };
 
         if enabled {
-            container_style.back_color = self.theme.colors.accent1;
-            knob_style.pos_from_r = PctOffset(10.0, -self.theme.border.unwrap_or(0.0));
+            container_style.back_color = on_color;
+            knob_style.pos_from_r = PctOffset(10.0, -self.theme.get().border.unwrap_or(0.0));
         } else {
-            container_style.back_color = self.theme.colors.back3;
-            knob_style.pos_from_l = PctOffset(10.0, -self.theme.border.unwrap_or(0.0));
+            container_style.back_color = off_color;
+            knob_style.pos_from_l = PctOffset(10.0, -self.theme.get().border.unwrap_or(0.0));
         }
 
-        if let Some(border_size) = self.theme.border {
+        if let Some(border_size) = self.theme.get().border {
             container_style.border_size_t = Pixels(border_size);
             container_style.border_size_b = Pixels(border_size);
             container_style.border_size_l = Pixels(border_size);
             container_style.border_size_r = Pixels(border_size);
-            container_style.border_color_t = self.theme.colors.border1;
-            container_style.border_color_b = self.theme.colors.border1;
-            container_style.border_color_l = self.theme.colors.border1;
-            container_style.border_color_r = self.theme.colors.border1;
+            container_style.border_color_t = self.theme.get().colors.border1;
+            container_style.border_color_b = self.theme.get().colors.border1;
+            container_style.border_color_l = self.theme.get().colors.border1;
+            container_style.border_color_r = self.theme.get().colors.border1;
 
             knob_style.border_size_t = Pixels(border_size);
             knob_style.border_size_b = Pixels(border_size);
             knob_style.border_size_l = Pixels(border_size);
             knob_style.border_size_r = Pixels(border_size);
-            knob_style.border_color_t = self.theme.colors.border3;
-            knob_style.border_color_b = self.theme.colors.border3;
-            knob_style.border_color_l = self.theme.colors.border3;
-            knob_style.border_color_r = self.theme.colors.border3;
+            knob_style.border_color_t = self.theme.get().colors.border3;
+            knob_style.border_color_b = self.theme.get().colors.border3;
+            knob_style.border_color_l = self.theme.get().colors.border3;
+            knob_style.border_color_r = self.theme.get().colors.border3;
         }
 
         Bin::style_update_batch([(&self.container, container_style), (&self.knob, knob_style)]);
     }
 }
+
+impl Widget for SwitchButton {
+    fn focus(&self) {
+        SwitchButton::focus(self)
+    }
+
+    fn blur(&self) {
+        SwitchButton::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        SwitchButton::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        SwitchButton::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        SwitchButton::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        SwitchButton::set_theme(self, theme)
+    }
+}
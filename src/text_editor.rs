@@ -1,29 +1,58 @@
+use std::cell::RefCell;
 use std::sync::Arc;
 
 use basalt::input::InputHookCtrl;
 use basalt::interface::UnitValue::Pixels;
 use basalt::interface::{
-    Bin, BinPostUpdate, BinStyle, Position, TextAttrs, TextBody, TextCursor, TextSpan,
+    Bin, BinPostUpdate, BinStyle, Color, FontFamily, FontWeight, PosTextCursor, Position,
+    TextAttrs, TextBody, TextBodyGuard, TextCursor, TextSelection, TextSpan, TextVertAlign,
+    TextWrap, Visibility, ZIndex,
 };
+use parking_lot::ReentrantMutex;
 
 use crate::builder::WidgetBuilder;
-use crate::{ScrollAxis, ScrollBar, Theme, WidgetContainer, WidgetPlacement, text_hooks, ulps_eq};
+use crate::theme::ThemeCell;
+use crate::{
+    ScrollAxis, ScrollBar, ScrollSource, Theme, Widget, WidgetContainer, WidgetPlacement,
+    WidgetRole, WordBoundary, text_hooks, ulps_eq,
+};
 
 /// Builder for [`TextEditor`]
 pub struct TextEditorBuilder<'a, C> {
     widget: WidgetBuilder<'a, C>,
     props: Properties,
     text_body: TextBody,
+    plmt_is_default: bool,
+    on_submit: Vec<Box<dyn FnMut(&Arc<TextEditor>, &str) + Send + 'static>>,
+    on_change: Vec<Box<dyn FnMut(&Arc<TextEditor>, &str) + Send + 'static>>,
+    on_cursor_move: Vec<Box<dyn FnMut(&Arc<TextEditor>, Option<(usize, usize)>) + Send + 'static>>,
+}
+
+/// Options for [`TextEditor::load`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadOptions {
+    /// Keep the scroll of the [`TextEditor`] rather than scrolling to the start.
+    ///
+    /// **Note**: The scroll is clamped to the overflow of the new value.
+    pub keep_scroll: bool,
 }
 
 #[derive(Default)]
 struct Properties {
+    single_line: bool,
+    auto_scroll: bool,
+    word_boundary: WordBoundary,
+    font: Option<(FontFamily, FontWeight)>,
     placement: WidgetPlacement,
 }
 
 impl Properties {
     fn new(placement: WidgetPlacement) -> Self {
         Self {
+            single_line: false,
+            auto_scroll: true,
+            word_boundary: WordBoundary::Punctuation,
+            font: None,
             placement,
         }
     }
@@ -35,33 +64,163 @@ where
 {
     pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
         Self {
-            props: Properties::new(
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| TextEditor::default_placement(&builder.theme)),
-            ),
+            plmt_is_default: builder.placement.is_none(),
+            props: Properties::new(builder.take_placement(TextEditor::default_placement)),
             text_body: TextBody {
                 base_attrs: TextAttrs {
                     color: builder.theme.colors.text1a,
                     height: Pixels(builder.theme.text_height),
                     font_family: builder.theme.font_family.clone(),
-                    font_weight: builder.theme.font_weight,
+                    font_weight: builder.theme.value_font_weight(),
                     ..Default::default()
                 },
                 spans: vec![TextSpan::default()],
+                selection_color: Color {
+                    a: 0.5,
+                    ..builder.theme.colors.accent1
+                },
                 ..Default::default()
             },
+            on_submit: Vec::new(),
+            on_change: Vec::new(),
+            on_cursor_move: Vec::new(),
             widget: builder,
         }
     }
 
+    /// Restrict the editor to a single line.
+    ///
+    /// **Notes**:
+    /// - Enter no longer inserts a newline, instead callbacks added with
+    ///   [`on_submit`](Self::on_submit) are called.
+    /// - The scroll bars are hidden and vertical cursor movement is disabled.
+    /// - If the placement is default, the height will be reduced to a single line. A size provided
+    ///   by [`WidgetBuilder::with_size`] takes precedence.
+    pub fn single_line(mut self, single_line: bool) -> Self {
+        self.props.single_line = single_line;
+
+        if self.plmt_is_default {
+            let default_placement = TextEditor::default_placement(&self.widget.theme);
+
+            self.props.placement = self.widget.apply_placement(
+                if single_line {
+                    WidgetPlacement {
+                        height: Pixels(self.widget.theme.spacing + self.widget.theme.base_size),
+                        ..default_placement
+                    }
+                } else {
+                    default_placement
+                },
+            );
+        }
+
+        self
+    }
+
+    /// Set if [`TextEditor::append`] should keep the view pinned to the bottom.
+    ///
+    /// When enabled and the view is scrolled to the bottom, appending text scrolls to the new
+    /// bottom. If the view has been scrolled up, the scroll is left alone.
+    ///
+    /// **Note**: When this isn't used this defaults to `true`.
+    pub fn auto_scroll(mut self, auto_scroll: bool) -> Self {
+        self.props.auto_scroll = auto_scroll;
+        self
+    }
+
+    /// Set how word boundaries are determined when moving the cursor or deleting with Ctrl.
+    ///
+    /// See [`WordBoundary`] docs for more information.
+    ///
+    /// **Note**: When this isn't used this defaults to [`WordBoundary::Punctuation`].
+    pub fn word_boundary(mut self, word_boundary: WordBoundary) -> Self {
+        self.props.word_boundary = word_boundary;
+        self
+    }
+
+    /// Add a callback to be called when Enter is pressed in single line mode.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_submit<F>(mut self, submit: F) -> Self
+    where
+        F: FnMut(&Arc<TextEditor>, &str) + Send + 'static,
+    {
+        self.on_submit.push(Box::new(submit));
+        self
+    }
+
+    /// Add a callback to be called when the value is changed.
+    ///
+    /// See [`TextEditor::on_change`] for more information.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_change<F>(mut self, on_change: F) -> Self
+    where
+        F: FnMut(&Arc<TextEditor>, &str) + Send + 'static,
+    {
+        self.on_change.push(Box::new(on_change));
+        self
+    }
+
+    /// Add a callback to be called when the cursor moves.
+    ///
+    /// See [`TextEditor::on_cursor_move`] for more information.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_cursor_move<F>(mut self, on_cursor_move: F) -> Self
+    where
+        F: FnMut(&Arc<TextEditor>, Option<(usize, usize)>) + Send + 'static,
+    {
+        self.on_cursor_move.push(Box::new(on_cursor_move));
+        self
+    }
+
     /// Set the initial text.
+    ///
+    /// **Note**: Line endings of `\r\n` are replaced with `\n`.
     pub fn with_text<T>(mut self, text: T) -> Self
     where
         T: Into<String>,
     {
-        self.text_body.spans[0] = TextSpan::from(text.into());
+        self.text_body.spans[0] = TextSpan::from(normalize_line_endings(text.into()));
+        self
+    }
+
+    /// Set the initial [`TextBody`].
+    ///
+    /// Unlike [`with_text`](Self::with_text), this keeps multiple spans along with their
+    /// attributes, allowing the initial text to be pre-colored.
+    ///
+    /// **Note**: The base attributes of the provided body are replaced with those from the theme
+    /// or those provided by [`with_attrs`](Self::with_attrs).
+    pub fn with_body(mut self, body: TextBody) -> Self {
+        let base_attrs = self.text_body.base_attrs.clone();
+        let selection_color = self.text_body.selection_color;
+        self.text_body = body;
+        self.text_body.base_attrs = base_attrs;
+        self.text_body.selection_color = selection_color;
+
+        if self.text_body.spans.is_empty() {
+            self.text_body.spans.push(TextSpan::default());
+        }
+
+        self
+    }
+
+    /// Set the font used, overriding the font of the theme.
+    ///
+    /// **Note**: This takes precedence over the font of the [`TextAttrs`] provided by
+    /// [`with_attrs`](Self::with_attrs).
+    pub fn font(mut self, family: FontFamily, weight: FontWeight) -> Self {
+        self.props.font = Some((family, weight));
+        self
+    }
+
+    /// Set the color used to highlight selected text.
+    ///
+    /// **Note**: When this isn't used the theme's `accent1` at half opacity is used.
+    pub fn selection_color(mut self, color: Color) -> Self {
+        self.text_body.selection_color = color;
         self
     }
 
@@ -72,7 +231,7 @@ where
     }
 
     /// Finish building the [`TextEditor`].
-    pub fn build(self) -> Arc<TextEditor> {
+    pub fn build(mut self) -> Arc<TextEditor> {
         let window = self
             .widget
             .container
@@ -80,11 +239,13 @@ where
             .window()
             .expect("The widget container must have an associated window.");
 
-        let mut bins = window.new_bins(2).into_iter();
+        let mut bins = window.new_bins(3).into_iter();
         let container = bins.next().unwrap();
         let editor = bins.next().unwrap();
+        let error_message = bins.next().unwrap();
 
         container.add_child(editor.clone());
+        container.add_child(error_message.clone());
 
         let sb_size = match ScrollBar::default_placement(&self.widget.theme, ScrollAxis::Y).width {
             Pixels(px) => px,
@@ -119,22 +280,54 @@ where
             .container_bin()
             .add_child(container.clone());
 
+        if self.props.single_line {
+            v_scroll_b.set_visible(false);
+            h_scroll_b.set_visible(false);
+            self.text_body.vert_align = TextVertAlign::Center;
+            self.text_body.text_wrap = TextWrap::None;
+        }
+
+        if let Some((family, weight)) = self.props.font.clone() {
+            self.text_body.base_attrs.font_family = family;
+            self.text_body.base_attrs.font_weight = weight;
+        }
+
         let text_editor = Arc::new(TextEditor {
-            theme: self.widget.theme,
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::TextBox),
             props: self.props,
             container,
             editor,
+            error_message,
             v_scroll_b,
             h_scroll_b,
+            state: ReentrantMutex::new(State {
+                on_submit: RefCell::new(self.on_submit),
+                on_change: RefCell::new(self.on_change),
+                on_cursor_move: RefCell::new(self.on_cursor_move),
+                cursor_position: RefCell::new(None),
+                error: RefCell::new(None),
+                focused: RefCell::new(false),
+                highlights: RefCell::new(Vec::new()),
+                highlight_bins: RefCell::new(Vec::new()),
+            }),
         });
 
         let text_editor_wk1 = Arc::downgrade(&text_editor);
         let text_editor_wk2 = Arc::downgrade(&text_editor);
 
+        let hook_props = text_hooks::Properties {
+            single_line: text_editor.props.single_line,
+            word_boundary: text_editor.props.word_boundary,
+            ..text_hooks::Properties::EDITOR
+        };
+
         text_hooks::create(
-            text_hooks::Properties::EDITOR,
+            hook_props,
             text_editor.editor.clone(),
-            text_editor.theme.clone(),
+            text_editor.theme.get().clone(),
             Some(Arc::new(move |updated| {
                 let text_hooks::Updated {
                     cursor: _,
@@ -144,35 +337,81 @@ where
                     editor_bpu,
                 } = updated;
 
-                if let Some(cursor_bounds) = cursor_bounds
-                    && let Some(text_editor) = text_editor_wk1.upgrade()
-                {
+                let text_editor = match text_editor_wk1.upgrade() {
+                    Some(some) => some,
+                    None => return,
+                };
+
+                if let Some(cursor_bounds) = cursor_bounds {
                     text_editor.check_cursor_in_view(editor_bpu, cursor_bounds);
                 }
+
+                text_editor.check_cursor_moved();
             })),
             Some(Arc::new(move |amt| {
                 if let Some(text_editor) = text_editor_wk2.upgrade() {
-                    text_editor.v_scroll_b.scroll(amt);
+                    text_editor
+                        .v_scroll_b
+                        .scroll_from(amt, ScrollSource::Keyboard);
                 }
             })),
         );
 
         let text_editor_wk = Arc::downgrade(&text_editor);
 
+        text_editor.editor.on_update(move |_, editor_bpu| {
+            if let Some(text_editor) = text_editor_wk.upgrade() {
+                text_editor.update_highlights(editor_bpu);
+            }
+        });
+
+        if text_editor.props.single_line {
+            let text_editor_wk = Arc::downgrade(&text_editor);
+
+            window
+                .basalt_ref()
+                .input_ref()
+                .hook()
+                .bin(&text_editor.editor)
+                .on_character()
+                .weight(1)
+                .call(move |_, _, c| {
+                    let text_editor = match text_editor_wk.upgrade() {
+                        Some(some) => some,
+                        None => return InputHookCtrl::Remove,
+                    };
+
+                    if !matches!(c.0, '\r' | '\n') {
+                        return Default::default();
+                    }
+
+                    text_editor.submit();
+                    InputHookCtrl::RetainNoPass
+                })
+                .finish()
+                .unwrap();
+        }
+
+        let text_editor_wk = Arc::downgrade(&text_editor);
+
         text_editor.editor.on_focus(move |_, _| {
             let text_editor = match text_editor_wk.upgrade() {
                 Some(some) => some,
                 None => return InputHookCtrl::Remove,
             };
 
-            let theme = &text_editor.theme;
+            let theme = &text_editor.theme.get();
+            *text_editor.state.lock().focused.borrow_mut() = true;
 
             if theme.border.is_some() {
+                let border_color =
+                    crate::field_border_color(theme, text_editor.error().is_some(), true);
+
                 text_editor.container.style_modify(|style| {
-                    style.border_color_t = theme.colors.accent2;
-                    style.border_color_b = theme.colors.accent2;
-                    style.border_color_l = theme.colors.accent2;
-                    style.border_color_r = theme.colors.accent2;
+                    style.border_color_t = border_color;
+                    style.border_color_b = border_color;
+                    style.border_color_l = border_color;
+                    style.border_color_r = border_color;
                 });
             }
 
@@ -187,14 +426,18 @@ where
                 None => return InputHookCtrl::Remove,
             };
 
-            let theme = &text_editor.theme;
+            let theme = &text_editor.theme.get();
+            *text_editor.state.lock().focused.borrow_mut() = false;
 
             if theme.border.is_some() {
+                let border_color =
+                    crate::field_border_color(theme, text_editor.error().is_some(), false);
+
                 text_editor.container.style_modify(|style| {
-                    style.border_color_t = theme.colors.border1;
-                    style.border_color_b = theme.colors.border1;
-                    style.border_color_l = theme.colors.border1;
-                    style.border_color_r = theme.colors.border1;
+                    style.border_color_t = border_color;
+                    style.border_color_b = border_color;
+                    style.border_color_l = border_color;
+                    style.border_color_r = border_color;
                 });
             }
 
@@ -202,18 +445,37 @@ where
         });
 
         text_editor.style_update(Some(self.text_body));
+        text_editor.set_error(None);
+        self.widget.container.widget_built(text_editor.clone());
         text_editor
     }
 }
 
 /// Text editor widget.
 pub struct TextEditor {
-    theme: Theme,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     container: Arc<Bin>,
     editor: Arc<Bin>,
+    error_message: Arc<Bin>,
     v_scroll_b: Arc<ScrollBar>,
     h_scroll_b: Arc<ScrollBar>,
+    state: ReentrantMutex<State>,
+}
+
+struct State {
+    on_submit: RefCell<Vec<Box<dyn FnMut(&Arc<TextEditor>, &str) + Send + 'static>>>,
+    on_change: RefCell<Vec<Box<dyn FnMut(&Arc<TextEditor>, &str) + Send + 'static>>>,
+    on_cursor_move:
+        RefCell<Vec<Box<dyn FnMut(&Arc<TextEditor>, Option<(usize, usize)>) + Send + 'static>>>,
+    cursor_position: RefCell<Option<(usize, usize)>>,
+    error: RefCell<Option<String>>,
+    focused: RefCell<bool>,
+    highlights: RefCell<Vec<(usize, usize)>>,
+    highlight_bins: RefCell<Vec<Arc<Bin>>>,
 }
 
 impl TextEditor {
@@ -228,17 +490,470 @@ impl TextEditor {
     }
 
     /// Set the value.
+    ///
+    /// **Note**: Line endings of `\r\n` are replaced with `\n`.
     pub fn set_value<V>(&self, value: V)
     where
         V: Into<String>,
     {
         self.editor.style_modify(|style| {
-            style.text_body.spans = vec![TextSpan::from(value.into())];
+            style.text_body.spans = vec![TextSpan::from(normalize_line_endings(value.into()))];
             style.text_body.cursor = TextCursor::None;
             style.text_body.selection = None;
         });
     }
 
+    /// Replace the value, such as when reloading a file.
+    ///
+    /// Unlike [`TextEditor::set_value`], this also sets the scroll as specified by the
+    /// [`LoadOptions`].
+    ///
+    /// **Note**: Line endings of `\r\n` are replaced with `\n`.
+    pub fn load(&self, text: &str, options: LoadOptions) {
+        let [scroll_x, scroll_y] = if options.keep_scroll {
+            [
+                self.h_scroll_b.target_scroll(),
+                self.v_scroll_b.target_scroll(),
+            ]
+        } else {
+            [0.0, 0.0]
+        };
+
+        self.set_value(text);
+        let h_scroll_b = self.h_scroll_b.clone();
+        let v_scroll_b = self.v_scroll_b.clone();
+
+        // The new overflow is only known once the editor has been updated.
+        self.editor.on_update_once(move |_, _| {
+            for (scroll_b, scroll) in [(h_scroll_b, scroll_x), (v_scroll_b, scroll_y)] {
+                scroll_b.refresh();
+                scroll_b.jump_to(scroll.min(scroll_b.target_overflow()).max(0.0));
+            }
+        });
+    }
+
+    /// Append text to the end of the value.
+    ///
+    /// The text takes on the attributes of the last span.
+    ///
+    /// **Note**: See [`TextEditorBuilder::auto_scroll`] for how this effects the scroll.
+    pub fn append(&self, text: &str) {
+        let pin_bottom = self.props.auto_scroll
+            && self.v_scroll_b.target_scroll() >= self.v_scroll_b.target_overflow() - 0.5;
+
+        self.editor.style_modify(|style| {
+            match style.text_body.spans.last_mut() {
+                Some(span) => span.text.push_str(text),
+                None => style.text_body.spans.push(TextSpan::from(text.to_string())),
+            }
+        });
+
+        if pin_bottom {
+            let v_scroll_b = self.v_scroll_b.clone();
+
+            // The new overflow is only known once the editor has been updated.
+            self.editor.on_update_once(move |_, _| {
+                v_scroll_b.refresh();
+                v_scroll_b.jump_to_max();
+            });
+        }
+    }
+
+    /// Obtain the number of lines of the value.
+    ///
+    /// **Notes**:
+    /// - Lines are separated by `\n`, lines created by wrapping aren't counted.
+    /// - An empty value has a single line.
+    pub fn line_count(&self) -> usize {
+        self.editor
+            .text_body()
+            .line_count(false)
+            .unwrap_or(0)
+            .max(1)
+    }
+
+    /// Obtain the zero-based index of the line the cursor is on.
+    ///
+    /// **Notes**:
+    /// - Like [`line_count`](Self::line_count), lines created by wrapping aren't counted.
+    /// - If there is no cursor, this is `0`.
+    pub fn current_line(&self) -> usize {
+        let text_body = self.editor.text_body();
+
+        text_body
+            .cursor_line_column(text_body.cursor(), false)
+            .map(|[line, _]| line)
+            .unwrap_or(0)
+    }
+
+    /// Insert text at the cursor.
+    ///
+    /// If there is a selection, it is replaced by the text. The cursor is placed after the inserted
+    /// text and scrolled into view, then callbacks added with [`on_change`](Self::on_change) are
+    /// called.
+    ///
+    /// **Note**: If the editor hasn't had a cursor yet, the text is inserted at the end.
+    pub fn insert(self: &Arc<Self>, text: &str) {
+        let text_body = self.editor.text_body();
+
+        if let Some(selection) = text_body.selection() {
+            text_body.clear_selection();
+            text_body.set_cursor(text_body.selection_delete(selection));
+        }
+
+        let cursor = match text_body.cursor() {
+            TextCursor::None => {
+                match text_body.select_all() {
+                    Some(selection) => selection.end.into(),
+                    None => TextCursor::Empty,
+                }
+            },
+            cursor => cursor,
+        };
+
+        text_body.set_cursor(text_body.cursor_insert_str(cursor, text.to_string()));
+
+        if let TextCursor::Position(cursor) = text_body.cursor() {
+            self.scroll_to_cursor(&text_body, cursor);
+        }
+
+        drop(text_body);
+        self.check_cursor_moved();
+        self.changed();
+    }
+
+    /// Delete the selected text.
+    ///
+    /// The cursor is placed where the selection started and scrolled into view.
+    ///
+    /// Returns `false` if there was no selection.
+    pub fn delete_selection(self: &Arc<Self>) -> bool {
+        let text_body = self.editor.text_body();
+
+        let selection = match text_body.selection() {
+            Some(some) => some,
+            None => return false,
+        };
+
+        text_body.clear_selection();
+        text_body.set_cursor(text_body.selection_delete(selection));
+
+        if let TextCursor::Position(cursor) = text_body.cursor() {
+            self.scroll_to_cursor(&text_body, cursor);
+        }
+
+        drop(text_body);
+        self.check_cursor_moved();
+        self.changed();
+        true
+    }
+
+    /// Obtain the current selection as character offsets.
+    ///
+    /// **Note**: The returned offsets are ordered, `start` will always be less than `end`.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        let text_body = self.editor.text_body();
+        let selection = text_body.selection()?;
+
+        Some((
+            text_hooks::cursor_offset(&text_body, selection.start)?,
+            text_hooks::cursor_offset(&text_body, selection.end)?,
+        ))
+    }
+
+    /// Set the selection given character offsets.
+    ///
+    /// **Notes**:
+    /// - Offsets are clamped to the end of the text.
+    /// - The cursor is placed at `end`.
+    /// - When `start` and `end` are equal the selection is cleared.
+    /// - This does not scroll the selection into view, see
+    ///   [`select_range_and_scroll`](Self::select_range_and_scroll).
+    pub fn set_selection(&self, start: usize, end: usize) {
+        let text_body = self.editor.text_body();
+        Self::apply_selection(&text_body, start, end);
+    }
+
+    /// Set the selection given character offsets and scroll the end of it into view.
+    ///
+    /// See [`set_selection`](Self::set_selection) for more information.
+    pub fn select_range_and_scroll(self: &Arc<Self>, start: usize, end: usize) {
+        let text_body = self.editor.text_body();
+
+        if let Some(cursor) = Self::apply_selection(&text_body, start, end) {
+            self.scroll_to_cursor(&text_body, cursor);
+        }
+    }
+
+    /// Find all occurrences of `needle` within the value.
+    ///
+    /// The returned ranges are character offsets, suitable for
+    /// [`highlight_ranges`](Self::highlight_ranges) and [`set_selection`](Self::set_selection).
+    ///
+    /// **Note**: Matches do not overlap.
+    pub fn find_all(&self, needle: &str, case_insensitive: bool) -> Vec<(usize, usize)> {
+        let needle = needle.chars().collect::<Vec<_>>();
+
+        if needle.is_empty() {
+            return Vec::new();
+        }
+
+        let haystack = self.value().chars().collect::<Vec<_>>();
+
+        let chars_eq = |a: char, b: char| {
+            a == b || (case_insensitive && a.to_lowercase().eq(b.to_lowercase()))
+        };
+
+        let mut matches = Vec::new();
+        let mut i = 0;
+
+        while i + needle.len() <= haystack.len() {
+            if haystack[i..(i + needle.len())]
+                .iter()
+                .zip(needle.iter())
+                .all(|(a, b)| chars_eq(*a, *b))
+            {
+                matches.push((i, i + needle.len()));
+                i += needle.len();
+            } else {
+                i += 1;
+            }
+        }
+
+        matches
+    }
+
+    /// Highlight the provided character ranges.
+    ///
+    /// This replaces any existing highlights. Providing no ranges clears the highlights.
+    ///
+    /// **Notes**:
+    /// - Highlighted text has a background of the theme's `accent2` at partial opacity.
+    /// - The text itself, including the attributes of its spans, the cursor and the selection
+    ///   are not modified.
+    /// - Ranges are character offsets and are not adjusted as the text is edited.
+    pub fn highlight_ranges(&self, ranges: Vec<(usize, usize)>) {
+        let len = self.value().chars().count();
+        *self.state.lock().highlights.borrow_mut() = normalize_ranges(ranges, len);
+        self.update_highlights(&self.editor.post_update());
+    }
+
+    /// Add a callback to be called when Enter is pressed in single line mode.
+    ///
+    /// The callback is called with the current value.
+    ///
+    /// **Note**: This is never called unless the editor was built with
+    /// [`single_line`](TextEditorBuilder::single_line).
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_submit<F>(&self, submit: F)
+    where
+        F: FnMut(&Arc<TextEditor>, &str) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_submit
+            .borrow_mut()
+            .push(Box::new(submit));
+    }
+
+    /// Add a callback to be called when the value is changed.
+    ///
+    /// The callback is called with the new value.
+    ///
+    /// **Note**: This is called by [`insert`](Self::insert) and
+    /// [`delete_selection`](Self::delete_selection). Input and other methods that modify the value
+    /// don't call this.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_change<F>(&self, on_change: F)
+    where
+        F: FnMut(&Arc<TextEditor>, &str) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_change
+            .borrow_mut()
+            .push(Box::new(on_change));
+    }
+
+    /// Add a callback to be called when the cursor moves.
+    ///
+    /// The callback is called with the new position as given by
+    /// [`cursor_position`](Self::cursor_position).
+    ///
+    /// **Note**: This is called for movements caused by input, such as clicking, using the arrow
+    /// keys or typing, and by [`insert`](Self::insert). Other methods that modify the value don't
+    /// call this.
+    ///
+    /// **Panics**: When adding a callback within the callback.
+    pub fn on_cursor_move<F>(&self, on_cursor_move: F)
+    where
+        F: FnMut(&Arc<TextEditor>, Option<(usize, usize)>) + Send + 'static,
+    {
+        self.state
+            .lock()
+            .on_cursor_move
+            .borrow_mut()
+            .push(Box::new(on_cursor_move));
+    }
+
+    /// Obtain the line and column of the cursor.
+    ///
+    /// **Notes**:
+    /// - Both the line and column start at `1`.
+    /// - Lines are separated by `\n`, lines created by wrapping aren't counted.
+    /// - Returns `None` if there is no cursor.
+    pub fn cursor_position(&self) -> Option<(usize, usize)> {
+        let text_body = self.editor.text_body();
+
+        text_body
+            .cursor_line_column(text_body.cursor(), false)
+            .map(|[line, column]| (line + 1, column + 1))
+    }
+
+    fn check_cursor_moved(self: &Arc<Self>) {
+        let position = self.cursor_position();
+        let state = self.state.lock();
+
+        if *state.cursor_position.borrow() == position {
+            return;
+        }
+
+        *state.cursor_position.borrow_mut() = position;
+
+        if let Ok(mut on_cursor_move_cbs) = state.on_cursor_move.try_borrow_mut() {
+            for on_cursor_move in on_cursor_move_cbs.iter_mut() {
+                on_cursor_move(self, position);
+            }
+        }
+    }
+
+    /// Set the color used to highlight selected text.
+    pub fn set_selection_color(&self, color: Color) {
+        self.editor.style_modify(|style| {
+            style.text_body.selection_color = color;
+        });
+    }
+
+    /// Obtain the color used to highlight selected text.
+    pub fn selection_color(&self) -> Color {
+        self.editor
+            .style_inspect(|style| style.text_body.selection_color)
+    }
+
+    /// Set or clear the error of this [`TextEditor`].
+    ///
+    /// When `Some`, the border is colored with the theme's `error` color and the message is
+    /// displayed below the [`TextEditor`]. When `None`, the normal styling is restored.
+    ///
+    /// **Notes**:
+    /// - The message may overlap neighboring widgets.
+    /// - The border is only displayed when the theme has a border.
+    pub fn set_error(&self, error: Option<String>) {
+        let state = self.state.lock();
+        crate::set_field_error(
+            &self.theme.get(),
+            &self.container,
+            &self.error_message,
+            error.as_deref(),
+            *state.focused.borrow(),
+        );
+        *state.error.borrow_mut() = error;
+    }
+
+    /// Obtain the current error provided by [`set_error`](Self::set_error).
+    pub fn error(&self) -> Option<String> {
+        self.state.lock().error.borrow().clone()
+    }
+
+    /// Obtain the [`ScrollBar`] that scrolls the text vertically.
+    ///
+    /// **Note**: The [`TextEditor`] scrolls this to keep the cursor in view, so scrolling it
+    /// elsewhere may be undone when the cursor moves.
+    pub fn vertical_scroll_bar(&self) -> &Arc<ScrollBar> {
+        &self.v_scroll_b
+    }
+
+    /// Obtain the [`ScrollBar`] that scrolls the text horizontally.
+    ///
+    /// **Note**: Like [`vertical_scroll_bar`](Self::vertical_scroll_bar), this is scrolled to
+    /// keep the cursor in view.
+    pub fn horizontal_scroll_bar(&self) -> &Arc<ScrollBar> {
+        &self.h_scroll_b
+    }
+
+    /// Give keyboard focus to this [`TextEditor`].
+    ///
+    /// This allows text to be entered without clicking on it first.
+    pub fn focus(&self) {
+        crate::focus_bin(&self.editor);
+    }
+
+    /// Remove keyboard focus from this [`TextEditor`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`TextEditor`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.editor);
+    }
+
+    /// Set if this [`TextEditor`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`TextEditor`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`TextEditor`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`TextEditor`] and re-apply its styling.
+    ///
+    /// **Note**: The [`TextAttrs`] and selection color of the text are kept.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.v_scroll_b.set_theme(theme.clone());
+        self.h_scroll_b.set_theme(theme.clone());
+        self.theme.set(theme);
+        self.style_update(None);
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::TextBox`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
     pub fn default_placement(theme: &Theme) -> WidgetPlacement {
         let height = theme.spacing + (theme.base_size * 9.0);
@@ -256,6 +971,170 @@ impl TextEditor {
         }
     }
 
+    fn submit(self: &Arc<Self>) {
+        let value = self.value();
+        let state = self.state.lock();
+
+        if let Ok(mut on_submit_cbs) = state.on_submit.try_borrow_mut() {
+            for on_submit in on_submit_cbs.iter_mut() {
+                on_submit(self, &value);
+            }
+        }
+    }
+
+    fn changed(self: &Arc<Self>) {
+        let value = self.value();
+        let state = self.state.lock();
+
+        if let Ok(mut on_change_cbs) = state.on_change.try_borrow_mut() {
+            for on_change in on_change_cbs.iter_mut() {
+                on_change(self, &value);
+            }
+        }
+    }
+
+    fn apply_selection(
+        text_body: &TextBodyGuard,
+        start: usize,
+        end: usize,
+    ) -> Option<PosTextCursor> {
+        let sel_start = text_hooks::cursor_at_offset(text_body, start)?;
+        let sel_end = text_hooks::cursor_at_offset(text_body, end)?;
+
+        if text_body.are_cursors_equivalent(sel_start.into(), sel_end.into()) {
+            text_body.clear_selection();
+        } else {
+            text_body.set_selection(TextSelection::unordered(sel_start, sel_end));
+        }
+
+        text_body.set_cursor(sel_end.into());
+        Some(sel_end)
+    }
+
+    fn scroll_to_cursor(self: &Arc<Self>, text_body: &TextBodyGuard, cursor: PosTextCursor) {
+        if let Some(cursor_bounds) = text_body.cursor_bounds(cursor.into()) {
+            let text_editor_wk = Arc::downgrade(self);
+
+            text_body.bin_on_update(move |_, editor_bpu| {
+                if let Some(text_editor) = text_editor_wk.upgrade() {
+                    text_editor.check_cursor_in_view(editor_bpu, cursor_bounds);
+                }
+            });
+        }
+    }
+
+    fn update_highlights(&self, editor_bpu: &BinPostUpdate) {
+        let state = self.state.lock();
+        let highlights = state.highlights.borrow();
+        let mut highlight_bins = state.highlight_bins.borrow_mut();
+
+        if highlights.is_empty() && highlight_bins.is_empty() {
+            return;
+        }
+
+        let mut rects = Vec::new();
+
+        {
+            let text_body = self.editor.text_body();
+
+            for &(start, end) in highlights.iter() {
+                let mut cursor = match text_hooks::cursor_at_offset(&text_body, start) {
+                    Some(some) => some,
+                    None => break,
+                };
+
+                let mut bounds_op = text_body.cursor_bounds(cursor.into());
+
+                for _ in start..end {
+                    let next = match text_body.cursor_next(cursor.into()) {
+                        TextCursor::None | TextCursor::Empty => break,
+                        TextCursor::Position(next) => next,
+                    };
+
+                    let next_bounds_op = text_body.cursor_bounds(next.into());
+
+                    if let (Some(bounds), Some(next_bounds)) = (bounds_op, next_bounds_op) {
+                        let x_min = if ulps_eq(bounds[2], next_bounds[2], 4) {
+                            bounds[0]
+                        } else {
+                            // The glyph is the first of the next line.
+                            text_body
+                                .cursor_bounds(text_body.cursor_line_start(next.into(), true))
+                                .map(|line_start| line_start[0])
+                                .unwrap_or(next_bounds[0])
+                        };
+
+                        push_highlight_rect(
+                            &mut rects,
+                            [x_min, next_bounds[0], next_bounds[2], next_bounds[3]],
+                        );
+                    }
+
+                    cursor = next;
+                    bounds_op = next_bounds_op;
+                }
+            }
+        }
+
+        // Only the portion within the editor is displayed.
+        let rects = rects
+            .into_iter()
+            .filter_map(|[x_min, x_max, y_min, y_max]| {
+                let x_min = x_min.max(editor_bpu.tli[0]);
+                let x_max = x_max.min(editor_bpu.tri[0]);
+                let y_min = y_min.max(editor_bpu.tli[1]);
+                let y_max = y_max.min(editor_bpu.bli[1]);
+                (x_max > x_min && y_max > y_min).then_some([x_min, x_max, y_min, y_max])
+            })
+            .collect::<Vec<_>>();
+
+        if rects.len() > highlight_bins.len() {
+            let window = match self.editor.window() {
+                Some(some) => some,
+                None => return,
+            };
+
+            for bin in window.new_bins(rects.len() - highlight_bins.len()) {
+                self.container.add_child(bin.clone());
+                highlight_bins.push(bin);
+            }
+        }
+
+        let back_color = Color {
+            a: 0.4,
+            ..self.theme.get().colors.accent2
+        };
+
+        Bin::style_update_batch(highlight_bins.iter().enumerate().map(|(i, bin)| {
+            // The editor is positioned at the top left of the container.
+            let style = match rects.get(i) {
+                Some(&[x_min, x_max, y_min, y_max]) => {
+                    BinStyle {
+                        position: Position::Relative,
+                        pos_from_t: Pixels(y_min - editor_bpu.tli[1]),
+                        pos_from_l: Pixels(x_min - editor_bpu.tli[0]),
+                        width: Pixels(x_max - x_min),
+                        height: Pixels(y_max - y_min),
+                        back_color,
+                        ..Default::default()
+                    }
+                },
+                None => {
+                    BinStyle {
+                        visibility: Visibility::Hide,
+                        ..Default::default()
+                    }
+                },
+            };
+
+            (bin, style)
+        }));
+
+        if highlights.is_empty() {
+            highlight_bins.clear();
+        }
+    }
+
     fn check_cursor_in_view(&self, editor_bpu: &BinPostUpdate, mut cursor_bounds: [f32; 4]) {
         let view_bounds = editor_bpu.optimal_content_bounds;
 
@@ -297,41 +1176,66 @@ impl TextEditor {
 
     fn style_update(&self, text_body_op: Option<TextBody>) {
         let mut container_style = self.props.placement.clone().into_style();
-        container_style.back_color = self.theme.colors.back2;
+        container_style.visibility = self.container.style_inspect(|style| style.visibility);
+        container_style.back_color = self.theme.get().colors.back2;
         let mut editor_style = BinStyle::default();
 
-        if let Some(text_body) = text_body_op {
-            editor_style.text_body = text_body;
-        }
+        // Without a new text body the current one is kept.
+        editor_style.text_body = match text_body_op {
+            Some(text_body) => text_body,
+            None => self.editor.style_inspect(|style| style.text_body.clone()),
+        };
 
         editor_style.position = Position::Relative;
         editor_style.pos_from_t = Pixels(0.0);
-        editor_style.pos_from_b = ScrollBar::default_placement(&self.theme, ScrollAxis::X).height;
+        editor_style.pos_from_b =
+            ScrollBar::default_placement(&self.theme.get(), ScrollAxis::X).height;
         editor_style.pos_from_l = Pixels(0.0);
-        editor_style.pos_from_r = ScrollBar::default_placement(&self.theme, ScrollAxis::Y).width;
-        editor_style.back_color = self.theme.colors.back2;
-        editor_style.padding_t = Pixels(self.theme.spacing);
-        editor_style.padding_b = Pixels(self.theme.spacing);
-        editor_style.padding_l = Pixels(self.theme.spacing);
-        editor_style.padding_r = Pixels(self.theme.spacing);
-
-        if let Some(border_size) = self.theme.border {
+        editor_style.pos_from_r =
+            ScrollBar::default_placement(&self.theme.get(), ScrollAxis::Y).width;
+        // Highlights are displayed between the container and the editor.
+        editor_style.z_index = ZIndex::Offset(1);
+        editor_style.padding_t = Pixels(self.theme.get().spacing);
+        editor_style.padding_b = Pixels(self.theme.get().spacing);
+        editor_style.padding_l = Pixels(self.theme.get().spacing);
+        editor_style.padding_r = Pixels(self.theme.get().spacing);
+
+        if let Some(border_size) = self.theme.get().border {
+            let border_color = crate::field_border_color(
+                &self.theme.get(),
+                self.error().is_some(),
+                *self.state.lock().focused.borrow(),
+            );
+
             container_style.border_size_t = Pixels(border_size);
             container_style.border_size_b = Pixels(border_size);
             container_style.border_size_l = Pixels(border_size);
             container_style.border_size_r = Pixels(border_size);
-            container_style.border_color_t = self.theme.colors.border1;
-            container_style.border_color_b = self.theme.colors.border1;
-            container_style.border_color_l = self.theme.colors.border1;
-            container_style.border_color_r = self.theme.colors.border1;
+            container_style.border_color_t = border_color;
+            container_style.border_color_b = border_color;
+            container_style.border_color_l = border_color;
+            container_style.border_color_r = border_color;
+        }
+
+        if self.props.single_line {
+            editor_style.pos_from_b = Pixels(0.0);
+            editor_style.pos_from_r = Pixels(0.0);
+            editor_style.padding_t = Pixels(0.0);
+            editor_style.padding_b = Pixels(0.0);
         }
 
-        if let Some(border_radius) = self.theme.roundness {
+        if let Some(border_radius) = self.theme.get().roundness {
             container_style.border_radius_tl = Pixels(border_radius);
             container_style.border_radius_tr = Pixels(border_radius);
             container_style.border_radius_bl = Pixels(border_radius);
             container_style.border_radius_br = Pixels(border_radius);
             editor_style.border_radius_tl = Pixels(border_radius);
+
+            if self.props.single_line {
+                editor_style.border_radius_tr = Pixels(border_radius);
+                editor_style.border_radius_bl = Pixels(border_radius);
+                editor_style.border_radius_br = Pixels(border_radius);
+            }
         }
 
         Bin::style_update_batch([
@@ -340,3 +1244,170 @@ impl TextEditor {
         ]);
     }
 }
+
+/// Sort, clamp and merge overlapping ranges, dropping any that are empty.
+fn normalize_ranges(mut ranges: Vec<(usize, usize)>, len: usize) -> Vec<(usize, usize)> {
+    ranges.sort_unstable();
+    let mut normalized: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+
+    for (start, end) in ranges {
+        let end = end.min(len);
+
+        if start >= end {
+            continue;
+        }
+
+        match normalized.last_mut() {
+            Some(last) if start <= last.1 => {
+                last.1 = last.1.max(end);
+            },
+            _ => normalized.push((start, end)),
+        }
+    }
+
+    normalized
+}
+
+/// Add the bounds of a glyph to the highlight rectangles, extending the last rectangle when the
+/// glyph follows it on the same line.
+///
+/// Bounds are `[x_min, x_max, y_min, y_max]`.
+fn push_highlight_rect(rects: &mut Vec<[f32; 4]>, glyph: [f32; 4]) {
+    if glyph[1] <= glyph[0] {
+        return;
+    }
+
+    if let Some(last) = rects.last_mut()
+        && ulps_eq(last[2], glyph[2], 4)
+        && glyph[0] <= last[1] + 0.5
+    {
+        last[1] = last[1].max(glyph[1]);
+        last[3] = last[3].max(glyph[3]);
+        return;
+    }
+
+    rects.push(glyph);
+}
+
+fn normalize_line_endings(text: String) -> String {
+    if text.contains('\r') {
+        text.replace("\r\n", "\n")
+    } else {
+        text
+    }
+}
+
+impl Widget for TextEditor {
+    fn focus(&self) {
+        TextEditor::focus(self)
+    }
+
+    fn blur(&self) {
+        TextEditor::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        TextEditor::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        TextEditor::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        TextEditor::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        TextEditor::set_theme(self, theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_crlf() {
+        assert_eq!(
+            normalize_line_endings(String::from("one\r\ntwo\r\nthree")),
+            "one\ntwo\nthree"
+        );
+    }
+
+    #[test]
+    fn normalize_lf_unchanged() {
+        assert_eq!(
+            normalize_line_endings(String::from("one\ntwo\n\nthree\n")),
+            "one\ntwo\n\nthree\n"
+        );
+    }
+
+    #[test]
+    fn normalize_mixed() {
+        assert_eq!(
+            normalize_line_endings(String::from("one\r\ntwo\nthree\r\n")),
+            "one\ntwo\nthree\n"
+        );
+    }
+
+    #[test]
+    fn normalize_lone_cr_kept() {
+        assert_eq!(
+            normalize_line_endings(String::from("one\rtwo\r\n")),
+            "one\rtwo\n"
+        );
+    }
+
+    #[test]
+    fn ranges_sorted_and_merged() {
+        assert_eq!(
+            normalize_ranges(vec![(8, 11), (0, 3), (2, 5), (5, 6)], 20),
+            vec![(0, 6), (8, 11)]
+        );
+    }
+
+    #[test]
+    fn ranges_clamped() {
+        assert_eq!(
+            normalize_ranges(vec![(4, 4), (6, 3), (8, 30), (25, 30)], 10),
+            vec![(8, 10)]
+        );
+    }
+
+    #[test]
+    fn highlight_rects_per_line() {
+        let mut rects = Vec::new();
+
+        // Three glyphs on the first line followed by two on the next.
+        for glyph in [
+            [0.0, 8.0, 0.0, 16.0],
+            [8.0, 16.0, 0.0, 16.0],
+            [16.0, 24.0, 0.0, 16.0],
+            [0.0, 8.0, 16.0, 32.0],
+            [8.0, 16.0, 16.0, 32.0],
+        ] {
+            push_highlight_rect(&mut rects, glyph);
+        }
+
+        assert_eq!(rects, vec![[0.0, 24.0, 0.0, 16.0], [0.0, 16.0, 16.0, 32.0]]);
+    }
+
+    #[test]
+    fn highlight_rects_skip_empty_glyphs() {
+        let mut rects = Vec::new();
+        push_highlight_rect(&mut rects, [0.0, 8.0, 0.0, 16.0]);
+        // A newline has no width.
+        push_highlight_rect(&mut rects, [8.0, 8.0, 0.0, 16.0]);
+        push_highlight_rect(&mut rects, [0.0, 0.0, 16.0, 32.0]);
+        assert_eq!(rects, vec![[0.0, 8.0, 0.0, 16.0]]);
+    }
+
+    #[test]
+    fn highlight_rects_separate_ranges() {
+        let mut rects = Vec::new();
+        push_highlight_rect(&mut rects, [0.0, 8.0, 0.0, 16.0]);
+        push_highlight_rect(&mut rects, [24.0, 32.0, 0.0, 16.0]);
+        assert_eq!(rects.len(), 2);
+    }
+}
@@ -4,11 +4,12 @@ use basalt::input::InputHookCtrl;
 use basalt::interface::UnitValue::Pixels;
 use basalt::interface::{
     Bin, BinPostUpdate, BinStyle, Position, TextAttrs, TextBody, TextCursor, TextHoriAlign,
-    TextSpan, TextVertAlign, TextWrap,
+    TextSpan, TextVertAlign, TextWrap, Visibility,
 };
 
 use crate::builder::WidgetBuilder;
-use crate::{Theme, WidgetContainer, WidgetPlacement, text_hooks};
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole, text_hooks};
 
 /// Builder for [`TextEntry`]
 pub struct TextEntryBuilder<'a, C> {
@@ -36,12 +37,7 @@ where
 {
     pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
         Self {
-            props: Properties::new(
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| TextEntry::default_placement(&builder.theme)),
-            ),
+            props: Properties::new(builder.take_placement(TextEntry::default_placement)),
             widget: builder,
             text: String::new(),
         }
@@ -74,7 +70,10 @@ where
             .add_child(entry.clone());
 
         let text_entry = Arc::new(TextEntry {
-            theme: self.widget.theme,
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::TextBox),
             props: self.props,
             entry,
         });
@@ -84,7 +83,7 @@ where
         text_hooks::create(
             text_hooks::Properties::ENTRY,
             text_entry.entry.clone(),
-            text_entry.theme.clone(),
+            text_entry.theme.get().clone(),
             Some(Arc::new(move |updated| {
                 let text_hooks::Updated {
                     cursor: _,
@@ -111,7 +110,7 @@ where
                 None => return InputHookCtrl::Remove,
             };
 
-            let theme = &text_entry.theme;
+            let theme = &text_entry.theme.get();
 
             if theme.border.is_some() {
                 text_entry.entry.style_modify(|style| {
@@ -133,7 +132,7 @@ where
                 None => return InputHookCtrl::Remove,
             };
 
-            let theme = &text_entry.theme;
+            let theme = &text_entry.theme.get();
 
             text_entry.entry.style_modify(|style| {
                 if theme.border.is_some() {
@@ -150,13 +149,17 @@ where
         });
 
         text_entry.style_update(self.text);
+        self.widget.container.widget_built(text_entry.clone());
         text_entry
     }
 }
 
 /// Text entry widget.
 pub struct TextEntry {
-    theme: Theme,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     entry: Arc<Bin>,
 }
@@ -184,6 +187,73 @@ impl TextEntry {
         });
     }
 
+    /// Give keyboard focus to this [`TextEntry`].
+    ///
+    /// This allows text to be entered without clicking on it first.
+    pub fn focus(&self) {
+        crate::focus_bin(&self.entry);
+    }
+
+    /// Remove keyboard focus from this [`TextEntry`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`TextEntry`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.entry);
+    }
+
+    /// Set if this [`TextEntry`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.entry.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`TextEntry`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.entry
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`TextEntry`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.entry)
+    }
+
+    /// Replace the [`Theme`] of this [`TextEntry`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update(self.value());
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::TextBox`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
     pub fn default_placement(theme: &Theme) -> WidgetPlacement {
         let height = theme.spacing + theme.base_size;
@@ -221,40 +291,41 @@ impl TextEntry {
 
     fn style_update(&self, text: String) {
         let mut container_style = self.props.placement.clone().into_style();
-        container_style.back_color = self.theme.colors.back2;
+        container_style.back_color = self.theme.get().colors.back2;
 
         let mut entry_style = BinStyle {
-            back_color: self.theme.colors.back2,
-            padding_l: Pixels(self.theme.spacing),
-            padding_r: Pixels(self.theme.spacing),
+            back_color: self.theme.get().colors.back2,
+            padding_l: Pixels(self.theme.get().spacing),
+            padding_r: Pixels(self.theme.get().spacing),
             text_body: TextBody {
                 hori_align: TextHoriAlign::Left,
                 vert_align: TextVertAlign::Center,
                 text_wrap: TextWrap::None,
                 base_attrs: TextAttrs {
-                    height: Pixels(self.theme.text_height),
-                    color: self.theme.colors.text1a,
-                    font_family: self.theme.font_family.clone(),
-                    font_weight: self.theme.font_weight,
+                    height: Pixels(self.theme.get().text_height),
+                    color: self.theme.get().colors.text1a,
+                    font_family: self.theme.get().font_family.clone(),
+                    font_weight: self.theme.get().value_font_weight(),
                     ..Default::default()
                 },
                 ..TextBody::from(text)
             },
+            visibility: self.entry.style_inspect(|style| style.visibility),
             ..self.props.placement.clone().into_style()
         };
 
-        if let Some(border_size) = self.theme.border {
+        if let Some(border_size) = self.theme.get().border {
             entry_style.border_size_t = Pixels(border_size);
             entry_style.border_size_b = Pixels(border_size);
             entry_style.border_size_l = Pixels(border_size);
             entry_style.border_size_r = Pixels(border_size);
-            entry_style.border_color_t = self.theme.colors.border1;
-            entry_style.border_color_b = self.theme.colors.border1;
-            entry_style.border_color_l = self.theme.colors.border1;
-            entry_style.border_color_r = self.theme.colors.border1;
+            entry_style.border_color_t = self.theme.get().colors.border1;
+            entry_style.border_color_b = self.theme.get().colors.border1;
+            entry_style.border_color_l = self.theme.get().colors.border1;
+            entry_style.border_color_r = self.theme.get().colors.border1;
         }
 
-        if let Some(border_radius) = self.theme.roundness {
+        if let Some(border_radius) = self.theme.get().roundness {
             entry_style.border_radius_tl = Pixels(border_radius);
             entry_style.border_radius_tr = Pixels(border_radius);
             entry_style.border_radius_bl = Pixels(border_radius);
@@ -264,3 +335,29 @@ impl TextEntry {
         self.entry.style_update(entry_style).expect_valid();
     }
 }
+
+impl Widget for TextEntry {
+    fn focus(&self) {
+        TextEntry::focus(self)
+    }
+
+    fn blur(&self) {
+        TextEntry::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        TextEntry::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        TextEntry::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        TextEntry::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        TextEntry::set_theme(self, theme)
+    }
+}
@@ -13,6 +13,18 @@ use parking_lot::Mutex;
 
 use crate::Theme;
 
+/// How word boundaries are determined when moving or deleting by word with Ctrl.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordBoundary {
+    /// Words are split at whitespace and punctuation, so `foo.bar` is three words.
+    ///
+    /// This is the default.
+    #[default]
+    Punctuation,
+    /// Words are only split at whitespace, so `foo.bar` is a single word.
+    Whitespace,
+}
+
 #[derive(Clone, Copy)]
 pub struct Properties {
     pub single_line: bool,
@@ -20,6 +32,7 @@ pub struct Properties {
     pub use_display_lines: bool,
     pub allow_modifications: bool,
     pub allow_cursor_to_selection: bool,
+    pub word_boundary: WordBoundary,
 }
 
 #[allow(dead_code)]
@@ -30,6 +43,7 @@ impl Properties {
         use_display_lines: false,
         allow_modifications: true,
         allow_cursor_to_selection: true,
+        word_boundary: WordBoundary::Punctuation,
     };
     pub const EDITOR: Self = Self {
         single_line: false,
@@ -37,6 +51,7 @@ impl Properties {
         use_display_lines: true,
         allow_modifications: true,
         allow_cursor_to_selection: true,
+        word_boundary: WordBoundary::Punctuation,
     };
     pub const ENTRY: Self = Self {
         single_line: true,
@@ -44,6 +59,7 @@ impl Properties {
         use_display_lines: false,
         allow_modifications: true,
         allow_cursor_to_selection: true,
+        word_boundary: WordBoundary::Punctuation,
     };
     pub const LABEL: Self = Self {
         single_line: false,
@@ -51,6 +67,7 @@ impl Properties {
         use_display_lines: true,
         allow_modifications: false,
         allow_cursor_to_selection: false,
+        word_boundary: WordBoundary::Punctuation,
     };
 }
 
@@ -311,6 +328,47 @@ pub fn create(
     }
 }
 
+/// Obtain the cursor at the provided character offset.
+///
+/// **Note**: The offset is clamped to the end of the body.
+pub fn cursor_at_offset(text_body: &TextBodyGuard, offset: usize) -> Option<PosTextCursor> {
+    let mut cursor = text_body.select_all()?.start;
+
+    for _ in 0..offset {
+        cursor = match text_body.cursor_next(cursor.into()) {
+            TextCursor::None | TextCursor::Empty => break,
+            TextCursor::Position(next) => {
+                if text_body.are_cursors_equivalent(cursor.into(), next.into()) {
+                    break;
+                }
+
+                next
+            },
+        };
+    }
+
+    Some(cursor)
+}
+
+/// Obtain the character offset of the provided cursor.
+pub fn cursor_offset(text_body: &TextBodyGuard, cursor: PosTextCursor) -> Option<usize> {
+    let start = text_body.select_all()?.start;
+
+    if text_body.are_cursors_equivalent(start.into(), cursor.into()) {
+        return Some(0);
+    }
+
+    Some(
+        text_body
+            .selection_string(TextSelection {
+                start,
+                end: cursor,
+            })
+            .chars()
+            .count(),
+    )
+}
+
 struct Hooks {
     basalt: Arc<Basalt>,
     properties: Properties,
@@ -1105,6 +1163,14 @@ impl Hooks {
         cursor: PosTextCursor,
         op: NextWordLineOp,
     ) -> PosTextCursor {
+        if self.properties.word_boundary == WordBoundary::Whitespace {
+            match op {
+                NextWordLineOp::WordStart => return whitespace_word_edge(text_body, cursor, false),
+                NextWordLineOp::WordEnd => return whitespace_word_edge(text_body, cursor, true),
+                NextWordLineOp::LineStart | NextWordLineOp::LineEnd => (),
+            }
+        }
+
         let edge = match match op {
             NextWordLineOp::WordStart => text_body.cursor_word_start(cursor.into()),
             NextWordLineOp::WordEnd => text_body.cursor_word_end(cursor.into()),
@@ -1191,6 +1257,69 @@ impl Hooks {
     }
 }
 
+/// Move the cursor past any whitespace and then to the edge of the following word.
+fn whitespace_word_edge(
+    text_body: &TextBodyGuard,
+    mut cursor: PosTextCursor,
+    forward: bool,
+) -> PosTextCursor {
+    let mut in_word = false;
+
+    loop {
+        let adjacent = if forward {
+            text_body.cursor_next(cursor.into())
+        } else {
+            text_body.cursor_prev(cursor.into())
+        };
+
+        let adjacent = match adjacent {
+            TextCursor::None | TextCursor::Empty => break,
+            TextCursor::Position(adjacent) => adjacent,
+        };
+
+        if text_body.are_cursors_equivalent(cursor.into(), adjacent.into()) {
+            break;
+        }
+
+        let selection = if forward {
+            TextSelection {
+                start: cursor,
+                end: adjacent,
+            }
+        } else {
+            TextSelection {
+                start: adjacent,
+                end: cursor,
+            }
+        };
+
+        let is_whitespace = text_body
+            .selection_string(selection)
+            .chars()
+            .all(char::is_whitespace);
+
+        if !whitespace_word_step(&mut in_word, is_whitespace) {
+            break;
+        }
+
+        cursor = adjacent;
+    }
+
+    cursor
+}
+
+/// Check if the cursor should move over the next character when moving to the edge of a word.
+///
+/// Whitespace before the word is skipped and the first whitespace after the word is the edge.
+fn whitespace_word_step(in_word: &mut bool, is_whitespace: bool) -> bool {
+    if is_whitespace {
+        !*in_word
+    } else {
+        *in_word = true;
+        true
+    }
+}
+
 enum NextWordLineOp {
     WordStart,
     WordEnd,
@@ -1263,3 +1392,68 @@ impl BitXorAssign for Modifiers {
         *self = Self(self.0 ^ rhs.0);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Move from the character offset as [`whitespace_word_edge`] would within `text`.
+    fn whitespace_word_edge_offset(text: &str, mut offset: usize, forward: bool) -> usize {
+        let chars = text.chars().collect::<Vec<_>>();
+        let mut in_word = false;
+
+        loop {
+            let c = match forward {
+                true if offset < chars.len() => chars[offset],
+                false if offset > 0 => chars[offset - 1],
+                _ => break,
+            };
+
+            if !whitespace_word_step(&mut in_word, c.is_whitespace()) {
+                break;
+            }
+
+            if forward {
+                offset += 1;
+            } else {
+                offset -= 1;
+            }
+        }
+
+        offset
+    }
+
+    /// Delete the word before the character offset as Ctrl+Backspace would.
+    fn ctrl_backspace(text: &str, offset: usize) -> String {
+        let start = whitespace_word_edge_offset(text, offset, false);
+        text.chars()
+            .take(start)
+            .chain(text.chars().skip(offset))
+            .collect()
+    }
+
+    const TEXT: &str = "foo.bar baz";
+
+    #[test]
+    fn whitespace_word_end() {
+        assert_eq!(whitespace_word_edge_offset(TEXT, 0, true), 7);
+        assert_eq!(whitespace_word_edge_offset(TEXT, 3, true), 7);
+        assert_eq!(whitespace_word_edge_offset(TEXT, 7, true), 11);
+        assert_eq!(whitespace_word_edge_offset(TEXT, 11, true), 11);
+    }
+
+    #[test]
+    fn whitespace_word_start() {
+        assert_eq!(whitespace_word_edge_offset(TEXT, 11, false), 8);
+        assert_eq!(whitespace_word_edge_offset(TEXT, 8, false), 0);
+        assert_eq!(whitespace_word_edge_offset(TEXT, 5, false), 0);
+        assert_eq!(whitespace_word_edge_offset(TEXT, 0, false), 0);
+    }
+
+    #[test]
+    fn whitespace_ctrl_backspace() {
+        assert_eq!(ctrl_backspace(TEXT, 11), "foo.bar ");
+        assert_eq!(ctrl_backspace("foo.bar ", 8), "");
+        assert_eq!(ctrl_backspace(TEXT, 7), " baz");
+    }
+}
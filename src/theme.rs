@@ -1,4 +1,5 @@
 use basalt::interface::{Color, FontFamily, FontWeight};
+use parking_lot::{RwLock, RwLockReadGuard};
 
 /// The theme used for widgets.
 ///
@@ -10,8 +11,29 @@ pub struct Theme {
     pub base_size: f32,
     pub font_family: FontFamily,
     pub font_weight: FontWeight,
+    /// The font weight used for labels, such as the text of a button.
+    ///
+    /// **Note**: Falls back to `font_weight` when `None`.
+    pub label_font_weight: Option<FontWeight>,
+    /// The font weight used for values, such as the text of an entry.
+    ///
+    /// **Note**: Falls back to `font_weight` when `None`.
+    pub value_font_weight: Option<FontWeight>,
     pub border: Option<f32>,
     pub roundness: Option<f32>,
+    /// Mirror the layout of widgets for right-to-left locales.
+    ///
+    /// **Note**: This is currently only respected by [`SpinButton`](crate::SpinButton), which
+    /// places its buttons on the left and its entry on the right.
+    pub rtl: bool,
+    /// Reduce the motion of widgets, such as for users that prefer less animation.
+    ///
+    /// When enabled:
+    /// - [`ScrollBar`](crate::ScrollBar)'s default to no smooth scroll or acceleration, including
+    /// those created internally by other widgets.
+    /// - [`ProgressBar`](crate::ProgressBar)'s don't animate their fill or stripes.
+    /// - [`Section`](crate::Section)'s collapse and expand instantly unless a duration is set.
+    pub reduce_motion: bool,
     pub colors: ThemeColors,
 }
 
@@ -24,8 +46,12 @@ impl Theme {
             base_size: 20.0,
             font_family: FontFamily::Serif,
             font_weight: FontWeight::Normal,
+            label_font_weight: None,
+            value_font_weight: None,
             border: Some(1.0),
             roundness: Some(3.0),
+            rtl: false,
+            reduce_motion: false,
             colors: ThemeColors::light(),
         }
     }
@@ -34,6 +60,31 @@ impl Theme {
     pub fn dark() -> Self {
         todo!()
     }
+
+    /// Create a copy of this theme with its sizes multiplied by `factor`.
+    ///
+    /// This scales `spacing`, `text_height`, `base_size`, `border` and `roundness`.
+    ///
+    /// **Note**: Basalt already applies the window's interface scale to pixel values, so this
+    /// is only needed for scaling in addition to that, such as a user zoom preference.
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            spacing: self.spacing * factor,
+            text_height: self.text_height * factor,
+            base_size: self.base_size * factor,
+            border: self.border.map(|border| border * factor),
+            roundness: self.roundness.map(|roundness| roundness * factor),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn label_font_weight(&self) -> FontWeight {
+        self.label_font_weight.unwrap_or(self.font_weight)
+    }
+
+    pub(crate) fn value_font_weight(&self) -> FontWeight {
+        self.value_font_weight.unwrap_or(self.font_weight)
+    }
 }
 
 impl Default for Theme {
@@ -42,9 +93,29 @@ impl Default for Theme {
     }
 }
 
+/// Holds the [`Theme`] of a widget, allowing it to be replaced after the widget is built.
+pub(crate) struct ThemeCell(RwLock<Theme>);
+
+impl ThemeCell {
+    pub(crate) fn new(theme: Theme) -> Self {
+        Self(RwLock::new(theme))
+    }
+
+    pub(crate) fn get(&self) -> RwLockReadGuard<'_, Theme> {
+        self.0.read_recursive()
+    }
+
+    pub(crate) fn set(&self, theme: Theme) {
+        *self.0.write() = theme;
+    }
+}
+
 /// [`Color`](basalt::interface::Color)'s used by [`Theme`]
 ///
-/// **Note**: The `Default` impl defaults to Basalt's light color pallete.
+/// **Notes**:
+/// - The `Default` impl defaults to Basalt's light color pallete.
+/// - Fields may be added in the future. To avoid breakage, prefer constructing this with
+/// [`ThemeColorsBuilder`] or struct update syntax from an existing [`ThemeColors`].
 #[derive(Debug, Clone)]
 pub struct ThemeColors {
     pub back1: Color,
@@ -58,6 +129,8 @@ pub struct ThemeColors {
     pub border1: Color,
     pub border2: Color,
     pub border3: Color,
+    /// The color used to indicate an error, such as the border of an invalid field.
+    pub error: Color,
 }
 
 impl ThemeColors {
@@ -75,6 +148,7 @@ impl ThemeColors {
             border1: Color::shex("5e585b"),
             border2: Color::shex("685e63"),
             border3: Color::shex("72656b"),
+            error: Color::shex("d4213d"),
         }
     }
 
@@ -82,6 +156,11 @@ impl ThemeColors {
     pub fn dark() -> Self {
         todo!()
     }
+
+    /// Create a [`ThemeColorsBuilder`].
+    pub fn builder() -> ThemeColorsBuilder {
+        ThemeColorsBuilder::default()
+    }
 }
 
 impl Default for ThemeColors {
@@ -89,3 +168,349 @@ impl Default for ThemeColors {
         Self::light()
     }
 }
+
+/// Builder for [`ThemeColors`].
+///
+/// Roles that aren't set are derived from other roles, so a usable pallete can be created from
+/// only a few colors. See the individual methods for how each role is derived.
+///
+/// **Note**: In debug builds, [`build`](Self::build) will panic if the contrast between text and
+/// the backgrounds it is displayed on is too low.
+#[derive(Debug, Clone, Default)]
+pub struct ThemeColorsBuilder {
+    back1: Option<Color>,
+    back2: Option<Color>,
+    back3: Option<Color>,
+    back4: Option<Color>,
+    accent1: Option<Color>,
+    accent2: Option<Color>,
+    text1a: Option<Color>,
+    text1b: Option<Color>,
+    border1: Option<Color>,
+    border2: Option<Color>,
+    border3: Option<Color>,
+    error: Option<Color>,
+}
+
+impl ThemeColorsBuilder {
+    /// Set `back1`.
+    ///
+    /// **Note**: If not set this defaults to the light pallete's `back1`.
+    pub fn back1(mut self, color: Color) -> Self {
+        self.back1 = Some(color);
+        self
+    }
+
+    /// Set `back2`.
+    ///
+    /// **Note**: If not set this is derived by darkening `back1`.
+    pub fn back2(mut self, color: Color) -> Self {
+        self.back2 = Some(color);
+        self
+    }
+
+    /// Set `back3`.
+    ///
+    /// **Note**: If not set this is derived by darkening `back1`.
+    pub fn back3(mut self, color: Color) -> Self {
+        self.back3 = Some(color);
+        self
+    }
+
+    /// Set `back4`.
+    ///
+    /// **Note**: If not set this is derived by darkening `back1`.
+    pub fn back4(mut self, color: Color) -> Self {
+        self.back4 = Some(color);
+        self
+    }
+
+    /// Set `accent1`.
+    ///
+    /// **Note**: If not set this defaults to the light pallete's `accent1`.
+    pub fn accent1(mut self, color: Color) -> Self {
+        self.accent1 = Some(color);
+        self
+    }
+
+    /// Set `accent2`.
+    ///
+    /// **Note**: If not set this is derived by darkening `accent1`.
+    pub fn accent2(mut self, color: Color) -> Self {
+        self.accent2 = Some(color);
+        self
+    }
+
+    /// Set `text1a`, the color of text displayed on backgrounds.
+    ///
+    /// **Note**: If not set this defaults to the light pallete's `text1a`.
+    pub fn text1a(mut self, color: Color) -> Self {
+        self.text1a = Some(color);
+        self
+    }
+
+    /// Set `text1b`, the color of text displayed on accents.
+    ///
+    /// **Note**: If not set this defaults to `back1`.
+    pub fn text1b(mut self, color: Color) -> Self {
+        self.text1b = Some(color);
+        self
+    }
+
+    /// Set `border1`.
+    ///
+    /// **Note**: If not set this defaults to the light pallete's `border1`.
+    pub fn border1(mut self, color: Color) -> Self {
+        self.border1 = Some(color);
+        self
+    }
+
+    /// Set `border2`.
+    ///
+    /// **Note**: If not set this is derived by lightening `border1`.
+    pub fn border2(mut self, color: Color) -> Self {
+        self.border2 = Some(color);
+        self
+    }
+
+    /// Set `border3`.
+    ///
+    /// **Note**: If not set this is derived by lightening `border1`.
+    pub fn border3(mut self, color: Color) -> Self {
+        self.border3 = Some(color);
+        self
+    }
+
+    /// Set `error`, the color used to indicate an error.
+    ///
+    /// **Note**: If not set this defaults to the light pallete's `error`.
+    pub fn error(mut self, color: Color) -> Self {
+        self.error = Some(color);
+        self
+    }
+
+    /// Finish building the [`ThemeColors`].
+    ///
+    /// **Panics**: In debug builds, when the contrast ratio between `text1a` and `back1`-`back3`
+    /// or between `text1b` and `accent1`-`accent2` is less than `3.0`.
+    pub fn build(self) -> ThemeColors {
+        let light = ThemeColors::light();
+        let back1 = self.back1.unwrap_or(light.back1);
+        let accent1 = self.accent1.unwrap_or(light.accent1);
+        let border1 = self.border1.unwrap_or(light.border1);
+
+        let colors = ThemeColors {
+            back1,
+            back2: self.back2.unwrap_or_else(|| scale_color(back1, 0.93)),
+            back3: self.back3.unwrap_or_else(|| scale_color(back1, 0.78)),
+            back4: self.back4.unwrap_or_else(|| scale_color(back1, 0.7)),
+            accent1,
+            accent2: self.accent2.unwrap_or_else(|| scale_color(accent1, 0.9)),
+            text1a: self.text1a.unwrap_or(light.text1a),
+            text1b: self.text1b.unwrap_or(back1),
+            border1,
+            border2: self.border2.unwrap_or_else(|| scale_color(border1, 1.15)),
+            border3: self.border3.unwrap_or_else(|| scale_color(border1, 1.3)),
+            error: self.error.unwrap_or(light.error),
+        };
+
+        for (text, text_name, back, back_name) in [
+            (colors.text1a, "text1a", colors.back1, "back1"),
+            (colors.text1a, "text1a", colors.back2, "back2"),
+            (colors.text1a, "text1a", colors.back3, "back3"),
+            (colors.text1b, "text1b", colors.accent1, "accent1"),
+            (colors.text1b, "text1b", colors.accent2, "accent2"),
+        ] {
+            debug_assert!(
+                contrast_ratio(text, back) >= MIN_CONTRAST_RATIO,
+                "The contrast between `{}` and `{}` is too low.",
+                text_name,
+                back_name,
+            );
+        }
+
+        colors
+    }
+}
+
+const MIN_CONTRAST_RATIO: f32 = 3.0;
+
+/// An error than can occur from [`ColorHex::from_hex`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorHexError {
+    /// The number of digits isn't 3, 6 or 8.
+    InvalidLength,
+    /// A character that isn't a hexadecimal digit was encountered.
+    InvalidDigit,
+}
+
+/// Conversion of [`Color`](basalt::interface::Color) to and from hex notation.
+///
+/// This makes translating design tokens into [`ThemeColors`] simpler.
+///
+/// ```ignore
+/// let colors = ThemeColors::builder()
+///     .back1(Color::from_hex("#2E3440")?)
+///     .accent1(Color::from_hex("#88C0D0")?)
+///     .build();
+/// ```
+///
+/// **Note**: Like [`Color::shex`](basalt::interface::Color::shex), the hex notation is sRGB and
+/// is converted to and from the linear components of [`Color`](basalt::interface::Color).
+pub trait ColorHex: Sized {
+    /// Parse a color in the format of `#RGB`, `#RRGGBB` or `#RRGGBBAA`.
+    ///
+    /// **Note**: The leading `#` is optional.
+    fn from_hex(hex: &str) -> Result<Self, ColorHexError>;
+
+    /// Format the color as `#RRGGBBAA`.
+    ///
+    /// **Note**: The alpha is omitted, formatting as `#RRGGBB`, when it is fully opaque.
+    fn to_hex(&self) -> String;
+}
+
+impl ColorHex for Color {
+    fn from_hex(hex: &str) -> Result<Self, ColorHexError> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        let mut digits = Vec::with_capacity(8);
+
+        for c in hex.chars() {
+            digits.push(c.to_digit(16).ok_or(ColorHexError::InvalidDigit)? as u8);
+        }
+
+        let channels: Vec<u8> = match digits.len() {
+            3 => digits.iter().map(|d| (d << 4) | d).collect(),
+            6 | 8 => digits.chunks(2).map(|d| (d[0] << 4) | d[1]).collect(),
+            _ => return Err(ColorHexError::InvalidLength),
+        };
+
+        Ok(Color {
+            r: srgb_to_linear(channels[0]),
+            g: srgb_to_linear(channels[1]),
+            b: srgb_to_linear(channels[2]),
+            a: channels.get(3).map(|a| *a as f32 / 255.0).unwrap_or(1.0),
+        })
+    }
+
+    fn to_hex(&self) -> String {
+        let a = (self.a.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        let mut hex = format!(
+            "#{:02X}{:02X}{:02X}",
+            linear_to_srgb(self.r),
+            linear_to_srgb(self.g),
+            linear_to_srgb(self.b),
+        );
+
+        if a != 255 {
+            hex.push_str(&format!("{:02X}", a));
+        }
+
+        hex
+    }
+}
+
+fn srgb_to_linear(v: u8) -> f32 {
+    let v = v as f32 / 255.0;
+
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(v: f32) -> u8 {
+    let v = v.clamp(0.0, 1.0);
+
+    let v = if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        (1.055 * v.powf(1.0 / 2.4)) - 0.055
+    };
+
+    (v * 255.0).round() as u8
+}
+
+fn scale_color(color: Color, factor: f32) -> Color {
+    Color {
+        r: (color.r * factor).clamp(0.0, 1.0),
+        g: (color.g * factor).clamp(0.0, 1.0),
+        b: (color.b * factor).clamp(0.0, 1.0),
+        a: color.a,
+    }
+}
+
+fn contrast_ratio(a: Color, b: Color) -> f32 {
+    // Color components are linear, so relative luminance can be computed directly.
+    let luminance = |c: Color| (0.2126 * c.r) + (0.7152 * c.g) + (0.0722 * c.b);
+    let [a_lum, b_lum] = [luminance(a), luminance(b)];
+    (a_lum.max(b_lum) + 0.05) / (a_lum.min(b_lum) + 0.05)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_hex_rgb() {
+        let color = Color::from_hex("#F0A").unwrap();
+        assert_eq!(color.to_hex(), "#FF00AA");
+        assert_eq!(color.a, 1.0);
+    }
+
+    #[test]
+    fn from_hex_rrggbb() {
+        let color = Color::from_hex("#1E90FF").unwrap();
+        assert_eq!(color.r, srgb_to_linear(0x1E));
+        assert_eq!(color.g, srgb_to_linear(0x90));
+        assert_eq!(color.b, srgb_to_linear(0xFF));
+        assert_eq!(color.a, 1.0);
+    }
+
+    #[test]
+    fn from_hex_rrggbbaa() {
+        let color = Color::from_hex("#1E90FF80").unwrap();
+        assert_eq!(color.a, 128.0 / 255.0);
+        assert_eq!(color.to_hex(), "#1E90FF80");
+    }
+
+    #[test]
+    fn from_hex_without_prefix() {
+        assert_eq!(Color::from_hex("1e90ff").unwrap().to_hex(), "#1E90FF");
+    }
+
+    #[test]
+    fn to_hex_round_trip() {
+        for hex in [
+            "#000000",
+            "#FFFFFF",
+            "#1E90FF",
+            "#7F7F7F",
+            "#12345678",
+            "#ABCDEF00",
+        ] {
+            assert_eq!(Color::from_hex(hex).unwrap().to_hex(), hex);
+        }
+    }
+
+    #[test]
+    fn from_hex_invalid_length() {
+        for hex in ["", "#", "#F0", "#F0A0", "#F0A0B", "#F0A0B0C", "#F0A0B0C0D"] {
+            assert!(matches!(
+                Color::from_hex(hex),
+                Err(ColorHexError::InvalidLength)
+            ));
+        }
+    }
+
+    #[test]
+    fn from_hex_invalid_digit() {
+        for hex in ["#GGG", "#12345Z", "#1E90FF8G", "##FFF", "#FF F"] {
+            assert!(matches!(
+                Color::from_hex(hex),
+                Err(ColorHexError::InvalidDigit)
+            ));
+        }
+    }
+}
@@ -2,15 +2,18 @@ use std::cell::RefCell;
 use std::sync::Arc;
 use std::sync::atomic::{self, AtomicBool};
 
+use basalt::image::ImageKey;
 use basalt::input::MouseButton;
-use basalt::interface::UnitValue::Pixels;
+use basalt::interface::UnitValue::{PctOffset, Pixels};
 use basalt::interface::{
-    Bin, BinStyle, Position, TextAttrs, TextBody, TextHoriAlign, TextVertAlign, TextWrap,
+    Bin, BinStyle, BinVertex, Color, Position, TextAttrs, TextBody, TextHoriAlign, TextVertAlign,
+    TextWrap, Visibility,
 };
 use parking_lot::ReentrantMutex;
 
 use crate::builder::WidgetBuilder;
-use crate::{Theme, WidgetContainer, WidgetPlacement};
+use crate::theme::ThemeCell;
+use crate::{Theme, Widget, WidgetContainer, WidgetPlacement, WidgetRole};
 
 /// Builder for [`ToggleButton`]
 pub struct ToggleButtonBuilder<'a, C> {
@@ -22,7 +25,10 @@ pub struct ToggleButtonBuilder<'a, C> {
 struct Properties {
     disabled_text: String,
     enabled_text: String,
+    disabled_icon: Option<Vec<BinVertex>>,
+    enabled_icon: Option<Vec<BinVertex>>,
     enabled: bool,
+    text_align: TextHoriAlign,
     placement: WidgetPlacement,
 }
 
@@ -31,10 +37,46 @@ impl Properties {
         Self {
             disabled_text: String::new(),
             enabled_text: String::new(),
+            disabled_icon: None,
+            enabled_icon: None,
             enabled: false,
+            text_align: TextHoriAlign::Center,
             placement,
         }
     }
+
+    /// The icon for the state, centered within a box of `base_size`.
+    fn icon_vertexes(&self, base_size: f32, enabled: bool) -> Vec<(ImageKey, Vec<BinVertex>)> {
+        let icon = match if enabled {
+            self.enabled_icon.as_ref()
+        } else {
+            self.disabled_icon.as_ref()
+        } {
+            Some(some) => some,
+            None => return Vec::new(),
+        };
+
+        let box_offset = base_size / -2.0;
+
+        let vertexes = icon
+            .iter()
+            .map(|vertex| {
+                BinVertex {
+                    x: match vertex.x {
+                        Pixels(x) => PctOffset(50.0, box_offset + x),
+                        x => x,
+                    },
+                    y: match vertex.y {
+                        Pixels(y) => PctOffset(50.0, box_offset + y),
+                        y => y,
+                    },
+                    ..vertex.clone()
+                }
+            })
+            .collect();
+
+        vec![(ImageKey::INVALID, vertexes)]
+    }
 }
 
 impl<'a, C> ToggleButtonBuilder<'a, C>
@@ -43,12 +85,7 @@ where
 {
     pub(crate) fn with_builder(mut builder: WidgetBuilder<'a, C>) -> Self {
         Self {
-            props: Properties::new(
-                builder
-                    .placement
-                    .take()
-                    .unwrap_or_else(|| ToggleButton::default_placement(&builder.theme)),
-            ),
+            props: Properties::new(builder.take_placement(ToggleButton::default_placement)),
             widget: builder,
             on_change: Vec::new(),
         }
@@ -76,6 +113,27 @@ where
         self
     }
 
+    /// Set the icon to be displayed when disabled.
+    ///
+    /// **Notes**:
+    /// - The vertexes of the icon are relative to the top-left of a square box of the theme's
+    /// `base_size` centered within the [`ToggleButton`], so their `x` and `y` must be
+    /// [`Pixels`](basalt::interface::UnitValue::Pixels).
+    /// - The color of the vertexes is replaced by the text color of the current state.
+    /// - The icon is displayed in addition to the text, so the text should usually be left empty.
+    pub fn disabled_icon(mut self, icon: Vec<BinVertex>) -> Self {
+        self.props.disabled_icon = Some(icon);
+        self
+    }
+
+    /// Set the icon to be displayed when enabled.
+    ///
+    /// See [`disabled_icon`](Self::disabled_icon) for more information.
+    pub fn enabled_icon(mut self, icon: Vec<BinVertex>) -> Self {
+        self.props.enabled_icon = Some(icon);
+        self
+    }
+
     /// Set the initial enabled state.
     ///
     /// **Note**: When this isn't used the initial value will be `false`.
@@ -84,6 +142,14 @@ where
         self
     }
 
+    /// Set the horizontal alignment of the text.
+    ///
+    /// **Note**: When this isn't used the text will be centered.
+    pub fn text_align(mut self, text_align: TextHoriAlign) -> Self {
+        self.props.text_align = text_align;
+        self
+    }
+
     /// Add a callback to be called when the [`ToggleButton`]'s value changed.
     ///
     /// **Note**: When changing the value within the callback, no callbacks will be called with
@@ -115,13 +181,20 @@ where
             .add_child(container.clone());
 
         let enabled = self.props.enabled;
+        let enabled_text = self.props.enabled_text.clone();
+        let disabled_text = self.props.disabled_text.clone();
 
         let toggle_button = Arc::new(ToggleButton {
-            theme: self.widget.theme,
+            theme: ThemeCell::new(self.widget.theme),
+            name: self.widget.name,
+            aria_label: self.widget.aria_label,
+            role: self.widget.role.unwrap_or(WidgetRole::ToggleButton),
             props: self.props,
             container,
             state: ReentrantMutex::new(State {
                 enabled: RefCell::new(enabled),
+                enabled_text: RefCell::new(enabled_text),
+                disabled_text: RefCell::new(disabled_text),
                 on_change: RefCell::new(self.on_change),
             }),
         });
@@ -138,8 +211,12 @@ where
 
             if !cb_button_pressed.load(atomic::Ordering::SeqCst) && !cb_toggle_button.get() {
                 let mut style = cb_toggle_button.container.style_copy();
-                style.back_color = cb_toggle_button.theme.colors.accent1;
-                style.text_body.base_attrs.color = cb_toggle_button.theme.colors.text1b;
+
+                apply_colors(
+                    &mut style,
+                    cb_toggle_button.theme.get().colors.accent1,
+                    cb_toggle_button.theme.get().colors.text1b,
+                );
 
                 cb_toggle_button
                     .container
@@ -159,8 +236,12 @@ where
 
             if !cb_button_pressed.load(atomic::Ordering::SeqCst) && !cb_toggle_button.get() {
                 let mut style = cb_toggle_button.container.style_copy();
-                style.back_color = cb_toggle_button.theme.colors.back3;
-                style.text_body.base_attrs.color = cb_toggle_button.theme.colors.text1a;
+
+                apply_colors(
+                    &mut style,
+                    cb_toggle_button.theme.get().colors.back3,
+                    cb_toggle_button.theme.get().colors.text1a,
+                );
 
                 cb_toggle_button
                     .container
@@ -195,11 +276,17 @@ where
                     let mut style = cb_toggle_button.container.style_copy();
 
                     if cb_cursor_inside.load(atomic::Ordering::SeqCst) {
-                        style.back_color = cb_toggle_button.theme.colors.accent1;
-                        style.text_body.base_attrs.color = cb_toggle_button.theme.colors.text1b;
+                        apply_colors(
+                            &mut style,
+                            cb_toggle_button.theme.get().colors.accent1,
+                            cb_toggle_button.theme.get().colors.text1b,
+                        );
                     } else {
-                        style.back_color = cb_toggle_button.theme.colors.back3;
-                        style.text_body.base_attrs.color = cb_toggle_button.theme.colors.text1a;
+                        apply_colors(
+                            &mut style,
+                            cb_toggle_button.theme.get().colors.back3,
+                            cb_toggle_button.theme.get().colors.text1a,
+                        );
                     }
 
                     cb_toggle_button
@@ -212,13 +299,17 @@ where
             });
 
         toggle_button.style_update();
+        self.widget.container.widget_built(toggle_button.clone());
         toggle_button
     }
 }
 
 /// Toggle button widget
 pub struct ToggleButton {
-    theme: Theme,
+    theme: ThemeCell,
+    name: Option<String>,
+    aria_label: Option<String>,
+    role: WidgetRole,
     props: Properties,
     container: Arc<Bin>,
     state: ReentrantMutex<State>,
@@ -226,6 +317,8 @@ pub struct ToggleButton {
 
 struct State {
     enabled: RefCell<bool>,
+    enabled_text: RefCell<String>,
+    disabled_text: RefCell<String>,
     on_change: RefCell<Vec<Box<dyn FnMut(&Arc<ToggleButton>, bool) + Send + 'static>>>,
 }
 
@@ -236,14 +329,20 @@ impl ToggleButton {
         *state.enabled.borrow_mut() = enabled;
 
         let mut style = self.container.style_copy();
-        style.back_color = self.theme.colors.accent2;
-        style.text_body.base_attrs.color = self.theme.colors.text1b;
-
-        style.text_body.spans[0].text = if enabled {
-            self.props.enabled_text.clone()
-        } else {
-            self.props.disabled_text.clone()
-        };
+        apply_state(
+            &mut style,
+            &self.props,
+            self.theme.get().base_size,
+            self.current_text(),
+            enabled,
+        );
+
+        // While pressed the pressed colors are used regardless of the state.
+        apply_colors(
+            &mut style,
+            self.theme.get().colors.accent2,
+            self.theme.get().colors.text1b,
+        );
 
         self.container.style_update(style).expect_valid();
 
@@ -267,6 +366,38 @@ impl ToggleButton {
         *self.state.lock().enabled.borrow()
     }
 
+    /// Obtain the text currently being displayed.
+    pub fn current_text(&self) -> String {
+        let state = self.state.lock();
+
+        if *state.enabled.borrow() {
+            state.enabled_text.borrow().clone()
+        } else {
+            state.disabled_text.borrow().clone()
+        }
+    }
+
+    /// Set the texts to be displayed when enabled and disabled.
+    ///
+    /// **Note**: The size of the [`ToggleButton`] is determined by its placement and not by its
+    /// texts, so changing them or toggling between them will never cause it to resize.
+    pub fn set_texts<E, D>(&self, enabled_text: E, disabled_text: D)
+    where
+        E: Into<String>,
+        D: Into<String>,
+    {
+        let state = self.state.lock();
+        *state.enabled_text.borrow_mut() = enabled_text.into();
+        *state.disabled_text.borrow_mut() = disabled_text.into();
+        let text = self.current_text();
+        let enabled = *state.enabled.borrow();
+        let base_size = self.theme.get().base_size;
+
+        self.container.style_modify(|style| {
+            apply_state(style, &self.props, base_size, text, enabled);
+        });
+    }
+
     /// Add a callback to be called when the [`ToggleButton`]'s value changed.
     ///
     /// **Note**: When changing the value within the callback, no callbacks will be called with
@@ -284,6 +415,76 @@ impl ToggleButton {
             .push(Box::new(on_change));
     }
 
+    /// Re-apply the layout and styling of this [`ToggleButton`], keeping the current state.
+    pub fn refresh_layout(&self) {
+        self.style_update();
+    }
+
+    /// Give keyboard focus to this [`ToggleButton`].
+    pub fn focus(&self) {
+        crate::focus_bin(&self.container);
+    }
+
+    /// Remove keyboard focus from this [`ToggleButton`].
+    ///
+    /// **Note**: This clears the focus of the window, so it should only be used when this
+    /// [`ToggleButton`] is focused.
+    pub fn blur(&self) {
+        crate::blur_bin(&self.container);
+    }
+
+    /// Set if this [`ToggleButton`] is visible.
+    pub fn set_visible(&self, visible: bool) {
+        self.container.style_modify(|style| {
+            if visible {
+                style.visibility = Visibility::Inheirt;
+            } else {
+                style.visibility = Visibility::Hide;
+            }
+        });
+    }
+
+    /// Check if this [`ToggleButton`] is visible.
+    ///
+    /// **Note**: This doesn't take into account the visibility of the container.
+    pub fn is_visible(&self) -> bool {
+        self.container
+            .style_inspect(|style| style.visibility != Visibility::Hide)
+    }
+
+    /// Obtain the bounds of this [`ToggleButton`] as of its last update.
+    ///
+    /// The bounds are in window coordinates in the form of `[left, right, top, bottom]`.
+    pub fn bounds(&self) -> [f32; 4] {
+        crate::bin_bounds(&self.container)
+    }
+
+    /// Replace the [`Theme`] of this [`ToggleButton`] and re-apply its styling.
+    ///
+    /// See [`Widget::set_theme`] for more information.
+    pub fn set_theme(&self, theme: Theme) {
+        self.theme.set(theme);
+        self.style_update();
+    }
+
+    /// Obtain the name provided by [`WidgetBuilder::named`].
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Obtain the label provided by [`WidgetBuilder::aria_label`].
+    pub fn aria_label(&self) -> Option<&str> {
+        self.aria_label.as_deref()
+    }
+
+    /// Obtain the [`WidgetRole`] of this widget.
+    ///
+    /// **Note**: Unless changed with [`WidgetBuilder::with_role`], this is
+    /// [`WidgetRole::ToggleButton`].
+    pub fn role(&self) -> WidgetRole {
+        self.role
+    }
+
     /// Obtain the default [`WidgetPlacement`](`WidgetPlacement`) given a [`Theme`](`Theme`).
     pub fn default_placement(theme: &Theme) -> WidgetPlacement {
         let height = theme.spacing + theme.base_size;
@@ -305,42 +506,57 @@ impl ToggleButton {
         let mut container_style = BinStyle {
             text_body: TextBody {
                 spans: vec![Default::default()],
-                hori_align: TextHoriAlign::Center,
+                hori_align: self.props.text_align,
                 vert_align: TextVertAlign::Center,
                 text_wrap: TextWrap::None,
                 base_attrs: TextAttrs {
-                    height: Pixels(self.theme.text_height),
-                    font_family: self.theme.font_family.clone(),
-                    font_weight: self.theme.font_weight,
+                    height: Pixels(self.theme.get().text_height),
+                    font_family: self.theme.get().font_family.clone(),
+                    font_weight: self.theme.get().label_font_weight(),
                     ..Default::default()
                 },
                 ..Default::default()
             },
+            visibility: self.container.style_inspect(|style| style.visibility),
             ..self.props.placement.clone().into_style()
         };
 
-        if *self.state.lock().enabled.borrow() {
-            container_style.back_color = self.theme.colors.accent2;
-            container_style.text_body.base_attrs.color = self.theme.colors.text1b;
-            container_style.text_body.spans[0].text = self.props.enabled_text.clone();
+        let enabled = *self.state.lock().enabled.borrow();
+
+        apply_state(
+            &mut container_style,
+            &self.props,
+            self.theme.get().base_size,
+            self.current_text(),
+            enabled,
+        );
+
+        if enabled {
+            apply_colors(
+                &mut container_style,
+                self.theme.get().colors.accent2,
+                self.theme.get().colors.text1b,
+            );
         } else {
-            container_style.back_color = self.theme.colors.back3;
-            container_style.text_body.base_attrs.color = self.theme.colors.text1a;
-            container_style.text_body.spans[0].text = self.props.disabled_text.clone();
+            apply_colors(
+                &mut container_style,
+                self.theme.get().colors.back3,
+                self.theme.get().colors.text1a,
+            );
         }
 
-        if let Some(border_size) = self.theme.border {
+        if let Some(border_size) = self.theme.get().border {
             container_style.border_size_t = Pixels(border_size);
             container_style.border_size_b = Pixels(border_size);
             container_style.border_size_l = Pixels(border_size);
             container_style.border_size_r = Pixels(border_size);
-            container_style.border_color_t = self.theme.colors.border1;
-            container_style.border_color_b = self.theme.colors.border1;
-            container_style.border_color_l = self.theme.colors.border1;
-            container_style.border_color_r = self.theme.colors.border1;
+            container_style.border_color_t = self.theme.get().colors.border1;
+            container_style.border_color_b = self.theme.get().colors.border1;
+            container_style.border_color_l = self.theme.get().colors.border1;
+            container_style.border_color_r = self.theme.get().colors.border1;
         }
 
-        if let Some(border_radius) = self.theme.roundness {
+        if let Some(border_radius) = self.theme.get().roundness {
             container_style.border_radius_tl = Pixels(border_radius);
             container_style.border_radius_tr = Pixels(border_radius);
             container_style.border_radius_bl = Pixels(border_radius);
@@ -350,3 +566,171 @@ impl ToggleButton {
         self.container.style_update(container_style).expect_valid();
     }
 }
+
+/// Set the text and icon of the state.
+///
+/// **Note**: This doesn't modify the size, so that toggling never resizes the [`ToggleButton`].
+fn apply_state(
+    style: &mut BinStyle,
+    props: &Properties,
+    base_size: f32,
+    text: String,
+    enabled: bool,
+) {
+    style.text_body.spans[0].text = text;
+    style.user_vertexes = props.icon_vertexes(base_size, enabled);
+}
+
+/// Set the background color and the color of the text and icon.
+fn apply_colors(style: &mut BinStyle, back_color: Color, text_color: Color) {
+    style.back_color = back_color;
+    style.text_body.base_attrs.color = text_color;
+
+    for (_, vertexes) in style.user_vertexes.iter_mut() {
+        for vertex in vertexes.iter_mut() {
+            vertex.color = text_color;
+        }
+    }
+}
+
+impl Widget for ToggleButton {
+    fn focus(&self) {
+        ToggleButton::focus(self)
+    }
+
+    fn blur(&self) {
+        ToggleButton::blur(self)
+    }
+
+    fn is_visible(&self) -> bool {
+        ToggleButton::is_visible(self)
+    }
+
+    fn set_visible(&self, visible: bool) {
+        ToggleButton::set_visible(self, visible)
+    }
+
+    fn bounds(&self) -> [f32; 4] {
+        ToggleButton::bounds(self)
+    }
+
+    fn set_theme(&self, theme: Theme) {
+        ToggleButton::set_theme(self, theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn icon(x: f32) -> Vec<BinVertex> {
+        vec![BinVertex {
+            x: Pixels(x),
+            y: Pixels(0.0),
+            ..Default::default()
+        }]
+    }
+
+    fn vertex_colors(style: &BinStyle) -> Vec<[f32; 4]> {
+        style
+            .user_vertexes
+            .iter()
+            .flat_map(|(_, vertexes)| vertexes.iter())
+            .map(|vertex| {
+                [
+                    vertex.color.r,
+                    vertex.color.g,
+                    vertex.color.b,
+                    vertex.color.a,
+                ]
+            })
+            .collect()
+    }
+
+    fn rgba(color: Color) -> [f32; 4] {
+        [color.r, color.g, color.b, color.a]
+    }
+
+    #[test]
+    fn icon_switches_with_state() {
+        let mut props = Properties::new(Default::default());
+        props.enabled_icon = Some(icon(1.0));
+        props.disabled_icon = Some(icon(2.0));
+
+        for (enabled, x) in [(true, 1.0), (false, 2.0), (true, 1.0)] {
+            let vertexes = props.icon_vertexes(20.0, enabled);
+            assert_eq!(vertexes.len(), 1);
+            assert!(vertexes[0].1[0].x == PctOffset(50.0, -10.0 + x));
+        }
+    }
+
+    #[test]
+    fn icon_absent_for_state() {
+        let mut props = Properties::new(Default::default());
+        props.enabled_icon = Some(icon(1.0));
+        assert!(props.icon_vertexes(20.0, false).is_empty());
+    }
+
+    #[test]
+    fn size_kept_across_toggles() {
+        let theme = Theme::default();
+        let mut props = Properties::new(ToggleButton::default_placement(&theme));
+        props.enabled_icon = Some(icon(1.0));
+
+        let mut style = BinStyle {
+            text_body: TextBody {
+                spans: vec![Default::default()],
+                ..Default::default()
+            },
+            ..props.placement.clone().into_style()
+        };
+
+        let width = style.width;
+        let height = style.height;
+
+        for (text, enabled) in [
+            ("On", true),
+            ("A much longer disabled text", false),
+            ("", true),
+        ] {
+            apply_state(
+                &mut style,
+                &props,
+                theme.base_size,
+                String::from(text),
+                enabled,
+            );
+
+            assert_eq!(style.text_body.spans[0].text, text);
+            assert!(style.width == width);
+            assert!(style.height == height);
+        }
+    }
+
+    #[test]
+    fn icon_recolored_by_hover_and_press() {
+        let theme = Theme::default();
+        let mut props = Properties::new(Default::default());
+        props.enabled_icon = Some(icon(1.0));
+        props.disabled_icon = Some(icon(2.0));
+
+        let mut style = BinStyle {
+            user_vertexes: props.icon_vertexes(theme.base_size, false),
+            ..Default::default()
+        };
+
+        // Hovered while disabled
+        apply_colors(&mut style, theme.colors.accent1, theme.colors.text1b);
+        assert_eq!(vertex_colors(&style), vec![rgba(theme.colors.text1b)]);
+
+        // Left while disabled
+        apply_colors(&mut style, theme.colors.back3, theme.colors.text1a);
+        assert_eq!(vertex_colors(&style), vec![rgba(theme.colors.text1a)]);
+
+        // Pressed, toggling to enabled
+        style.user_vertexes = props.icon_vertexes(theme.base_size, true);
+        apply_colors(&mut style, theme.colors.accent2, theme.colors.text1b);
+        assert_eq!(vertex_colors(&style), vec![rgba(theme.colors.text1b)]);
+        assert_eq!(rgba(style.back_color), rgba(theme.colors.accent2));
+    }
+}
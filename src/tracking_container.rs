@@ -0,0 +1,67 @@
+use std::sync::{Arc, Weak};
+
+use basalt::interface::Bin;
+use parking_lot::Mutex;
+
+use crate::{Widget, WidgetContainer};
+
+/// A [`WidgetContainer`] that keeps track of the widgets built within it.
+///
+/// This allows operations to be applied to all widgets of a container at once, such as hiding
+/// every widget of a form.
+///
+/// **Notes**:
+/// - Widgets built within a [`Grid`](crate::Grid) or [`Section`](crate::Section) of this
+/// container aren't tracked, only the [`Grid`](crate::Grid) or [`Section`](crate::Section) itself.
+/// - Widgets aren't kept alive by this container. Once dropped they are no longer tracked.
+pub struct TrackingContainer {
+    bin: Arc<Bin>,
+    widgets: Mutex<Vec<Weak<dyn Widget>>>,
+}
+
+impl TrackingContainer {
+    /// Create a new [`TrackingContainer`] that builds widgets within the provided [`Bin`].
+    pub fn new(bin: Arc<Bin>) -> Self {
+        Self {
+            bin,
+            widgets: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Obtain the widgets built within this container in the order they were built.
+    ///
+    /// **Note**: Widgets that have been dropped are excluded.
+    pub fn widgets(&self) -> Vec<Arc<dyn Widget>> {
+        let mut widgets = self.widgets.lock();
+        widgets.retain(|widget| widget.strong_count() > 0);
+        widgets.iter().filter_map(Weak::upgrade).collect()
+    }
+
+    /// Call the provided method on each widget built within this container.
+    ///
+    /// **Note**: Widgets that have been dropped are skipped.
+    pub fn for_each<F>(&self, mut method: F)
+    where
+        F: FnMut(&Arc<dyn Widget>),
+    {
+        // Collected first so the method is able to build widgets within this container.
+        for widget in self.widgets() {
+            method(&widget);
+        }
+    }
+
+    /// Stop tracking all widgets.
+    pub fn clear(&self) {
+        self.widgets.lock().clear();
+    }
+}
+
+impl WidgetContainer for TrackingContainer {
+    fn container_bin(&self) -> &Arc<Bin> {
+        &self.bin
+    }
+
+    fn widget_built(&self, widget: Arc<dyn Widget>) {
+        self.widgets.lock().push(Arc::downgrade(&widget));
+    }
+}